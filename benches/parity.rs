@@ -0,0 +1,51 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use mil_std_1553b::{InvalidMasks, Packet, PacketReader, PacketWriter};
+
+/// Build a capture of packed words where every 16th word is invalid
+fn capture(words: usize) -> Vec<u8> {
+    let mut data = vec![0u8; (words * 20).div_ceil(8)];
+    let mut writer = PacketWriter::new(&mut data);
+    for i in 0..words {
+        let body = (i as u16).wrapping_mul(0x9e37);
+        let mut packet = Packet::new(Packet::DATA_SYNC, body.to_be_bytes(), 0);
+        if packet.check_parity() == (i % 16 == 0) {
+            packet.parity ^= 1;
+        }
+        writer.push(&packet).unwrap();
+    }
+    data
+}
+
+/// Check each word by reading it as a packet
+fn per_word(data: &[u8]) -> u32 {
+    PacketReader::new(data)
+        .filter(|p| !p.check_parity() || !p.check_sync())
+        .count() as u32
+}
+
+/// Check every word with the bulk masks
+fn bulk(data: &[u8]) -> u32 {
+    InvalidMasks::new(data).map(u64::count_ones).sum()
+}
+
+fn parity(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parity");
+
+    for words in [33, 1024, 65536] {
+        let data = capture(words);
+        assert_eq!(per_word(&data), bulk(&data));
+
+        group.throughput(Throughput::Elements(words as u64));
+        group.bench_with_input(BenchmarkId::new("per_word", words), &data, |b, d| {
+            b.iter(|| per_word(black_box(d)))
+        });
+        group.bench_with_input(BenchmarkId::new("bulk", words), &data, |b, d| {
+            b.iter(|| bulk(black_box(d)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parity);
+criterion_main!(benches);
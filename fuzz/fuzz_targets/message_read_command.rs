@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mil_std_1553b::Message;
+
+fuzz_target!(|data: &[u8]| {
+    // messages that are too small for the data must fail cleanly
+    let _ = Message::<0>::read_command(data);
+    let _ = Message::<2>::read_command(data);
+
+    if let Ok(message) = Message::<33>::read_command(data) {
+        // anything that was read must read the same after writing
+        let mut buffer = [0u8; 83];
+        message.write(&mut buffer).unwrap();
+        assert_eq!(Message::<33>::read_command(&buffer), Ok(message));
+    }
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mil_std_1553b::Message;
+
+fuzz_target!(|data: &[u8]| {
+    // messages that are too small for the data must fail cleanly
+    let _ = Message::<0>::read_status(data);
+    let _ = Message::<2>::read_status(data);
+
+    if let Ok(message) = Message::<33>::read_status(data) {
+        // anything that was read must read the same after writing
+        let length = (message.length() * 20).div_ceil(8);
+        let mut buffer = [0u8; 83];
+        message.write(&mut buffer).unwrap();
+        assert_eq!(Message::<33>::read_status(&buffer[..length]), Ok(message));
+    }
+});
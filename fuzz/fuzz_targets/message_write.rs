@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mil_std_1553b::{CommandWord, DataWord, Message, StatusWord, Word};
+
+fuzz_target!(|input: (Vec<(u8, u16, u8)>, u8)| {
+    let (words, length) = input;
+    let mut message = Message::<8>::new();
+
+    // add words in any order, including invalid ones
+    for (kind, value, parity) in words {
+        match kind % 3 {
+            0 => message.add(CommandWord::from_value(value).with_parity(parity)),
+            1 => message.add(StatusWord::from_value(value).with_parity(parity)),
+            _ => message.add(DataWord::from_value(value).with_parity(parity)),
+        }
+    }
+
+    let mut buffer = [0u8; 256];
+    let _ = message.write(&mut buffer[..usize::from(length)]);
+});
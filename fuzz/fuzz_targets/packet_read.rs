@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mil_std_1553b::Packet;
+
+fuzz_target!(|input: (u8, &[u8])| {
+    let (offset, data) = input;
+    let offset = usize::from(offset);
+
+    if let Ok(packet) = Packet::read(data, offset) {
+        // a packet that was read must write back to the same bits
+        let mut buffer = [0u8; 4];
+        packet.write(&mut buffer, offset).unwrap();
+        assert_eq!(Packet::read(&buffer, offset), Ok(packet));
+    }
+});
@@ -0,0 +1,25 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mil_std_1553b::Packet;
+
+fuzz_target!(|input: (u8, [u8; 2], u8, u8, Vec<u8>)| {
+    let (sync, body, parity, offset, mut data) = input;
+    let packet = Packet::new(sync, body, parity);
+    let offset = usize::from(offset);
+    let before = data.clone();
+
+    if packet.write(&mut data, offset).is_ok() {
+        // the packet is masked to the width of each field
+        let result = Packet::read(&data, offset).unwrap();
+        assert_eq!(result.sync, sync & 0b111);
+        assert_eq!(result.body, body);
+        assert_eq!(result.parity, parity & 0b1);
+
+        // bits before the offset are left alone
+        let read = |d: &[u8]| u16::from_be_bytes([d[0], d[1]]) >> (16 - offset.max(1));
+        if offset > 0 {
+            assert_eq!(read(&data), read(&before));
+        }
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mil_std_1553b::{CommandWord, StatusWord, Transfer};
+
+fuzz_target!(|text: &str| {
+    // anything that was parsed must parse the same after display
+    if let Ok(word) = text.parse::<CommandWord>() {
+        assert_eq!(word.to_string().parse::<CommandWord>(), Ok(word));
+    }
+    if let Ok(word) = text.parse::<StatusWord>() {
+        assert_eq!(word.to_string().parse::<StatusWord>(), Ok(word));
+    }
+    if let Ok(transfer) = text.parse::<Transfer>() {
+        assert_eq!(transfer.to_string().parse::<Transfer>(), Ok(transfer));
+    }
+});
@@ -0,0 +1,25 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mil_std_1553b::{Fault, Message};
+
+fuzz_target!(|input: (Message<33>, Fault)| {
+    let (message, fault) = input;
+    let length = (message.length() * 20).div_ceil(8);
+    let mut buffer = [0u8; 83];
+
+    // a valid message must read back exactly as it was written
+    message.write(&mut buffer).unwrap();
+    let result = match message.is_command() {
+        true => Message::<33>::read_command(&buffer[..length]),
+        false => Message::<33>::read_status(&buffer[..length]),
+    };
+    assert_eq!(result, Ok(message.clone()));
+
+    // writing with a fault must never panic
+    let mut buffer = [0u8; 256];
+    if fault.write_message(&message, &mut buffer).is_ok() {
+        let _ = Message::<33>::read_command(&buffer);
+        let _ = Message::<33>::read_status(&buffer);
+    }
+});
@@ -0,0 +1,365 @@
+use super::Outcome;
+use crate::errors::{Error, Result};
+use crate::flags::{Address, DynamicBusAcceptance, ModeCode};
+use crate::message::Transfer;
+use crate::word::{CommandWord, StatusWord};
+use crate::Word;
+
+/// The role of a terminal in a dynamic bus control handover
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ControlState {
+    /// The terminal is the active bus controller
+    Active,
+
+    /// The terminal has offered control to another terminal
+    /// and is waiting for its status word
+    Offering(Address),
+
+    /// The terminal is a backup bus controller, acting as a
+    /// remote terminal until it is offered control
+    Backup,
+}
+
+/// A state machine for passing control of the bus between terminals
+///
+/// The active bus controller offers control with the
+/// [DynamicBusControl](ModeCode::DynamicBusControl) mode code, and
+/// the addressed terminal answers with the dynamic bus control
+/// acceptance bit of its status word. If control is accepted, the
+/// offering terminal becomes a backup and the accepting terminal
+/// becomes the active bus controller and starts its schedule.
+/// Otherwise the offering terminal stays in control.
+///
+/// Each terminal keeps its own schedule, which is only available
+/// while the terminal is the active bus controller.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let mut primary = BusControl::active(Address::Value(1), "primary");
+///     let mut backup = BusControl::backup(Address::Value(2), "backup");
+///
+///     let transfer = primary.offer(Address::Value(2))?;
+///     let status = backup.receive(transfer.command());
+///
+///     assert_eq!(primary.respond(Outcome::Response(status.unwrap())), ControlState::Backup);
+///     assert_eq!(primary.schedule(), None);
+///     assert_eq!(backup.schedule(), Some(&"backup"));
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusControl<S> {
+    address: Address,
+    state: ControlState,
+    accepting: bool,
+    schedule: S,
+}
+
+impl<S> BusControl<S> {
+    /// Create a terminal that starts as the active bus controller
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal while it is a backup
+    /// * `schedule` - The schedule to run while in control
+    ///
+    pub fn active(address: Address, schedule: S) -> Self {
+        Self {
+            address,
+            state: ControlState::Active,
+            accepting: true,
+            schedule,
+        }
+    }
+
+    /// Create a terminal that starts as a backup bus controller
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal while it is a backup
+    /// * `schedule` - The schedule to run while in control
+    ///
+    pub fn backup(address: Address, schedule: S) -> Self {
+        Self {
+            state: ControlState::Backup,
+            ..Self::active(address, schedule)
+        }
+    }
+
+    /// Constructor method to set whether offers of control are accepted
+    ///
+    /// # Arguments
+    ///
+    /// * `accepting` - True if the terminal accepts control
+    ///
+    pub fn with_accepting(mut self, accepting: bool) -> Self {
+        self.set_accepting(accepting);
+        self
+    }
+
+    /// Set whether offers of control are accepted
+    ///
+    /// # Arguments
+    ///
+    /// * `accepting` - True if the terminal accepts control
+    ///
+    pub fn set_accepting(&mut self, accepting: bool) {
+        self.accepting = accepting;
+    }
+
+    /// Get the current role of the terminal
+    pub fn state(&self) -> ControlState {
+        self.state
+    }
+
+    /// Check if the terminal is the active bus controller
+    #[must_use = "Returned value is not used"]
+    pub fn is_active(&self) -> bool {
+        self.state == ControlState::Active
+    }
+
+    /// Get the schedule of the terminal if it is in control
+    ///
+    /// A terminal that is offering control is still the bus
+    /// controller, and keeps its schedule until control is
+    /// accepted.
+    pub fn schedule(&self) -> Option<&S> {
+        match self.state {
+            ControlState::Backup => None,
+            _ => Some(&self.schedule),
+        }
+    }
+
+    /// Get the mutable schedule of the terminal if it is in control
+    pub fn schedule_mut(&mut self) -> Option<&mut S> {
+        match self.state {
+            ControlState::Backup => None,
+            _ => Some(&mut self.schedule),
+        }
+    }
+
+    /// Offer control of the bus to another terminal
+    ///
+    /// Returns the mode code transfer to send. Fails if this
+    /// terminal isn't the active bus controller, or the
+    /// target is the broadcast address.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The address of the terminal to offer control to
+    ///
+    pub fn offer(&mut self, target: Address) -> Result<Transfer> {
+        if !self.is_active() || target.is_broadcast() {
+            return Err(Error::InvalidMessage);
+        }
+        self.state = ControlState::Offering(target);
+        Ok(Transfer::mode_code(target, ModeCode::DynamicBusControl))
+    }
+
+    /// Interpret the response to an offer of control
+    ///
+    /// Control is passed if the offered terminal responds with
+    /// the dynamic bus control acceptance bit set. Any other
+    /// response keeps this terminal in control. Does nothing
+    /// unless an offer is outstanding.
+    ///
+    /// # Arguments
+    ///
+    /// * `outcome` - The result of the offer transfer
+    ///
+    pub fn respond(&mut self, outcome: Outcome) -> ControlState {
+        if let ControlState::Offering(target) = self.state {
+            self.state = match outcome {
+                Outcome::Response(s)
+                    if s.address() == target && s.dynamic_bus_acceptance().is_accepted() =>
+                {
+                    ControlState::Backup
+                }
+                _ => ControlState::Active,
+            };
+        }
+        self.state
+    }
+
+    /// Handle a command received while acting as a remote terminal
+    ///
+    /// Returns the status word to respond with if the command is
+    /// addressed to this terminal, or `None` for commands to other
+    /// terminals or broadcast commands. If the command is an offer
+    /// of control and the terminal is accepting, the acceptance bit
+    /// is set and the terminal becomes the active bus controller.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word received
+    ///
+    pub fn receive(&mut self, command: &CommandWord) -> Option<StatusWord> {
+        if self.state != ControlState::Backup || command.address() != self.address {
+            return None;
+        }
+
+        let offer = command.is_mode_code()
+            && command.mode_code() == ModeCode::DynamicBusControl
+            && Some(command.transmit_receive()) == ModeCode::DynamicBusControl.direction();
+        let accepted = offer && self.accepting;
+
+        if accepted {
+            self.state = ControlState::Active;
+        }
+
+        Some(
+            StatusWord::new()
+                .with_address(self.address)
+                .with_dynamic_bus_acceptance(match accepted {
+                    true => DynamicBusAcceptance::Accepted,
+                    false => DynamicBusAcceptance::NotAccepted,
+                })
+                .with_calculated_parity(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::{SubAddress, TransmitReceive};
+
+    fn pair() -> (BusControl<u8>, BusControl<u8>) {
+        (
+            BusControl::active(Address::Value(1), 1),
+            BusControl::backup(Address::Value(2), 2),
+        )
+    }
+
+    #[test]
+    fn test_handover_accepted() {
+        let (mut primary, mut backup) = pair();
+        let transfer = primary.offer(Address::Value(2)).unwrap();
+        assert_eq!(primary.state(), ControlState::Offering(Address::Value(2)));
+        assert_eq!(primary.schedule(), Some(&1));
+
+        let status = backup.receive(transfer.command()).unwrap();
+        assert!(status.dynamic_bus_acceptance().is_accepted());
+        assert!(status.check_parity());
+        assert!(backup.is_active());
+
+        assert_eq!(
+            primary.respond(Outcome::Response(status)),
+            ControlState::Backup
+        );
+        assert_eq!(primary.schedule(), None);
+        assert_eq!(backup.schedule(), Some(&2));
+    }
+
+    #[test]
+    fn test_handover_rejected() {
+        let (mut primary, backup) = pair();
+        let mut backup = backup.with_accepting(false);
+
+        let transfer = primary.offer(Address::Value(2)).unwrap();
+        let status = backup.receive(transfer.command()).unwrap();
+        assert!(!status.dynamic_bus_acceptance().is_accepted());
+        assert!(!backup.is_active());
+
+        assert_eq!(
+            primary.respond(Outcome::Response(status)),
+            ControlState::Active
+        );
+    }
+
+    #[test]
+    fn test_handover_no_response() {
+        let (mut primary, _) = pair();
+        let _ = primary.offer(Address::Value(2)).unwrap();
+        assert_eq!(primary.respond(Outcome::NoResponse), ControlState::Active);
+    }
+
+    #[test]
+    fn test_handover_wrong_address() {
+        let (mut primary, mut backup) = pair();
+        let _ = primary.offer(Address::Value(3)).unwrap();
+        let status = StatusWord::new()
+            .with_address(Address::Value(2))
+            .with_dynamic_bus_acceptance(DynamicBusAcceptance::Accepted);
+        assert_eq!(
+            primary.respond(Outcome::Response(status)),
+            ControlState::Active
+        );
+
+        let transfer = Transfer::mode_code(Address::Value(3), ModeCode::DynamicBusControl);
+        assert_eq!(backup.receive(transfer.command()), None);
+    }
+
+    #[test]
+    fn test_handover_offer_fail() {
+        let (mut primary, mut backup) = pair();
+        assert_eq!(backup.offer(Address::Value(1)), Err(Error::InvalidMessage));
+        assert_eq!(
+            primary.offer(Address::from(31u8)),
+            Err(Error::InvalidMessage)
+        );
+    }
+
+    #[test]
+    fn test_handover_respond_without_offer() {
+        let (mut primary, mut backup) = pair();
+        assert_eq!(primary.respond(Outcome::NoResponse), ControlState::Active);
+        assert_eq!(backup.respond(Outcome::NoResponse), ControlState::Backup);
+    }
+
+    #[test]
+    fn test_handover_other_command() {
+        let (_, mut backup) = pair();
+        let command = CommandWord::new()
+            .with_address(Address::Value(2))
+            .with_subaddress(SubAddress::Value(4))
+            .with_word_count(1)
+            .with_calculated_parity();
+
+        let status = backup.receive(&command).unwrap();
+        assert!(!status.dynamic_bus_acceptance().is_accepted());
+        assert!(!backup.is_active());
+    }
+
+    #[test]
+    fn test_handover_wrong_direction() {
+        let (_, mut backup) = pair();
+        let command = Transfer::mode_code(Address::Value(2), ModeCode::DynamicBusControl)
+            .command()
+            .with_transmit_receive(TransmitReceive::Receive)
+            .with_calculated_parity();
+
+        let status = backup.receive(&command).unwrap();
+        assert!(!status.dynamic_bus_acceptance().is_accepted());
+        assert!(!backup.is_active());
+    }
+
+    #[test]
+    fn test_handover_schedule_mut() {
+        let (mut primary, mut backup) = pair();
+        *primary.schedule_mut().unwrap() = 3;
+        assert_eq!(primary.schedule(), Some(&3));
+        assert_eq!(backup.schedule_mut(), None);
+    }
+
+    #[test]
+    fn test_handover_round_trip() {
+        let (mut primary, mut backup) = pair();
+        let transfer = primary.offer(Address::Value(2)).unwrap();
+        let status = backup.receive(transfer.command()).unwrap();
+        let _ = primary.respond(Outcome::Response(status));
+
+        let transfer = backup.offer(Address::Value(1)).unwrap();
+        let status = primary.receive(transfer.command()).unwrap();
+        assert_eq!(
+            backup.respond(Outcome::Response(status)),
+            ControlState::Backup
+        );
+        assert!(primary.is_active());
+    }
+}
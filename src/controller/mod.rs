@@ -0,0 +1,9 @@
+//! Logic for bus controllers
+
+mod handover;
+mod retry;
+mod service;
+
+pub use handover::{BusControl, ControlState};
+pub use retry::{Action, Handling, Outcome, RetryPolicy};
+pub use service::ServiceRequests;
@@ -0,0 +1,422 @@
+use crate::errors::Error;
+use crate::flags::Address;
+use crate::word::{CommandWord, StatusWord};
+
+/// The number of terminal addresses (including broadcast)
+const ADDRESSES: usize = 32;
+
+/// The result of a transfer as seen by the bus controller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Outcome {
+    /// The terminal responded with a status word
+    Response(StatusWord),
+
+    /// The terminal didn't respond before the timeout
+    NoResponse,
+
+    /// The response couldn't be read (parity, sync or word count errors)
+    Invalid(Error),
+}
+
+/// The next action for the bus controller to take after a transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Action {
+    /// The transfer succeeded, continue with the schedule
+    Continue,
+
+    /// Send the transfer again on the same bus
+    RetrySame,
+
+    /// Send the transfer again on the alternate bus
+    RetryAlternate,
+
+    /// Give up on the transfer and continue with the schedule
+    Skip,
+
+    /// Give up on the transfer and raise an alarm
+    Alarm,
+}
+
+/// How a kind of failed transfer is handled by a [RetryPolicy]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Handling {
+    /// Treat the transfer as successful
+    Ignore,
+
+    /// Retry the transfer until the retries are used up
+    Retry,
+
+    /// Skip the transfer without retrying
+    Skip,
+
+    /// Raise an alarm without retrying
+    Alarm,
+}
+
+/// A configurable policy for retrying failed transfers
+///
+/// Each transfer outcome is classified as one of: no response,
+/// an invalid response, a busy terminal ([StatusWord::is_busy])
+/// or an error flag in the status word ([StatusWord::is_error]).
+/// Each kind of failure has its own [Handling]. Failures that
+/// are retried are sent again on the same bus, then on the
+/// alternate bus, and once the retries for the terminal are
+/// used up the policy returns the exhausted action.
+///
+/// Retry counters are kept per terminal address, and are reset
+/// whenever a transfer to that terminal finishes.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let mut policy = RetryPolicy::new()
+///         .with_same_bus_retries(1)
+///         .with_alternate_bus_retries(1)
+///         .with_busy(Handling::Skip);
+///
+///     let command = CommandWord::new()
+///         .with_address(Address::Value(5))
+///         .with_subaddress(SubAddress::Value(2))
+///         .with_word_count(1)
+///         .build()?;
+///
+///     assert_eq!(policy.evaluate(&command, Outcome::NoResponse), Action::RetrySame);
+///     assert_eq!(policy.evaluate(&command, Outcome::NoResponse), Action::RetryAlternate);
+///     assert_eq!(policy.evaluate(&command, Outcome::NoResponse), Action::Alarm);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    same: u8,
+    alternate: u8,
+    no_response: Handling,
+    invalid: Handling,
+    busy: Handling,
+    error: Handling,
+    exhausted: Action,
+    retries: [u8; ADDRESSES],
+}
+
+impl RetryPolicy {
+    /// Create a new policy
+    ///
+    /// By default every failure is retried once on each bus
+    /// before raising an alarm.
+    pub const fn new() -> Self {
+        Self {
+            same: 1,
+            alternate: 1,
+            no_response: Handling::Retry,
+            invalid: Handling::Retry,
+            busy: Handling::Retry,
+            error: Handling::Retry,
+            exhausted: Action::Alarm,
+            retries: [0; ADDRESSES],
+        }
+    }
+
+    /// Constructor method to set the number of retries on the same bus
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of retries
+    ///
+    pub const fn with_same_bus_retries(mut self, count: u8) -> Self {
+        self.same = count;
+        self
+    }
+
+    /// Constructor method to set the number of retries on the alternate bus
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of retries
+    ///
+    pub const fn with_alternate_bus_retries(mut self, count: u8) -> Self {
+        self.alternate = count;
+        self
+    }
+
+    /// Constructor method to set the handling of transfers without a response
+    ///
+    /// # Arguments
+    ///
+    /// * `handling` - How the failure is handled
+    ///
+    pub const fn with_no_response(mut self, handling: Handling) -> Self {
+        self.no_response = handling;
+        self
+    }
+
+    /// Constructor method to set the handling of invalid responses
+    ///
+    /// # Arguments
+    ///
+    /// * `handling` - How the failure is handled
+    ///
+    pub const fn with_invalid_response(mut self, handling: Handling) -> Self {
+        self.invalid = handling;
+        self
+    }
+
+    /// Constructor method to set the handling of busy terminals
+    ///
+    /// # Arguments
+    ///
+    /// * `handling` - How the failure is handled
+    ///
+    pub const fn with_busy(mut self, handling: Handling) -> Self {
+        self.busy = handling;
+        self
+    }
+
+    /// Constructor method to set the handling of status words with error flags
+    ///
+    /// # Arguments
+    ///
+    /// * `handling` - How the failure is handled
+    ///
+    pub const fn with_status_error(mut self, handling: Handling) -> Self {
+        self.error = handling;
+        self
+    }
+
+    /// Constructor method to set the action once retries are used up
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - The action to return (usually Skip or Alarm)
+    ///
+    pub const fn with_exhausted(mut self, action: Action) -> Self {
+        self.exhausted = action;
+        self
+    }
+
+    /// Get the number of retries made for a terminal address
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The terminal address
+    ///
+    pub fn retries(&self, address: Address) -> u8 {
+        self.retries[index(address)]
+    }
+
+    /// Reset the retry counter of every terminal
+    pub fn reset(&mut self) {
+        self.retries = [0; ADDRESSES];
+    }
+
+    /// Decide what to do after a transfer
+    ///
+    /// Broadcast commands are expected to have no response, and
+    /// a response with the wrong terminal address is invalid.
+    /// A busy terminal is checked before the error flags of the
+    /// status word.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word of the transfer
+    /// * `outcome` - The result of the transfer
+    ///
+    pub fn evaluate(&mut self, command: &CommandWord, outcome: Outcome) -> Action {
+        let address = command.address();
+        let index = index(address);
+
+        let handling = match outcome {
+            Outcome::NoResponse if address.is_broadcast() => Handling::Ignore,
+            Outcome::NoResponse => self.no_response,
+            Outcome::Invalid(_) => self.invalid,
+            Outcome::Response(_) if address.is_broadcast() => self.invalid,
+            Outcome::Response(s) if s.address() != address => self.invalid,
+            Outcome::Response(s) if s.is_busy() => self.busy,
+            Outcome::Response(s) if s.is_error() => self.error,
+            Outcome::Response(_) => Handling::Ignore,
+        };
+
+        let retries = self.retries[index];
+        let action = match handling {
+            Handling::Ignore => Action::Continue,
+            Handling::Skip => Action::Skip,
+            Handling::Alarm => Action::Alarm,
+            Handling::Retry if retries < self.same => Action::RetrySame,
+            Handling::Retry if retries < self.same.saturating_add(self.alternate) => {
+                Action::RetryAlternate
+            }
+            Handling::Retry => self.exhausted,
+        };
+
+        self.retries[index] = match action {
+            Action::RetrySame | Action::RetryAlternate => retries.saturating_add(1),
+            _ => 0,
+        };
+
+        action
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get the index of a terminal address
+fn index(address: Address) -> usize {
+    usize::from(u8::from(address)) % ADDRESSES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{MessageError, SubsystemError};
+    use crate::flags::TerminalBusy;
+    use crate::parse::command;
+    use crate::Word;
+
+    fn status(address: u8) -> StatusWord {
+        StatusWord::new()
+            .with_address(Address::from(address))
+            .with_calculated_parity()
+    }
+
+    #[test]
+    fn test_retry_success() {
+        let mut policy = RetryPolicy::new();
+        let action = policy.evaluate(&command("05-R-02-01"), Outcome::Response(status(5)));
+        assert_eq!(action, Action::Continue);
+        assert_eq!(policy.retries(Address::Value(5)), 0);
+    }
+
+    #[test]
+    fn test_retry_sequence() {
+        let mut policy = RetryPolicy::new()
+            .with_same_bus_retries(2)
+            .with_alternate_bus_retries(1);
+
+        let actions = [
+            Action::RetrySame,
+            Action::RetrySame,
+            Action::RetryAlternate,
+            Action::Alarm,
+        ];
+        for action in actions {
+            assert_eq!(
+                policy.evaluate(&command("05-R-02-01"), Outcome::NoResponse),
+                action
+            );
+        }
+        assert_eq!(policy.retries(Address::Value(5)), 0);
+    }
+
+    #[test]
+    fn test_retry_exhausted_skip() {
+        let mut policy = RetryPolicy::new()
+            .with_same_bus_retries(0)
+            .with_alternate_bus_retries(0)
+            .with_exhausted(Action::Skip);
+        assert_eq!(
+            policy.evaluate(&command("05-R-02-01"), Outcome::NoResponse),
+            Action::Skip
+        );
+    }
+
+    #[test]
+    fn test_retry_reset_on_success() {
+        let mut policy = RetryPolicy::new();
+        assert_eq!(
+            policy.evaluate(&command("05-R-02-01"), Outcome::NoResponse),
+            Action::RetrySame
+        );
+        assert_eq!(policy.retries(Address::Value(5)), 1);
+        assert_eq!(
+            policy.evaluate(&command("05-R-02-01"), Outcome::Response(status(5))),
+            Action::Continue
+        );
+        assert_eq!(policy.retries(Address::Value(5)), 0);
+    }
+
+    #[test]
+    fn test_retry_per_terminal() {
+        let mut policy = RetryPolicy::new();
+        let _ = policy.evaluate(&command("05-R-02-01"), Outcome::NoResponse);
+        assert_eq!(policy.retries(Address::Value(5)), 1);
+        assert_eq!(policy.retries(Address::Value(6)), 0);
+        assert_eq!(
+            policy.evaluate(&command("06-R-02-01"), Outcome::NoResponse),
+            Action::RetrySame
+        );
+
+        policy.reset();
+        assert_eq!(policy.retries(Address::Value(5)), 0);
+        assert_eq!(policy.retries(Address::Value(6)), 0);
+    }
+
+    #[test]
+    fn test_retry_invalid() {
+        let mut policy = RetryPolicy::new().with_invalid_response(Handling::Alarm);
+        assert_eq!(
+            policy.evaluate(&command("05-R-02-01"), Outcome::Invalid(Error::InvalidWord)),
+            Action::Alarm
+        );
+        assert_eq!(
+            policy.evaluate(&command("05-R-02-01"), Outcome::Response(status(6))),
+            Action::Alarm
+        );
+    }
+
+    #[test]
+    fn test_retry_busy() {
+        let mut policy = RetryPolicy::new()
+            .with_busy(Handling::Skip)
+            .with_status_error(Handling::Alarm);
+        let busy = status(5)
+            .with_terminal_busy(TerminalBusy::Busy)
+            .with_message_error(MessageError::Error);
+        assert_eq!(
+            policy.evaluate(&command("05-R-02-01"), Outcome::Response(busy)),
+            Action::Skip
+        );
+    }
+
+    #[test]
+    fn test_retry_status_error() {
+        let mut policy = RetryPolicy::new().with_status_error(Handling::Ignore);
+        let message = status(5).with_message_error(MessageError::Error);
+        let subsystem = status(5).with_subsystem_error(SubsystemError::Error);
+        assert_eq!(
+            policy.evaluate(&command("05-R-02-01"), Outcome::Response(message)),
+            Action::Continue
+        );
+        assert_eq!(
+            policy.evaluate(&command("05-R-02-01"), Outcome::Response(subsystem)),
+            Action::Continue
+        );
+
+        let mut policy = RetryPolicy::default();
+        assert_eq!(
+            policy.evaluate(&command("05-R-02-01"), Outcome::Response(message)),
+            Action::RetrySame
+        );
+    }
+
+    #[test]
+    fn test_retry_broadcast() {
+        let mut policy = RetryPolicy::new().with_no_response(Handling::Alarm);
+        assert_eq!(
+            policy.evaluate(&command("31-R-02-01"), Outcome::NoResponse),
+            Action::Continue
+        );
+        assert_eq!(
+            policy.evaluate(&command("31-R-02-01"), Outcome::Response(status(31))),
+            Action::RetrySame
+        );
+    }
+}
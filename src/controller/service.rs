@@ -0,0 +1,252 @@
+use crate::flags::{Address, ModeCode};
+use crate::message::Transfer;
+use crate::word::{DataWord, StatusWord};
+use crate::Word;
+
+/// The number of terminal addresses (excluding broadcast)
+const TERMINALS: u8 = 31;
+
+/// A tracker for service requests raised by remote terminals
+///
+/// Terminals set the service request bit of their status word
+/// when they need the bus controller to schedule asynchronous
+/// messages. The bus controller then requests the vector word of
+/// the terminal, and the bits of the vector word select which
+/// messages to send.
+///
+/// The mapping from vector bits to messages is supplied as a slice
+/// of `(mask, transfer)` pairs, and a transfer is selected if any
+/// of the bits in its mask are set in the vector word.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let mut requests = ServiceRequests::new();
+///
+///     let status = StatusWord::new()
+///         .with_address(Address::Value(5))
+///         .with_service_request(ServiceRequest::Service)
+///         .build()?;
+///
+///     requests.observe(&status);
+///     assert_eq!(requests.next(), Some(Address::Value(5)));
+///
+///     let transfer = ServiceRequests::vector_transfer(Address::Value(5));
+///     assert_eq!(transfer.command().mode_code(), ModeCode::TransmitVectorWord);
+///
+///     let mapping: [(u16, Transfer); 2] = [
+///         (0b01, "05-T-03-04".parse().unwrap()),
+///         (0b10, "05-T-04-02".parse().unwrap()),
+///     ];
+///
+///     let vector = DataWord::from(0b10u16);
+///     let mut messages = requests.decode(Address::Value(5), &vector, &mapping);
+///
+///     assert_eq!(messages.next(), Some(mapping[1].1));
+///     assert_eq!(messages.next(), None);
+///     assert_eq!(requests.next(), None);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ServiceRequests {
+    pending: u32,
+}
+
+impl ServiceRequests {
+    /// Create a new tracker with no pending service requests
+    pub const fn new() -> Self {
+        Self { pending: 0 }
+    }
+
+    /// Update the pending service requests from a status word
+    ///
+    /// The request for the terminal that sent the status word is
+    /// set or cleared to match its service request bit.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - A status word received from a terminal
+    ///
+    pub fn observe(&mut self, status: &StatusWord) {
+        let address = status.address();
+        if address.is_broadcast() {
+            return;
+        }
+        match status.service_request().is_service() {
+            true => self.pending |= bit(address),
+            false => self.pending &= !bit(address),
+        }
+    }
+
+    /// Check if a terminal has a pending service request
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal
+    ///
+    #[must_use = "Returned value is not used"]
+    pub fn is_pending(&self, address: Address) -> bool {
+        self.pending & bit(address) != 0
+    }
+
+    /// Get the lowest terminal address with a pending service request
+    pub fn next(&self) -> Option<Address> {
+        (0..TERMINALS)
+            .map(Address::from)
+            .find(|a| self.is_pending(*a))
+    }
+
+    /// Get the number of terminals with pending service requests
+    pub fn count(&self) -> usize {
+        self.pending.count_ones() as usize
+    }
+
+    /// Clear the pending service request of a terminal
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal
+    ///
+    pub fn clear(&mut self, address: Address) {
+        self.pending &= !bit(address);
+    }
+
+    /// Build the transfer that requests the vector word of a terminal
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal
+    ///
+    pub fn vector_transfer(address: Address) -> Transfer {
+        Transfer::mode_code(address, ModeCode::TransmitVectorWord)
+    }
+
+    /// Decode the vector word of a terminal into the messages to schedule
+    ///
+    /// Clears the pending service request of the terminal, and
+    /// returns the transfers in the mapping whose mask overlaps
+    /// the vector word, in the order of the mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal
+    /// * `vector` - The vector word sent by the terminal
+    /// * `mapping` - Pairs of vector bit masks and the transfers they select
+    ///
+    pub fn decode<'a>(
+        &mut self,
+        address: Address,
+        vector: &DataWord,
+        mapping: &'a [(u16, Transfer)],
+    ) -> impl Iterator<Item = Transfer> + 'a {
+        self.clear(address);
+        let value = vector.as_value();
+        mapping
+            .iter()
+            .filter(move |(mask, _)| mask & value != 0)
+            .map(|(_, transfer)| *transfer)
+    }
+}
+
+/// Get the bit for a terminal address
+fn bit(address: Address) -> u32 {
+    1 << (u8::from(address) % 32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::ServiceRequest;
+
+    fn status(address: u8, service: ServiceRequest) -> StatusWord {
+        StatusWord::new()
+            .with_address(Address::from(address))
+            .with_service_request(service)
+            .with_calculated_parity()
+    }
+
+    #[test]
+    fn test_service_observe() {
+        let mut requests = ServiceRequests::new();
+        requests.observe(&status(5, ServiceRequest::Service));
+        requests.observe(&status(7, ServiceRequest::Service));
+        requests.observe(&status(9, ServiceRequest::NoService));
+
+        assert!(requests.is_pending(Address::Value(5)));
+        assert!(requests.is_pending(Address::Value(7)));
+        assert!(!requests.is_pending(Address::Value(9)));
+        assert_eq!(requests.count(), 2);
+
+        requests.observe(&status(5, ServiceRequest::NoService));
+        assert!(!requests.is_pending(Address::Value(5)));
+    }
+
+    #[test]
+    fn test_service_observe_broadcast() {
+        let mut requests = ServiceRequests::new();
+        requests.observe(&status(31, ServiceRequest::Service));
+        assert_eq!(requests.count(), 0);
+    }
+
+    #[test]
+    fn test_service_next() {
+        let mut requests = ServiceRequests::default();
+        assert_eq!(requests.next(), None);
+
+        requests.observe(&status(12, ServiceRequest::Service));
+        requests.observe(&status(3, ServiceRequest::Service));
+        assert_eq!(requests.next(), Some(Address::Value(3)));
+
+        requests.clear(Address::Value(3));
+        assert_eq!(requests.next(), Some(Address::Value(12)));
+    }
+
+    #[test]
+    fn test_service_vector_transfer() {
+        let transfer = ServiceRequests::vector_transfer(Address::Value(5));
+        let command = transfer.command();
+        assert!(transfer.is_mode_code());
+        assert!(command.is_transmit());
+        assert!(command.check_parity());
+        assert_eq!(command.address(), Address::Value(5));
+        assert_eq!(command.mode_code(), ModeCode::TransmitVectorWord);
+        assert_eq!(transfer.word_count(), 1);
+    }
+
+    #[test]
+    fn test_service_decode() {
+        let mut requests = ServiceRequests::new();
+        requests.observe(&status(5, ServiceRequest::Service));
+
+        let mapping = [
+            (0b0001, "05-T-01-02".parse().unwrap()),
+            (0b0010, "05-T-02-02".parse().unwrap()),
+            (0b1100, "05-T-03-02".parse().unwrap()),
+        ];
+
+        let vector = DataWord::from_value(0b0101).with_calculated_parity();
+        let mut messages = requests.decode(Address::Value(5), &vector, &mapping);
+
+        assert_eq!(messages.next(), Some("05-T-01-02".parse().unwrap()));
+        assert_eq!(messages.next(), Some("05-T-03-02".parse().unwrap()));
+        assert_eq!(messages.next(), None);
+        assert!(!requests.is_pending(Address::Value(5)));
+    }
+
+    #[test]
+    fn test_service_decode_empty() {
+        let mut requests = ServiceRequests::new();
+        let mapping = [(0b0001, "05-T-01-02".parse().unwrap())];
+        let vector = DataWord::from_value(0).with_calculated_parity();
+        assert_eq!(
+            requests
+                .decode(Address::Value(5), &vector, &mapping)
+                .count(),
+            0
+        );
+    }
+}
@@ -24,6 +24,7 @@ pub(crate) const fn parity(v: u16) -> u8 {
 /// calculations fail. The [Error::SystemError] variant
 /// contains any errors generated by the 1553 bus.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Error {
     /// An index or range was out of bounds
@@ -54,11 +55,145 @@ pub enum Error {
     SystemError(SystemError),
 }
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBounds => write!(f, "index or range out of bounds"),
+            Self::InvalidPacket => write!(f, "invalid packet"),
+            Self::InvalidWord => write!(f, "invalid word"),
+            Self::InvalidString => write!(f, "invalid string size or encoding"),
+            Self::MessageFull => write!(f, "message is full"),
+            Self::DataFirst => write!(f, "message begins with a data word"),
+            Self::HeaderNotFirst => write!(f, "header word is not the first word"),
+            Self::InvalidMessage => write!(f, "invalid message"),
+            Self::SystemError(e) => write!(f, "system error: {:?}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// An error produced while parsing the text notation of a word
+///
+/// Contains the byte offset into the text at which parsing
+/// failed, along with a [ParseErrorKind] that describes the
+/// problem.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParseError {
+    /// The byte offset at which the error was found
+    position: usize,
+
+    /// The kind of error
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Create a new parse error
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The byte offset at which the error was found
+    /// * `kind` - The kind of error
+    ///
+    pub const fn new(position: usize, kind: ParseErrorKind) -> Self {
+        Self { position, kind }
+    }
+
+    /// Get the byte offset at which the error was found
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get the kind of error
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// The kind of problem found while parsing
+///
+/// See [ParseError] for more information.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum ParseErrorKind {
+    /// The text ended before the word was complete
+    UnexpectedEnd,
+
+    /// A character was found that doesn't belong
+    UnexpectedCharacter,
+
+    /// A number was expected
+    ExpectedNumber,
+
+    /// A direction ('T' or 'R') was expected
+    ExpectedDirection,
+
+    /// The terminal address is larger than 31
+    InvalidAddress,
+
+    /// The subaddress is larger than 31
+    InvalidSubaddress,
+
+    /// The word count isn't between 1 and 32
+    InvalidWordCount,
+
+    /// The mode code isn't a known mode code
+    UnknownModeCode,
+
+    /// The direction isn't the one defined for the mode code
+    WrongDirection,
+
+    /// The status flag isn't a known flag
+    UnknownFlag,
+
+    /// The status flag was given more than once
+    DuplicateFlag,
+
+    /// The reserved bits aren't between 1 and 7
+    InvalidReserved,
+
+    /// The command words don't form a valid transfer
+    InvalidTransfer,
+}
+
+impl core::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of text"),
+            Self::UnexpectedCharacter => write!(f, "unexpected character"),
+            Self::ExpectedNumber => write!(f, "expected a number"),
+            Self::ExpectedDirection => write!(f, "expected 'T' or 'R'"),
+            Self::InvalidAddress => write!(f, "address must be between 0 and 31"),
+            Self::InvalidSubaddress => write!(f, "subaddress must be between 0 and 31"),
+            Self::InvalidWordCount => write!(f, "word count must be between 1 and 32"),
+            Self::UnknownModeCode => write!(f, "unknown mode code"),
+            Self::WrongDirection => write!(f, "direction doesn't match the mode code"),
+            Self::UnknownFlag => write!(f, "unknown status flag"),
+            Self::DuplicateFlag => write!(f, "status flag given more than once"),
+            Self::InvalidReserved => write!(f, "reserved bits must be between 1 and 7"),
+            Self::InvalidTransfer => write!(f, "command words don't form a valid transfer"),
+        }
+    }
+}
+
 /// An error deriving from a remote terminal or bus controller.
 ///
 /// These errors are generated during runtime by terminals and
 /// provided in messages on the bus.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum SystemError {
     /// No error
@@ -85,6 +220,7 @@ pub enum SystemError {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum TerminalError {
     /// No error
@@ -138,6 +274,15 @@ impl From<TerminalError> for u16 {
     }
 }
 
+impl core::fmt::Display for TerminalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Error => write!(f, "Error"),
+        }
+    }
+}
+
 /// This flag provides health data regarding subsystems of a remote terminal.
 ///
 /// The Subsystem Flag bit located at bit time 17 (index 13) is used to provide
@@ -151,6 +296,7 @@ impl From<TerminalError> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum SubsystemError {
     /// No error
@@ -204,6 +350,15 @@ impl From<SubsystemError> for u16 {
     }
 }
 
+impl core::fmt::Display for SubsystemError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Error => write!(f, "Error"),
+        }
+    }
+}
+
 /// This flag is set when a receiving terminal detects an error in a message.
 ///
 /// The error may have occurred in any of the data words within the message, and
@@ -217,6 +372,7 @@ impl From<SubsystemError> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum MessageError {
     /// No error
@@ -270,6 +426,15 @@ impl From<MessageError> for u16 {
     }
 }
 
+impl core::fmt::Display for MessageError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Error => write!(f, "Error"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,17 +463,26 @@ mod tests {
         assert_eq!(value, 0);
     }
 
+    #[test]
+    fn test_error_display() {
+        let result = std::format!("{}", Error::OutOfBounds);
+        assert_eq!(result, "index or range out of bounds");
+
+        let result = std::format!("{}", Error::SystemError(SystemError::None));
+        assert_eq!(result, "system error: None");
+    }
+
     #[test]
     fn test_system_error_clone() {
         let error1 = SystemError::Terminal(TerminalError::Error);
-        let error2 = error1.clone();
+        let error2 = Clone::clone(&error1);
         assert_eq!(error1, error2);
     }
 
     #[test]
     fn test_terminal_error_clone() {
         let error1 = TerminalError::Error;
-        let error2 = error1.clone();
+        let error2 = Clone::clone(&error1);
         assert_eq!(error1, error2);
     }
 
@@ -360,7 +534,7 @@ mod tests {
     #[test]
     fn test_subsystem_error_clone() {
         let error1 = SubsystemError::Error;
-        let error2 = error1.clone();
+        let error2 = Clone::clone(&error1);
         assert_eq!(error1, error2);
     }
 
@@ -412,7 +586,7 @@ mod tests {
     #[test]
     fn test_message_error_clone() {
         let error1 = MessageError::Error;
-        let error2 = error1.clone();
+        let error2 = Clone::clone(&error1);
         assert_eq!(error1, error2);
     }
 
@@ -460,4 +634,11 @@ mod tests {
         let error = u16::from(MessageError::Error);
         assert_eq!(error, 1);
     }
+
+    #[test]
+    fn test_status_flag_error_display() {
+        assert_eq!(std::format!("{}", MessageError::Error), "Error");
+        assert_eq!(std::format!("{}", SubsystemError::None), "None");
+        assert_eq!(std::format!("{}", TerminalError::Error), "Error");
+    }
 }
@@ -0,0 +1,531 @@
+//! Event queues for terminal and bus controller logic
+
+use crate::flags::{SubAddress, TransmitReceive};
+use crate::word::{CommandWord, StatusWord};
+
+/// An event posted by terminal or bus controller logic
+///
+/// Events reference the command word of the message that caused
+/// them and the status word sent in response, if there was one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Event {
+    /// A message completed without errors
+    EndOfMessage {
+        /// The command word of the message
+        command: CommandWord,
+
+        /// The status word sent in response
+        status: Option<StatusWord>,
+    },
+
+    /// A message completed with an error or without a response
+    ErrorMessage {
+        /// The command word of the message
+        command: CommandWord,
+
+        /// The status word sent in response
+        status: Option<StatusWord>,
+    },
+
+    /// A mode code command was received
+    ModeCode {
+        /// The command word of the message
+        command: CommandWord,
+
+        /// The status word sent in response
+        status: Option<StatusWord>,
+    },
+
+    /// A broadcast command was received
+    Broadcast {
+        /// The command word of the message
+        command: CommandWord,
+    },
+
+    /// A message was sent to a subaddress with events enabled
+    Subaddress {
+        /// The command word of the message
+        command: CommandWord,
+
+        /// The status word sent in response
+        status: Option<StatusWord>,
+    },
+
+    /// Events were lost because the queue was full
+    Overflow {
+        /// The number of events that were lost
+        lost: usize,
+    },
+}
+
+impl Event {
+    /// Get the command word of the event
+    pub fn command(&self) -> Option<&CommandWord> {
+        match self {
+            Self::EndOfMessage { command, .. }
+            | Self::ErrorMessage { command, .. }
+            | Self::ModeCode { command, .. }
+            | Self::Broadcast { command }
+            | Self::Subaddress { command, .. } => Some(command),
+            Self::Overflow { .. } => None,
+        }
+    }
+
+    /// Get the status word of the event
+    pub fn status(&self) -> Option<&StatusWord> {
+        match self {
+            Self::EndOfMessage { status, .. }
+            | Self::ErrorMessage { status, .. }
+            | Self::ModeCode { status, .. }
+            | Self::Subaddress { status, .. } => status.as_ref(),
+            Self::Broadcast { .. } | Self::Overflow { .. } => None,
+        }
+    }
+
+    /// Check if the event is an error message
+    #[must_use = "Returned value is not used"]
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::ErrorMessage { .. })
+    }
+
+    /// Check if the event is a queue overflow
+    #[must_use = "Returned value is not used"]
+    pub fn is_overflow(&self) -> bool {
+        matches!(self, Self::Overflow { .. })
+    }
+}
+
+/// A fixed-capacity queue of events
+///
+/// Logic posts events as messages complete, and the application
+/// drains them in the order they were posted. When the queue is
+/// about to fill, the last slot is used for an [Overflow](Event::Overflow)
+/// event that counts the events lost until it is removed, even if other
+/// events are removed first.
+///
+/// The queue needs room for at least one event and the overflow event,
+/// so a `SIZE` of less than two fails to compile.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let mut events = Events::<8>::new()
+///         .with_subaddress(TransmitReceive::Receive, SubAddress::Value(3));
+///
+///     let command = CommandWord::new()
+///         .with_address(Address::Value(5))
+///         .with_subaddress(SubAddress::Value(3))
+///         .with_word_count(1)
+///         .build()?;
+///
+///     let status = StatusWord::new()
+///         .with_address(Address::Value(5))
+///         .build()?;
+///
+///     events.post_message(command, Some(status));
+///
+///     assert_eq!(events.pop(), Some(Event::Subaddress { command, status: Some(status) }));
+///     assert!(events.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Events<const SIZE: usize = 32> {
+    events: [Option<Event>; SIZE],
+    start: usize,
+    length: usize,
+    subaddresses: [u32; 2],
+}
+
+impl<const SIZE: usize> Events<SIZE> {
+    /// Fails to compile if the queue can't hold an event and an overflow
+    const CHECK_SIZE: () = assert!(SIZE >= 2, "event queues need at least two slots");
+
+    /// Create a new, empty event queue
+    pub fn new() -> Self {
+        let () = Self::CHECK_SIZE;
+        Self {
+            events: [None; SIZE],
+            start: 0,
+            length: 0,
+            subaddresses: [0; 2],
+        }
+    }
+
+    /// Constructor method to enable events for a subaddress
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the subaddress
+    /// * `subaddress` - The subaddress to post events for
+    ///
+    pub fn with_subaddress(mut self, direction: TransmitReceive, subaddress: SubAddress) -> Self {
+        self.set_subaddress(direction, subaddress, true);
+        self
+    }
+
+    /// Enable or disable events for a subaddress
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the subaddress
+    /// * `subaddress` - The subaddress to post events for
+    /// * `enabled` - True if events should be posted
+    ///
+    pub fn set_subaddress(
+        &mut self,
+        direction: TransmitReceive,
+        subaddress: SubAddress,
+        enabled: bool,
+    ) {
+        let bit = bit(subaddress);
+        match enabled {
+            true => self.subaddresses[direction as usize] |= bit,
+            false => self.subaddresses[direction as usize] &= !bit,
+        }
+    }
+
+    /// Check if events are enabled for a subaddress
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the subaddress
+    /// * `subaddress` - The subaddress to check
+    ///
+    #[must_use = "Returned value is not used"]
+    pub fn is_subaddress(&self, direction: TransmitReceive, subaddress: SubAddress) -> bool {
+        let bit = bit(subaddress);
+        self.subaddresses[direction as usize] & bit != 0
+    }
+
+    /// Post an event to the queue
+    ///
+    /// If the queue is full, or the last event in the queue is
+    /// an overflow event, the event is counted by the overflow
+    /// event instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to post
+    ///
+    pub fn post(&mut self, event: Event) {
+        if let Some(Event::Overflow { lost }) = self.last_mut() {
+            *lost = lost.saturating_add(1);
+        } else if self.length + 1 < SIZE {
+            self.push(event);
+        } else {
+            self.push(Event::Overflow { lost: 1 });
+        }
+    }
+
+    /// Post the event for a completed message
+    ///
+    /// Posts a single event, checked in order:
+    ///
+    /// * [ErrorMessage](Event::ErrorMessage) if the status word has an error flag
+    ///   set, or there is no status word for a command that isn't broadcast
+    /// * [ModeCode](Event::ModeCode) if the command is a mode code
+    /// * [Broadcast](Event::Broadcast) if the command is broadcast
+    /// * [Subaddress](Event::Subaddress) if events are enabled for the subaddress
+    /// * [EndOfMessage](Event::EndOfMessage) otherwise
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word of the message
+    /// * `status` - The status word sent in response, if any
+    ///
+    pub fn post_message(&mut self, command: CommandWord, status: Option<StatusWord>) {
+        let broadcast = command.address().is_broadcast();
+        let error = match status {
+            Some(s) => s.is_error(),
+            None => !broadcast,
+        };
+
+        self.post(if error {
+            Event::ErrorMessage { command, status }
+        } else if command.is_mode_code() {
+            Event::ModeCode { command, status }
+        } else if broadcast {
+            Event::Broadcast { command }
+        } else if self.is_subaddress(command.transmit_receive(), command.subaddress()) {
+            Event::Subaddress { command, status }
+        } else {
+            Event::EndOfMessage { command, status }
+        });
+    }
+
+    /// Remove the oldest event from the queue
+    pub fn pop(&mut self) -> Option<Event> {
+        if self.length == 0 {
+            return None;
+        }
+        let event = self.events[self.start].take();
+        self.start = (self.start + 1) % SIZE;
+        self.length -= 1;
+        event
+    }
+
+    /// Get the oldest event without removing it
+    pub fn peek(&self) -> Option<&Event> {
+        match self.length {
+            0 => None,
+            _ => self.events[self.start].as_ref(),
+        }
+    }
+
+    /// Get an iterator that removes events from the queue
+    pub fn drain(&mut self) -> impl Iterator<Item = Event> + '_ {
+        core::iter::from_fn(|| self.pop())
+    }
+
+    /// Remove all events from the queue
+    pub fn clear(&mut self) {
+        self.events = [None; SIZE];
+        self.start = 0;
+        self.length = 0;
+    }
+
+    /// Get the number of events in the queue
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Check if the queue is empty
+    #[must_use = "Returned value is not used"]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Check if the queue has lost events
+    ///
+    /// This is true until the overflow event is removed.
+    #[must_use = "Returned value is not used"]
+    pub fn is_overflow(&self) -> bool {
+        self.length > 0 && matches!(self.events[self.last()], Some(Event::Overflow { .. }))
+    }
+
+    /// Add an event to the end of the queue
+    fn push(&mut self, event: Event) {
+        self.events[(self.start + self.length) % SIZE] = Some(event);
+        self.length += 1;
+    }
+
+    /// Get the last event in the queue
+    fn last_mut(&mut self) -> Option<&mut Event> {
+        match self.length {
+            0 => None,
+            _ => self.events[self.last()].as_mut(),
+        }
+    }
+
+    /// Get the index of the last event in the queue
+    fn last(&self) -> usize {
+        (self.start + self.length + SIZE - 1) % SIZE
+    }
+}
+
+impl<const SIZE: usize> Default for Events<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get the bit for a subaddress in a mask
+///
+/// Out-of-range subaddresses have no bit.
+fn bit(subaddress: SubAddress) -> u32 {
+    subaddress.index().map(|i| 1 << i).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::MessageError;
+    use crate::flags::Address;
+    use crate::parse::command;
+    use crate::Word;
+
+    fn status() -> StatusWord {
+        StatusWord::new()
+            .with_address(Address::Value(5))
+            .with_calculated_parity()
+    }
+
+    #[test]
+    fn test_events_order() {
+        let mut events = Events::<4>::new();
+        events.post_message(command("05-R-01-01"), Some(status()));
+        events.post_message(command("05-R-02-01"), Some(status()));
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events.pop().and_then(|e| e.command().copied()),
+            Some(command("05-R-01-01"))
+        );
+        assert_eq!(
+            events.pop().and_then(|e| e.command().copied()),
+            Some(command("05-R-02-01"))
+        );
+        assert_eq!(events.pop(), None);
+    }
+
+    #[test]
+    fn test_events_end_of_message() {
+        let mut events = Events::<4>::new();
+        events.post_message(command("05-R-01-01"), Some(status()));
+        assert_eq!(
+            events.pop(),
+            Some(Event::EndOfMessage {
+                command: command("05-R-01-01"),
+                status: Some(status())
+            })
+        );
+    }
+
+    #[test]
+    fn test_events_error_message() {
+        let mut events = Events::<4>::new();
+        let error = status().with_message_error(MessageError::Error);
+        events.post_message(command("05-R-01-01"), Some(error));
+        events.post_message(command("05-R-01-01"), None);
+
+        assert!(events.pop().unwrap().is_error());
+        assert_eq!(
+            events.pop(),
+            Some(Event::ErrorMessage {
+                command: command("05-R-01-01"),
+                status: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_events_mode_code() {
+        let mut events = Events::<4>::new();
+        events.post_message(command("05-T-00-Synchronize"), Some(status()));
+        assert!(matches!(events.pop(), Some(Event::ModeCode { .. })));
+    }
+
+    #[test]
+    fn test_events_broadcast() {
+        let mut events = Events::<4>::new();
+        events.post_message(command("31-R-01-01"), None);
+        assert_eq!(
+            events.pop(),
+            Some(Event::Broadcast {
+                command: command("31-R-01-01")
+            })
+        );
+    }
+
+    #[test]
+    fn test_events_subaddress() {
+        let mut events =
+            Events::<4>::new().with_subaddress(TransmitReceive::Receive, SubAddress::Value(3));
+        assert!(events.is_subaddress(TransmitReceive::Receive, SubAddress::Value(3)));
+        assert!(!events.is_subaddress(TransmitReceive::Transmit, SubAddress::Value(3)));
+
+        events.post_message(command("05-R-03-01"), Some(status()));
+        assert!(matches!(events.pop(), Some(Event::Subaddress { .. })));
+
+        events.set_subaddress(TransmitReceive::Receive, SubAddress::Value(3), false);
+        events.post_message(command("05-R-03-01"), Some(status()));
+        assert!(matches!(events.pop(), Some(Event::EndOfMessage { .. })));
+    }
+
+    #[test]
+    fn test_events_subaddress_out_of_range() {
+        let mut events =
+            Events::<4>::new().with_subaddress(TransmitReceive::Receive, SubAddress::Value(35));
+        assert!(!events.is_subaddress(TransmitReceive::Receive, SubAddress::Value(35)));
+        assert!(!events.is_subaddress(TransmitReceive::Receive, SubAddress::Value(3)));
+
+        events.set_subaddress(TransmitReceive::Receive, SubAddress::Value(3), true);
+        events.set_subaddress(TransmitReceive::Receive, SubAddress::Value(35), false);
+        assert!(events.is_subaddress(TransmitReceive::Receive, SubAddress::Value(3)));
+    }
+
+    #[test]
+    fn test_events_overflow() {
+        let mut events = Events::<3>::new();
+        for _ in 0..5 {
+            events.post_message(command("05-R-01-01"), Some(status()));
+        }
+
+        assert!(events.is_overflow());
+        assert_eq!(events.len(), 3);
+
+        let drained: std::vec::Vec<Event> = events.drain().collect();
+        assert!(!drained[0].is_overflow());
+        assert!(!drained[1].is_overflow());
+        assert_eq!(drained[2], Event::Overflow { lost: 3 });
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_events_overflow_partial_pop() {
+        let mut events = Events::<3>::new();
+        for _ in 0..5 {
+            events.post_message(command("05-R-01-01"), Some(status()));
+        }
+
+        assert!(!events.pop().unwrap().is_overflow());
+        assert!(events.is_overflow());
+
+        events.post_message(command("05-R-02-01"), Some(status()));
+        events.post_message(command("05-R-03-01"), Some(status()));
+
+        let drained: std::vec::Vec<Event> = events.drain().collect();
+        assert_eq!(drained.len(), 2);
+        assert_eq!(drained[0].command(), Some(&command("05-R-01-01")));
+        assert_eq!(drained[1], Event::Overflow { lost: 5 });
+        assert!(!events.is_overflow());
+
+        events.post_message(command("05-R-04-01"), Some(status()));
+        assert_eq!(
+            events.pop().unwrap().command(),
+            Some(&command("05-R-04-01"))
+        );
+    }
+
+    #[test]
+    fn test_events_overflow_smallest() {
+        let mut events = Events::<2>::new();
+        events.post_message(command("05-R-01-01"), Some(status()));
+        events.post_message(command("05-R-02-01"), Some(status()));
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events.pop().unwrap().command(),
+            Some(&command("05-R-01-01"))
+        );
+        assert_eq!(events.pop(), Some(Event::Overflow { lost: 1 }));
+    }
+
+    #[test]
+    fn test_events_wrap() {
+        let mut events = Events::<3>::new();
+        for i in 1..10 {
+            events.post_message(command(&std::format!("05-R-{:02}-01", i)), Some(status()));
+            let event = events.pop().unwrap();
+            assert_eq!(
+                event.command(),
+                Some(&command(&std::format!("05-R-{:02}-01", i)))
+            );
+            assert_eq!(event.status(), Some(&status()));
+        }
+        assert_eq!(events.peek(), None);
+    }
+
+    #[test]
+    fn test_events_clear() {
+        let mut events = Events::<3>::default();
+        events.post_message(command("05-R-01-01"), Some(status()));
+        assert!(events.peek().is_some());
+        events.clear();
+        assert!(events.is_empty());
+    }
+}
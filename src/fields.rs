@@ -5,7 +5,9 @@ use crate::Word;
 /// Represents a field inside of a 16-bit word
 ///
 /// Given a mask and offset, the Field struct can get
-/// or set between 1 and 8 bits in a u16 word.
+/// or set between 1 and 8 bits in a u16 word, or read
+/// and write up to 16 bits using [Field::read] and
+/// [Field::write].
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct Field {
     /// The mask used to isolate the value
@@ -38,20 +40,53 @@ impl Field {
         Self::new().with_mask(mask).with_offset()
     }
 
+    /// Get the mask used to isolate the value
+    pub const fn mask(&self) -> u16 {
+        self.mask
+    }
+
+    /// Get the offset used to shift the value
+    pub const fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Get the number of bits covered by the mask
+    pub const fn width(&self) -> u32 {
+        self.mask.count_ones()
+    }
+
     /// Read the value of the field from a data word
     pub fn get<T: Word, F: From<u16>>(&self, word: &T) -> F {
-        let value = word.as_value() & self.mask;
-        F::from(value >> self.offset)
+        F::from(self.read(word))
     }
 
     /// Write the value of the field to a data word
     pub fn set<T: Word>(&self, word: &mut T, value: u8) {
-        let value = (value as u16) << self.offset;
+        self.write(word, value as u16);
+    }
+
+    /// Read the full (up to 16-bit) value of the field from a word
+    pub fn read<T: Word>(&self, word: &T) -> u16 {
+        (word.as_value() & self.mask) >> self.offset
+    }
+
+    /// Write a full (up to 16-bit) value of the field to a word
+    ///
+    /// Bits of the value that do not fit in the field
+    /// are discarded.
+    pub fn write<T: Word>(&self, word: &mut T, value: u16) {
+        let value = value.checked_shl(self.offset).unwrap_or(0);
         let data = word.as_value() & !self.mask;
         word.set_value(data | (value & self.mask));
     }
 }
 
+impl Default for Field {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Mask for parsing the terminal address of a command word.
 pub(crate) const COMMAND_ADDRESS: u16 = 0b1111100000000000;
 
@@ -151,7 +186,7 @@ mod tests {
     #[test]
     fn test_field_clone() {
         let field1 = Field::from(0b1010101010101010);
-        let field2 = field1.clone();
+        let field2 = Clone::clone(&field1);
         assert_eq!(field1, field2);
     }
 
@@ -332,6 +367,34 @@ mod tests {
         assert_eq!(word.as_value(), expected);
     }
 
+    #[test]
+    fn test_field_accessors() {
+        let field = Field::from(0b0000011111000000);
+        assert_eq!(field.mask(), 0b0000011111000000);
+        assert_eq!(field.offset(), 6);
+        assert_eq!(field.width(), 5);
+    }
+
+    #[test]
+    fn test_field_read_write_wide() {
+        let field = Field::from(0b0011111111111100);
+        let mut word = DataWord::from(0b1100000000000011);
+
+        field.write(&mut word, 0b111100001111);
+        assert_eq!(word.as_value(), 0b1111110000111111);
+        assert_eq!(field.read(&word), 0b111100001111);
+        assert!(word.check_parity());
+    }
+
+    #[test]
+    fn test_field_write_truncated() {
+        let field = Field::from(0b0000000011110000);
+        let mut word = DataWord::from(0);
+
+        field.write(&mut word, 0b111111);
+        assert_eq!(word.as_value(), 0b0000000011110000);
+    }
+
     #[test]
     fn test_field_set_4() {
         let mask = 0b0000000000000011;
@@ -1,5 +1,8 @@
 //! Flags parsed from fields
 
+/// The number of subaddresses (including mode code subaddresses)
+pub(crate) const SUBADDRESSES: usize = 32;
+
 /// A flag used by the bus controller to manage remote terminals on the bus.
 ///
 /// Mode Codes are flags defined by the 1553 standard to provide the Bus
@@ -20,6 +23,7 @@
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum ModeCode {
     /// Dynamic Bus Control Mode Code is used to pass control of the data
@@ -182,6 +186,51 @@ impl ModeCode {
     pub const fn is_unknown(&self) -> bool {
         matches!(self, Self::UnknownModeCode(_))
     }
+
+    /// Get the transmit/receive bit that the mode code is defined for
+    ///
+    /// Follows Table I of the standard, which assigns a single
+    /// direction to every mode code that isn't reserved.
+    pub(crate) fn direction(&self) -> Option<TransmitReceive> {
+        match self.value() {
+            0b00000..=0b01000 => Some(TransmitReceive::Transmit),
+            0b10000 | 0b10010 | 0b10011 => Some(TransmitReceive::Transmit),
+            0b10001 | 0b10100 | 0b10101 => Some(TransmitReceive::Receive),
+            _ => None,
+        }
+    }
+
+    /// Get the number of data words sent with the mode code
+    ///
+    /// Unlike [has_data](Self::has_data), this uses the MSB of
+    /// the value for every mode code, including reserved ones.
+    pub(crate) fn data_words(&self) -> usize {
+        usize::from(self.value() & 0b10000 != 0)
+    }
+
+    /// Get the name of the mode code, if it's recognized
+    pub(crate) const fn name(&self) -> Option<&'static str> {
+        match self {
+            Self::DynamicBusControl => Some("DynamicBusControl"),
+            Self::Synchronize => Some("Synchronize"),
+            Self::TransmitStatusWord => Some("TransmitStatusWord"),
+            Self::InitiateSelfTest => Some("InitiateSelfTest"),
+            Self::TransmitterShutdown => Some("TransmitterShutdown"),
+            Self::OverrideTransmitterShutdown => Some("OverrideTransmitterShutdown"),
+            Self::InhibitTerminalFlagBit => Some("InhibitTerminalFlagBit"),
+            Self::OverrideInhibitTerminalFlagBit => Some("OverrideInhibitTerminalFlagBit"),
+            Self::ResetRemoteTerminal => Some("ResetRemoteTerminal"),
+            Self::TransmitVectorWord => Some("TransmitVectorWord"),
+            Self::SynchronizeWithDataWord => Some("SynchronizeWithDataWord"),
+            Self::TransmitLastCommandWord => Some("TransmitLastCommandWord"),
+            Self::TransmitBITWord => Some("TransmitBITWord"),
+            Self::SelectedTransmitterShutdown => Some("SelectedTransmitterShutdown"),
+            Self::OverrideSelectedTransmitterShutdown => {
+                Some("OverrideSelectedTransmitterShutdown")
+            }
+            Self::UnknownModeCode(_) => None,
+        }
+    }
 }
 
 impl From<u8> for ModeCode {
@@ -244,6 +293,16 @@ impl From<ModeCode> for u16 {
     }
 }
 
+impl core::fmt::Display for ModeCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (self.name(), f.alternate()) {
+            (Some(name), true) => write!(f, "{} ({})", name, self.value()),
+            (Some(name), false) => write!(f, "{}", name),
+            (None, _) => write!(f, "UnknownModeCode({})", self.value()),
+        }
+    }
+}
+
 /// The direction of message transmission from the point of view of the remote terminal.
 ///
 /// This flag is available in bit 9 (index 5). A transmit bit (logic 1)
@@ -255,6 +314,7 @@ impl From<ModeCode> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum TransmitReceive {
     /// The remote terminal is receiving data
@@ -308,6 +368,17 @@ impl From<TransmitReceive> for u16 {
     }
 }
 
+impl core::fmt::Display for TransmitReceive {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (self, f.alternate()) {
+            (Self::Receive, false) => write!(f, "R"),
+            (Self::Transmit, false) => write!(f, "T"),
+            (Self::Receive, true) => write!(f, "Receive"),
+            (Self::Transmit, true) => write!(f, "Transmit"),
+        }
+    }
+}
+
 /// The address of a remote terminal
 ///
 /// This 5-bit address is found in the Terminal Address (TA) field located at bit times 4-8
@@ -318,6 +389,7 @@ impl From<TransmitReceive> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Address {
     /// The address references a terminal
@@ -374,6 +446,16 @@ impl From<Address> for u16 {
     }
 }
 
+impl core::fmt::Display for Address {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (self, f.alternate()) {
+            (Self::Value(v), true) => write!(f, "{} (terminal)", v),
+            (Self::Broadcast(v), true) => write!(f, "{} (broadcast)", v),
+            (_, false) => write!(f, "{:02}", u8::from(*self)),
+        }
+    }
+}
+
 /// The address of a subsystem within a remote terminal.
 ///
 /// This 5-bit address is found in the Subaddress (SA) field located at bit times
@@ -384,6 +466,7 @@ impl From<Address> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum SubAddress {
     /// The address references a subsystem
@@ -397,6 +480,17 @@ impl SubAddress {
     /// The maximum real value that can be addressed
     const MAX: u8 = 0b11111;
 
+    /// Get the index of the subaddress in a table of [SUBADDRESSES] entries
+    ///
+    /// Out-of-range values such as `Value(35)` have no index,
+    /// rather than aliasing a subaddress that is in range.
+    pub(crate) const fn index(&self) -> Option<usize> {
+        match *self {
+            Self::Value(k) | Self::ModeCode(k) if (k as usize) < SUBADDRESSES => Some(k as usize),
+            _ => None,
+        }
+    }
+
     /// Check if this enum contains an address
     #[must_use = "Returned value is not used"]
     pub const fn is_value(&self) -> bool {
@@ -440,6 +534,16 @@ impl From<SubAddress> for u16 {
     }
 }
 
+impl core::fmt::Display for SubAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (self, f.alternate()) {
+            (Self::Value(v), true) => write!(f, "{} (subaddress)", v),
+            (Self::ModeCode(v), true) => write!(f, "{} (mode code)", v),
+            (_, false) => write!(f, "{:02}", u8::from(*self)),
+        }
+    }
+}
+
 /// Used to differentiate between a command and status word.
 ///
 /// The instrumentation bit in the status word is always set to a logic 0,
@@ -457,6 +561,7 @@ impl From<SubAddress> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Instrumentation {
     /// The containing word is a status word
@@ -510,6 +615,15 @@ impl From<Instrumentation> for u16 {
     }
 }
 
+impl core::fmt::Display for Instrumentation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Status => write!(f, "Status"),
+            Self::Command => write!(f, "Command"),
+        }
+    }
+}
+
 /// Used by a remote terminal to tell the bus controller that it needs to be serviced.
 ///
 /// This flag is located at bit time 11 (index 7) and is typically used when
@@ -523,6 +637,7 @@ impl From<Instrumentation> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum ServiceRequest {
     /// This terminal does not require servicing
@@ -576,6 +691,15 @@ impl From<ServiceRequest> for u16 {
     }
 }
 
+impl core::fmt::Display for ServiceRequest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoService => write!(f, "NoService"),
+            Self::Service => write!(f, "Service"),
+        }
+    }
+}
+
 /// Reserved bits that should always be zero
 ///
 /// Bit times 12-14 (index 8-10) are reserved for future growth of the standard
@@ -588,6 +712,7 @@ impl From<ServiceRequest> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Reserved {
     /// The reserved bits are empty
@@ -641,6 +766,15 @@ impl From<Reserved> for u16 {
     }
 }
 
+impl core::fmt::Display for Reserved {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Value(v) => write!(f, "{:#05b}", v),
+        }
+    }
+}
+
 /// Indicates that the remote terminal has received a valid broadcast command.
 ///
 /// On receiving such a command, the remote terminal sets this flag and
@@ -653,6 +787,7 @@ impl From<Reserved> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum BroadcastReceived {
     /// This terminal has not received a broadcast command
@@ -706,6 +841,15 @@ impl From<BroadcastReceived> for u16 {
     }
 }
 
+impl core::fmt::Display for BroadcastReceived {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotReceived => write!(f, "NotReceived"),
+            Self::Received => write!(f, "Received"),
+        }
+    }
+}
+
 /// Indicates that the remote terminal is busy
 ///
 /// The Busy bit, located at bit time 16 (index 12) is provided as
@@ -718,6 +862,7 @@ impl From<BroadcastReceived> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum TerminalBusy {
     /// This terminal is not busy
@@ -771,6 +916,15 @@ impl From<TerminalBusy> for u16 {
     }
 }
 
+impl core::fmt::Display for TerminalBusy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotBusy => write!(f, "NotBusy"),
+            Self::Busy => write!(f, "Busy"),
+        }
+    }
+}
+
 /// Informs the bus controller that the terminal has accepted bus control.
 ///
 /// This flag is set by remote terminals that have received the Dynamic Bus
@@ -784,6 +938,7 @@ impl From<TerminalBusy> for u16 {
 ///
 /// [^1]: [MIL-STD-1553 Tutorial](http://www.horntech.cn/techDocuments/MIL-STD-1553Tutorial.pdf)
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum DynamicBusAcceptance {
     /// This terminal has refused control of the bus
@@ -837,6 +992,15 @@ impl From<DynamicBusAcceptance> for u16 {
     }
 }
 
+impl core::fmt::Display for DynamicBusAcceptance {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotAccepted => write!(f, "NotAccepted"),
+            Self::Accepted => write!(f, "Accepted"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -844,7 +1008,7 @@ mod tests {
     #[test]
     fn test_mode_code_clone() {
         let item1 = ModeCode::InhibitTerminalFlagBit;
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
@@ -933,420 +1097,402 @@ mod tests {
 
     #[test]
     fn test_mode_code_is_transmit_0() {
-        assert_eq!(ModeCode::DynamicBusControl.is_transmit(), true);
+        assert!(ModeCode::DynamicBusControl.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_1() {
-        assert_eq!(ModeCode::Synchronize.is_transmit(), false);
+        assert!(!ModeCode::Synchronize.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_2() {
-        assert_eq!(ModeCode::TransmitStatusWord.is_transmit(), true);
+        assert!(ModeCode::TransmitStatusWord.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_3() {
-        assert_eq!(ModeCode::InitiateSelfTest.is_transmit(), true);
+        assert!(ModeCode::InitiateSelfTest.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_4() {
-        assert_eq!(ModeCode::TransmitterShutdown.is_transmit(), true);
+        assert!(ModeCode::TransmitterShutdown.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_5() {
-        assert_eq!(ModeCode::OverrideTransmitterShutdown.is_transmit(), true);
+        assert!(ModeCode::OverrideTransmitterShutdown.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_6() {
-        assert_eq!(ModeCode::InhibitTerminalFlagBit.is_transmit(), true);
+        assert!(ModeCode::InhibitTerminalFlagBit.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_7() {
-        assert_eq!(ModeCode::OverrideInhibitTerminalFlagBit.is_transmit(), true);
+        assert!(ModeCode::OverrideInhibitTerminalFlagBit.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_8() {
-        assert_eq!(ModeCode::ResetRemoteTerminal.is_transmit(), true);
+        assert!(ModeCode::ResetRemoteTerminal.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_9() {
-        assert_eq!(ModeCode::TransmitVectorWord.is_transmit(), true);
+        assert!(ModeCode::TransmitVectorWord.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_10() {
-        assert_eq!(ModeCode::SynchronizeWithDataWord.is_transmit(), true);
+        assert!(ModeCode::SynchronizeWithDataWord.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_11() {
-        assert_eq!(ModeCode::TransmitLastCommandWord.is_transmit(), true);
+        assert!(ModeCode::TransmitLastCommandWord.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_12() {
-        assert_eq!(ModeCode::TransmitBITWord.is_transmit(), true);
+        assert!(ModeCode::TransmitBITWord.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_13() {
-        assert_eq!(ModeCode::SelectedTransmitterShutdown.is_transmit(), false);
+        assert!(!ModeCode::SelectedTransmitterShutdown.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_14() {
-        assert_eq!(
-            ModeCode::OverrideSelectedTransmitterShutdown.is_transmit(),
-            false
-        );
+        assert!(!ModeCode::OverrideSelectedTransmitterShutdown.is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_transmit_15() {
-        assert_eq!(ModeCode::UnknownModeCode(0b11111u8).is_transmit(), false);
+        assert!(!ModeCode::UnknownModeCode(0b11111u8).is_transmit());
     }
 
     #[test]
     fn test_mode_code_is_receive_0() {
-        assert_eq!(ModeCode::DynamicBusControl.is_receive(), false);
+        assert!(!ModeCode::DynamicBusControl.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_1() {
-        assert_eq!(ModeCode::Synchronize.is_receive(), true);
+        assert!(ModeCode::Synchronize.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_2() {
-        assert_eq!(ModeCode::TransmitStatusWord.is_receive(), false);
+        assert!(!ModeCode::TransmitStatusWord.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_3() {
-        assert_eq!(ModeCode::InitiateSelfTest.is_receive(), false);
+        assert!(!ModeCode::InitiateSelfTest.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_4() {
-        assert_eq!(ModeCode::TransmitterShutdown.is_receive(), false);
+        assert!(!ModeCode::TransmitterShutdown.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_5() {
-        assert_eq!(ModeCode::OverrideTransmitterShutdown.is_receive(), false);
+        assert!(!ModeCode::OverrideTransmitterShutdown.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_6() {
-        assert_eq!(ModeCode::InhibitTerminalFlagBit.is_receive(), false);
+        assert!(!ModeCode::InhibitTerminalFlagBit.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_7() {
-        assert_eq!(ModeCode::OverrideInhibitTerminalFlagBit.is_receive(), false);
+        assert!(!ModeCode::OverrideInhibitTerminalFlagBit.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_8() {
-        assert_eq!(ModeCode::ResetRemoteTerminal.is_receive(), false);
+        assert!(!ModeCode::ResetRemoteTerminal.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_9() {
-        assert_eq!(ModeCode::TransmitVectorWord.is_receive(), false);
+        assert!(!ModeCode::TransmitVectorWord.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_10() {
-        assert_eq!(ModeCode::SynchronizeWithDataWord.is_receive(), false);
+        assert!(!ModeCode::SynchronizeWithDataWord.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_11() {
-        assert_eq!(ModeCode::TransmitLastCommandWord.is_receive(), false);
+        assert!(!ModeCode::TransmitLastCommandWord.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_12() {
-        assert_eq!(ModeCode::TransmitBITWord.is_receive(), false);
+        assert!(!ModeCode::TransmitBITWord.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_13() {
-        assert_eq!(ModeCode::SelectedTransmitterShutdown.is_receive(), true);
+        assert!(ModeCode::SelectedTransmitterShutdown.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_14() {
-        assert_eq!(
-            ModeCode::OverrideSelectedTransmitterShutdown.is_receive(),
-            true
-        );
+        assert!(ModeCode::OverrideSelectedTransmitterShutdown.is_receive());
     }
 
     #[test]
     fn test_mode_code_is_receive_15() {
-        assert_eq!(ModeCode::UnknownModeCode(0b11111u8).is_receive(), false);
+        assert!(!ModeCode::UnknownModeCode(0b11111u8).is_receive());
     }
 
     #[test]
     fn test_mode_code_has_data_0() {
-        assert_eq!(ModeCode::DynamicBusControl.has_data(), false);
+        assert!(!ModeCode::DynamicBusControl.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_1() {
-        assert_eq!(ModeCode::Synchronize.has_data(), true);
+        assert!(ModeCode::Synchronize.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_2() {
-        assert_eq!(ModeCode::TransmitStatusWord.has_data(), false);
+        assert!(!ModeCode::TransmitStatusWord.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_3() {
-        assert_eq!(ModeCode::InitiateSelfTest.has_data(), false);
+        assert!(!ModeCode::InitiateSelfTest.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_4() {
-        assert_eq!(ModeCode::TransmitterShutdown.has_data(), false);
+        assert!(!ModeCode::TransmitterShutdown.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_5() {
-        assert_eq!(ModeCode::OverrideTransmitterShutdown.has_data(), false);
+        assert!(!ModeCode::OverrideTransmitterShutdown.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_6() {
-        assert_eq!(ModeCode::InhibitTerminalFlagBit.has_data(), false);
+        assert!(!ModeCode::InhibitTerminalFlagBit.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_7() {
-        assert_eq!(ModeCode::OverrideInhibitTerminalFlagBit.has_data(), false);
+        assert!(!ModeCode::OverrideInhibitTerminalFlagBit.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_8() {
-        assert_eq!(ModeCode::ResetRemoteTerminal.has_data(), false);
+        assert!(!ModeCode::ResetRemoteTerminal.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_9() {
-        assert_eq!(ModeCode::TransmitVectorWord.has_data(), true);
+        assert!(ModeCode::TransmitVectorWord.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_10() {
-        assert_eq!(ModeCode::SynchronizeWithDataWord.has_data(), false);
+        assert!(!ModeCode::SynchronizeWithDataWord.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_11() {
-        assert_eq!(ModeCode::TransmitLastCommandWord.has_data(), true);
+        assert!(ModeCode::TransmitLastCommandWord.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_12() {
-        assert_eq!(ModeCode::TransmitBITWord.has_data(), true);
+        assert!(ModeCode::TransmitBITWord.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_13() {
-        assert_eq!(ModeCode::SelectedTransmitterShutdown.has_data(), true);
+        assert!(ModeCode::SelectedTransmitterShutdown.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_14() {
-        assert_eq!(
-            ModeCode::OverrideSelectedTransmitterShutdown.has_data(),
-            true
-        );
+        assert!(ModeCode::OverrideSelectedTransmitterShutdown.has_data());
     }
 
     #[test]
     fn test_mode_code_has_data_15() {
-        assert_eq!(ModeCode::UnknownModeCode(0b11111u8).has_data(), false);
+        assert!(!ModeCode::UnknownModeCode(0b11111u8).has_data());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_0() {
-        assert_eq!(ModeCode::DynamicBusControl.is_broadcast(), false);
+        assert!(!ModeCode::DynamicBusControl.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_1() {
-        assert_eq!(ModeCode::Synchronize.is_broadcast(), true);
+        assert!(ModeCode::Synchronize.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_2() {
-        assert_eq!(ModeCode::TransmitStatusWord.is_broadcast(), false);
+        assert!(!ModeCode::TransmitStatusWord.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_3() {
-        assert_eq!(ModeCode::InitiateSelfTest.is_broadcast(), true);
+        assert!(ModeCode::InitiateSelfTest.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_4() {
-        assert_eq!(ModeCode::TransmitterShutdown.is_broadcast(), true);
+        assert!(ModeCode::TransmitterShutdown.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_5() {
-        assert_eq!(ModeCode::OverrideTransmitterShutdown.is_broadcast(), true);
+        assert!(ModeCode::OverrideTransmitterShutdown.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_6() {
-        assert_eq!(ModeCode::InhibitTerminalFlagBit.is_broadcast(), true);
+        assert!(ModeCode::InhibitTerminalFlagBit.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_7() {
-        assert_eq!(
-            ModeCode::OverrideInhibitTerminalFlagBit.is_broadcast(),
-            true
-        );
+        assert!(ModeCode::OverrideInhibitTerminalFlagBit.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_8() {
-        assert_eq!(ModeCode::ResetRemoteTerminal.is_broadcast(), true);
+        assert!(ModeCode::ResetRemoteTerminal.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_9() {
-        assert_eq!(ModeCode::TransmitVectorWord.is_broadcast(), false);
+        assert!(!ModeCode::TransmitVectorWord.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_10() {
-        assert_eq!(ModeCode::SynchronizeWithDataWord.is_broadcast(), true);
+        assert!(ModeCode::SynchronizeWithDataWord.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_11() {
-        assert_eq!(ModeCode::TransmitLastCommandWord.is_broadcast(), false);
+        assert!(!ModeCode::TransmitLastCommandWord.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_12() {
-        assert_eq!(ModeCode::TransmitBITWord.is_broadcast(), false);
+        assert!(!ModeCode::TransmitBITWord.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_13() {
-        assert_eq!(ModeCode::SelectedTransmitterShutdown.is_broadcast(), true);
+        assert!(ModeCode::SelectedTransmitterShutdown.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_14() {
-        assert_eq!(
-            ModeCode::OverrideSelectedTransmitterShutdown.is_broadcast(),
-            true
-        );
+        assert!(ModeCode::OverrideSelectedTransmitterShutdown.is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_broadcast_15() {
-        assert_eq!(ModeCode::UnknownModeCode(0b11111u8).is_broadcast(), false);
+        assert!(!ModeCode::UnknownModeCode(0b11111u8).is_broadcast());
     }
 
     #[test]
     fn test_mode_code_is_unknown_0() {
-        assert_eq!(ModeCode::DynamicBusControl.is_unknown(), false);
+        assert!(!ModeCode::DynamicBusControl.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_1() {
-        assert_eq!(ModeCode::Synchronize.is_unknown(), false);
+        assert!(!ModeCode::Synchronize.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_2() {
-        assert_eq!(ModeCode::TransmitStatusWord.is_unknown(), false);
+        assert!(!ModeCode::TransmitStatusWord.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_3() {
-        assert_eq!(ModeCode::InitiateSelfTest.is_unknown(), false);
+        assert!(!ModeCode::InitiateSelfTest.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_4() {
-        assert_eq!(ModeCode::TransmitterShutdown.is_unknown(), false);
+        assert!(!ModeCode::TransmitterShutdown.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_5() {
-        assert_eq!(ModeCode::OverrideTransmitterShutdown.is_unknown(), false);
+        assert!(!ModeCode::OverrideTransmitterShutdown.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_6() {
-        assert_eq!(ModeCode::InhibitTerminalFlagBit.is_unknown(), false);
+        assert!(!ModeCode::InhibitTerminalFlagBit.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_7() {
-        assert_eq!(ModeCode::OverrideInhibitTerminalFlagBit.is_unknown(), false);
+        assert!(!ModeCode::OverrideInhibitTerminalFlagBit.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_8() {
-        assert_eq!(ModeCode::ResetRemoteTerminal.is_unknown(), false);
+        assert!(!ModeCode::ResetRemoteTerminal.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_9() {
-        assert_eq!(ModeCode::TransmitVectorWord.is_unknown(), false);
+        assert!(!ModeCode::TransmitVectorWord.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_10() {
-        assert_eq!(ModeCode::SynchronizeWithDataWord.is_unknown(), false);
+        assert!(!ModeCode::SynchronizeWithDataWord.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_11() {
-        assert_eq!(ModeCode::TransmitLastCommandWord.is_unknown(), false);
+        assert!(!ModeCode::TransmitLastCommandWord.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_12() {
-        assert_eq!(ModeCode::TransmitBITWord.is_unknown(), false);
+        assert!(!ModeCode::TransmitBITWord.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_13() {
-        assert_eq!(ModeCode::SelectedTransmitterShutdown.is_unknown(), false);
+        assert!(!ModeCode::SelectedTransmitterShutdown.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_14() {
-        assert_eq!(
-            ModeCode::OverrideSelectedTransmitterShutdown.is_unknown(),
-            false
-        );
+        assert!(!ModeCode::OverrideSelectedTransmitterShutdown.is_unknown());
     }
 
     #[test]
     fn test_mode_code_is_unknown_15() {
-        assert_eq!(ModeCode::UnknownModeCode(0b11111u8).is_unknown(), true);
+        assert!(ModeCode::UnknownModeCode(0b11111u8).is_unknown());
     }
 
     #[test]
@@ -1540,7 +1686,7 @@ mod tests {
     #[test]
     fn test_mode_code_from_u16_15() {
         assert_eq!(
-            ModeCode::from(0b101000_11111u16),
+            ModeCode::from(0b101_0001_1111_u16),
             ModeCode::UnknownModeCode(0b11111u8)
         );
     }
@@ -1720,28 +1866,28 @@ mod tests {
     #[test]
     fn test_transmit_receive_clone() {
         let item1 = TransmitReceive::Transmit;
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn test_transmit_receive_is_transmit_0() {
-        assert_eq!(TransmitReceive::Receive.is_transmit(), false);
+        assert!(!TransmitReceive::Receive.is_transmit());
     }
 
     #[test]
     fn test_transmit_receive_is_transmit_1() {
-        assert_eq!(TransmitReceive::Transmit.is_transmit(), true);
+        assert!(TransmitReceive::Transmit.is_transmit());
     }
 
     #[test]
     fn test_transmit_receive_is_receive_0() {
-        assert_eq!(TransmitReceive::Receive.is_receive(), true);
+        assert!(TransmitReceive::Receive.is_receive());
     }
 
     #[test]
     fn test_transmit_receive_is_receive_1() {
-        assert_eq!(TransmitReceive::Transmit.is_receive(), false);
+        assert!(!TransmitReceive::Transmit.is_receive());
     }
 
     #[test]
@@ -1797,28 +1943,28 @@ mod tests {
     #[test]
     fn test_address_clone() {
         let item1 = Address::Broadcast(0b11111u8);
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn test_address_is_value_0() {
-        assert_eq!(Address::Value(0b10101u8).is_value(), true);
+        assert!(Address::Value(0b10101u8).is_value());
     }
 
     #[test]
     fn test_address_is_value_2() {
-        assert_eq!(Address::Broadcast(0b11111u8).is_value(), false);
+        assert!(!Address::Broadcast(0b11111u8).is_value());
     }
 
     #[test]
     fn test_address_is_broadcast_0() {
-        assert_eq!(Address::Value(0b10101u8).is_broadcast(), false);
+        assert!(!Address::Value(0b10101u8).is_broadcast());
     }
 
     #[test]
     fn test_address_is_broadcast_2() {
-        assert_eq!(Address::Broadcast(0b11111u8).is_broadcast(), true);
+        assert!(Address::Broadcast(0b11111u8).is_broadcast());
     }
 
     #[test]
@@ -1874,38 +2020,51 @@ mod tests {
     #[test]
     fn test_subaddress_clone() {
         let item1 = SubAddress::ModeCode(0b11111u8);
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn test_subaddress_is_value_0() {
-        assert_eq!(SubAddress::Value(0b10101u8).is_value(), true);
+        assert!(SubAddress::Value(0b10101u8).is_value());
     }
 
     #[test]
     fn test_subaddress_is_value_2() {
-        assert_eq!(SubAddress::ModeCode(0b11111u8).is_value(), false);
+        assert!(!SubAddress::ModeCode(0b11111u8).is_value());
     }
 
     #[test]
     fn test_subaddress_is_value_3() {
-        assert_eq!(SubAddress::ModeCode(0b00000u8).is_value(), false);
+        assert!(!SubAddress::ModeCode(0b00000u8).is_value());
     }
 
     #[test]
     fn test_subaddress_is_mode_code_0() {
-        assert_eq!(SubAddress::Value(0b10101u8).is_mode_code(), false);
+        assert!(!SubAddress::Value(0b10101u8).is_mode_code());
     }
 
     #[test]
     fn test_subaddress_is_mode_code_2() {
-        assert_eq!(SubAddress::ModeCode(0b11111u8).is_mode_code(), true);
+        assert!(SubAddress::ModeCode(0b11111u8).is_mode_code());
     }
 
     #[test]
     fn test_subaddress_is_mode_code_3() {
-        assert_eq!(SubAddress::ModeCode(0b00000u8).is_mode_code(), true);
+        assert!(SubAddress::ModeCode(0b00000u8).is_mode_code());
+    }
+
+    #[test]
+    fn test_subaddress_index() {
+        assert_eq!(SubAddress::Value(3).index(), Some(3));
+        assert_eq!(SubAddress::ModeCode(0).index(), Some(0));
+        assert_eq!(SubAddress::ModeCode(31).index(), Some(31));
+    }
+
+    #[test]
+    fn test_subaddress_index_out_of_range() {
+        assert_eq!(SubAddress::Value(35).index(), None);
+        assert_eq!(SubAddress::ModeCode(32).index(), None);
     }
 
     #[test]
@@ -1985,28 +2144,28 @@ mod tests {
     #[test]
     fn test_instrumentation_clone() {
         let item1 = Instrumentation::Command;
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn test_instrumentation_is_status_0() {
-        assert_eq!(Instrumentation::Status.is_status(), true);
+        assert!(Instrumentation::Status.is_status());
     }
 
     #[test]
     fn test_instrumentation_is_status_1() {
-        assert_eq!(Instrumentation::Command.is_status(), false);
+        assert!(!Instrumentation::Command.is_status());
     }
 
     #[test]
     fn test_instrumentation_is_command_0() {
-        assert_eq!(Instrumentation::Status.is_command(), false);
+        assert!(!Instrumentation::Status.is_command());
     }
 
     #[test]
     fn test_instrumentation_is_command_1() {
-        assert_eq!(Instrumentation::Command.is_command(), true);
+        assert!(Instrumentation::Command.is_command());
     }
 
     #[test]
@@ -2062,28 +2221,28 @@ mod tests {
     #[test]
     fn test_service_request_clone() {
         let item1 = ServiceRequest::Service;
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn test_service_request_is_noservice_0() {
-        assert_eq!(ServiceRequest::NoService.is_noservice(), true);
+        assert!(ServiceRequest::NoService.is_noservice());
     }
 
     #[test]
     fn test_service_request_is_noservice_1() {
-        assert_eq!(ServiceRequest::Service.is_noservice(), false);
+        assert!(!ServiceRequest::Service.is_noservice());
     }
 
     #[test]
     fn test_service_request_is_service_0() {
-        assert_eq!(ServiceRequest::NoService.is_service(), false);
+        assert!(!ServiceRequest::NoService.is_service());
     }
 
     #[test]
     fn test_service_request_is_service_1() {
-        assert_eq!(ServiceRequest::Service.is_service(), true);
+        assert!(ServiceRequest::Service.is_service());
     }
 
     #[test]
@@ -2139,28 +2298,28 @@ mod tests {
     #[test]
     fn test_reserved_clone() {
         let item1 = Reserved::Value(0b111u8);
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn test_reserved_is_none_0() {
-        assert_eq!(Reserved::None.is_none(), true);
+        assert!(Reserved::None.is_none());
     }
 
     #[test]
     fn test_reserved_is_none_1() {
-        assert_eq!(Reserved::Value(0b111u8).is_none(), false);
+        assert!(!Reserved::Value(0b111u8).is_none());
     }
 
     #[test]
     fn test_reserved_is_value_0() {
-        assert_eq!(Reserved::None.is_value(), false);
+        assert!(!Reserved::None.is_value());
     }
 
     #[test]
     fn test_reserved_is_value_1() {
-        assert_eq!(Reserved::Value(0b111u8).is_value(), true);
+        assert!(Reserved::Value(0b111u8).is_value());
     }
 
     #[test]
@@ -2206,28 +2365,28 @@ mod tests {
     #[test]
     fn test_broadcast_received_clone() {
         let item1 = BroadcastReceived::Received;
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn test_broadcast_received_is_notreceived_0() {
-        assert_eq!(BroadcastReceived::NotReceived.is_notreceived(), true);
+        assert!(BroadcastReceived::NotReceived.is_notreceived());
     }
 
     #[test]
     fn test_broadcast_received_is_notreceived_1() {
-        assert_eq!(BroadcastReceived::Received.is_notreceived(), false);
+        assert!(!BroadcastReceived::Received.is_notreceived());
     }
 
     #[test]
     fn test_broadcast_received_is_received_0() {
-        assert_eq!(BroadcastReceived::NotReceived.is_received(), false);
+        assert!(!BroadcastReceived::NotReceived.is_received());
     }
 
     #[test]
     fn test_broadcast_received_is_received_1() {
-        assert_eq!(BroadcastReceived::Received.is_received(), true);
+        assert!(BroadcastReceived::Received.is_received());
     }
 
     #[test]
@@ -2286,28 +2445,28 @@ mod tests {
     #[test]
     fn test_terminal_busy_clone() {
         let item1 = TerminalBusy::Busy;
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn test_terminal_busy_is_notbusy_0() {
-        assert_eq!(TerminalBusy::NotBusy.is_notbusy(), true);
+        assert!(TerminalBusy::NotBusy.is_notbusy());
     }
 
     #[test]
     fn test_terminal_busy_is_notbusy_1() {
-        assert_eq!(TerminalBusy::Busy.is_notbusy(), false);
+        assert!(!TerminalBusy::Busy.is_notbusy());
     }
 
     #[test]
     fn test_terminal_busy_is_busy_0() {
-        assert_eq!(TerminalBusy::NotBusy.is_busy(), false);
+        assert!(!TerminalBusy::NotBusy.is_busy());
     }
 
     #[test]
     fn test_terminal_busy_is_busy_1() {
-        assert_eq!(TerminalBusy::Busy.is_busy(), true);
+        assert!(TerminalBusy::Busy.is_busy());
     }
 
     #[test]
@@ -2363,28 +2522,28 @@ mod tests {
     #[test]
     fn test_dynamic_bus_acceptance_clone() {
         let item1 = DynamicBusAcceptance::Accepted;
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn test_dynamic_bus_acceptance_is_notaccepted_0() {
-        assert_eq!(DynamicBusAcceptance::NotAccepted.is_notaccepted(), true);
+        assert!(DynamicBusAcceptance::NotAccepted.is_notaccepted());
     }
 
     #[test]
     fn test_dynamic_bus_acceptance_is_notaccepted_1() {
-        assert_eq!(DynamicBusAcceptance::Accepted.is_notaccepted(), false);
+        assert!(!DynamicBusAcceptance::Accepted.is_notaccepted());
     }
 
     #[test]
     fn test_dynamic_bus_acceptance_is_accepted_0() {
-        assert_eq!(DynamicBusAcceptance::NotAccepted.is_accepted(), false);
+        assert!(!DynamicBusAcceptance::NotAccepted.is_accepted());
     }
 
     #[test]
     fn test_dynamic_bus_acceptance_is_accepted_1() {
-        assert_eq!(DynamicBusAcceptance::Accepted.is_accepted(), true);
+        assert!(DynamicBusAcceptance::Accepted.is_accepted());
     }
 
     #[test]
@@ -2454,4 +2613,39 @@ mod tests {
     fn test_dynamic_bus_acceptance_to_u16_1() {
         assert_eq!(u16::from(DynamicBusAcceptance::Accepted), 1);
     }
+
+    #[test]
+    fn test_flags_display() {
+        assert_eq!(std::format!("{}", TransmitReceive::Transmit), "T");
+        assert_eq!(std::format!("{:#}", TransmitReceive::Receive), "Receive");
+        assert_eq!(std::format!("{}", Address::Value(5)), "05");
+        assert_eq!(
+            std::format!("{:#}", Address::Broadcast(31)),
+            "31 (broadcast)"
+        );
+        assert_eq!(std::format!("{}", SubAddress::ModeCode(31)), "31");
+        assert_eq!(
+            std::format!("{:#}", SubAddress::ModeCode(31)),
+            "31 (mode code)"
+        );
+        assert_eq!(std::format!("{}", ServiceRequest::Service), "Service");
+        assert_eq!(std::format!("{}", Reserved::Value(0b101)), "0b101");
+        assert_eq!(std::format!("{}", Reserved::None), "None");
+    }
+
+    #[test]
+    fn test_mode_code_display() {
+        assert_eq!(
+            std::format!("{}", ModeCode::TransmitBITWord),
+            "TransmitBITWord"
+        );
+        assert_eq!(
+            std::format!("{:#}", ModeCode::TransmitBITWord),
+            "TransmitBITWord (19)"
+        );
+        assert_eq!(
+            std::format!("{}", ModeCode::UnknownModeCode(10)),
+            "UnknownModeCode(10)"
+        );
+    }
 }
@@ -0,0 +1,215 @@
+//! Implementations of arbitrary::Arbitrary for flags, words and messages
+//!
+//! Flags cover every value of their field, while words, packets and
+//! messages are valid by construction. Values with faults can be
+//! generated with the methods on [Faults].
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{Faults, Source};
+use crate::errors::{MessageError, SubsystemError, TerminalError};
+use crate::flags::*;
+use crate::{CommandWord, DataWord, Fault, Message, Packet, StatusWord, WordType};
+
+impl Source for Unstructured<'_> {
+    fn range(&mut self, min: u16, max: u16) -> u16 {
+        self.int_in_range(min..=max).unwrap_or(min)
+    }
+}
+
+/// Implement Arbitrary for a flag enum with values from 0 to `$max`
+macro_rules! impl_flag {
+    ( $name:ident, $max:expr ) => {
+        impl<'a> Arbitrary<'a> for $name {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                Ok(Self::from(u.int_in_range(0u8..=$max)?))
+            }
+
+            fn size_hint(_: usize) -> (usize, Option<usize>) {
+                (1, Some(1))
+            }
+        }
+    };
+}
+
+impl_flag!(Address, 0b11111);
+impl_flag!(SubAddress, 0b11111);
+impl_flag!(TransmitReceive, 1);
+impl_flag!(ModeCode, 0b11111);
+impl_flag!(Instrumentation, 1);
+impl_flag!(ServiceRequest, 1);
+impl_flag!(Reserved, 0b111);
+impl_flag!(BroadcastReceived, 1);
+impl_flag!(TerminalBusy, 1);
+impl_flag!(DynamicBusAcceptance, 1);
+impl_flag!(MessageError, 1);
+impl_flag!(SubsystemError, 1);
+impl_flag!(TerminalError, 1);
+
+/// Implement Arbitrary for a type using a generator without faults
+macro_rules! impl_generated {
+    ( $name:ty, $generator:expr ) => {
+        impl<'a> Arbitrary<'a> for $name {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                Ok($generator(u, &Faults::new()))
+            }
+        }
+    };
+}
+
+impl_generated!(CommandWord, super::command_word);
+impl_generated!(StatusWord, super::status_word);
+impl_generated!(DataWord, super::data_word);
+impl_generated!(WordType, super::word_type);
+impl_generated!(Packet, super::packet);
+
+impl<'a, const WORDS: usize> Arbitrary<'a> for Message<WORDS> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(super::message(u, &Faults::new()))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Fault {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let index = u.int_in_range(0..=super::MAX_FAULT_INDEX)?;
+        Ok(match u.int_in_range(0u8..=5)? {
+            0 => Self::Parity(index),
+            1 => Self::Sync(index),
+            2 => Self::LongWordCount(index),
+            3 => Self::ShortWordCount(index),
+            4 => Self::Gap {
+                index,
+                bits: u.int_in_range(0..=super::MAX_FAULT_INDEX)?,
+            },
+            _ => Self::StatusAddress(Address::arbitrary(u)?),
+        })
+    }
+}
+
+impl Faults {
+    /// Generate a command word with these faults
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The unstructured data to generate from
+    ///
+    pub fn command_word(&self, u: &mut Unstructured<'_>) -> Result<CommandWord> {
+        Ok(super::command_word(u, self))
+    }
+
+    /// Generate a status word with these faults
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The unstructured data to generate from
+    ///
+    pub fn status_word(&self, u: &mut Unstructured<'_>) -> Result<StatusWord> {
+        Ok(super::status_word(u, self))
+    }
+
+    /// Generate a data word with these faults
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The unstructured data to generate from
+    ///
+    pub fn data_word(&self, u: &mut Unstructured<'_>) -> Result<DataWord> {
+        Ok(super::data_word(u, self))
+    }
+
+    /// Generate a packet with these faults
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The unstructured data to generate from
+    ///
+    pub fn packet(&self, u: &mut Unstructured<'_>) -> Result<Packet> {
+        Ok(super::packet(u, self))
+    }
+
+    /// Generate a message with these faults
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The unstructured data to generate from
+    ///
+    pub fn message<const WORDS: usize>(&self, u: &mut Unstructured<'_>) -> Result<Message<WORDS>> {
+        Ok(super::message(u, self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Word;
+
+    /// Create some pseudo-random bytes for a seed
+    fn bytes(seed: u32) -> [u8; 256] {
+        let mut state = seed.wrapping_mul(2654435761).wrapping_add(1);
+        core::array::from_fn(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+    }
+
+    #[test]
+    fn test_arbitrary_flags() {
+        for seed in 0..100 {
+            let data = bytes(seed);
+            let mut u = Unstructured::new(&data);
+            assert!(u8::from(Address::arbitrary(&mut u).unwrap()) <= 0b11111);
+            assert!(u8::from(Reserved::arbitrary(&mut u).unwrap()) <= 0b111);
+            let _ = TransmitReceive::arbitrary(&mut u).unwrap();
+            let _ = MessageError::arbitrary(&mut u).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_words() {
+        for seed in 0..100 {
+            let data = bytes(seed);
+            let mut u = Unstructured::new(&data);
+            assert!(CommandWord::arbitrary(&mut u).unwrap().check_parity());
+            assert!(StatusWord::arbitrary(&mut u).unwrap().check_parity());
+            assert!(DataWord::arbitrary(&mut u).unwrap().check_parity());
+            assert!(WordType::arbitrary(&mut u).unwrap().check_parity());
+            assert!(Packet::arbitrary(&mut u).unwrap().is_valid());
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_message() {
+        for seed in 0..100 {
+            let data = bytes(seed);
+            let mut u = Unstructured::new(&data);
+            assert!(Message::<33>::arbitrary(&mut u).unwrap().is_valid());
+            assert!(Message::<3>::arbitrary(&mut u).unwrap().is_valid());
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_exhausted() {
+        let mut u = Unstructured::new(&[]);
+        assert!(CommandWord::arbitrary(&mut u).unwrap().check_parity());
+        assert!(Message::<33>::arbitrary(&mut u).unwrap().is_valid());
+    }
+
+    #[test]
+    fn test_arbitrary_faults() {
+        let faults = Faults::new()
+            .with_parity_errors(true)
+            .with_sync_errors(true);
+
+        for seed in 0..100 {
+            let data = bytes(seed);
+            let mut u = Unstructured::new(&data);
+            assert!(!faults.command_word(&mut u).unwrap().check_parity());
+            assert!(!faults.status_word(&mut u).unwrap().check_parity());
+            assert!(!faults.data_word(&mut u).unwrap().check_parity());
+            assert!(!faults.packet(&mut u).unwrap().check_sync());
+            assert!(!faults.message::<8>(&mut u).unwrap().is_valid());
+        }
+    }
+}
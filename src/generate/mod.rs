@@ -0,0 +1,507 @@
+//! Generators for words, packets and messages used by property tests
+//!
+//! Generated values are valid by construction: every word has a
+//! correct parity bit, command words have a word count of 1-32 (or a
+//! recognized mode code), transmit commands never use the broadcast
+//! address, status words have no reserved bits set, and messages
+//! contain exactly the number of data words their command asks for.
+//! [Faults] can be used to break specific rules on purpose.
+
+use crate::{
+    Address, CommandWord, DataWord, Message, ModeCode, Packet, Reserved, StatusWord, SubAddress,
+    TransmitReceive, Word, WordType,
+};
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+
+#[cfg(feature = "proptest")]
+mod strategies;
+
+/// The largest value of the address, subaddress and mode code fields
+const MAX_FIELD: u16 = 0b11111;
+
+/// The maximum number of data words in a message
+const MAX_DATA: usize = 32;
+
+/// The largest word index or count used in generated faults
+const MAX_FAULT_INDEX: usize = 40;
+
+/// Sync patterns that are neither data nor service sync
+const BAD_SYNC: [u8; 6] = [0b000, 0b010, 0b011, 0b101, 0b110, 0b111];
+
+/// Faults to inject into generated values
+///
+/// By default no faults are injected and every generated value
+/// follows the rules described in the module documentation. Each
+/// fault is enabled independently and only affects the values it
+/// applies to.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// let faults = Faults::new()
+///     .with_parity_errors(true)
+///     .with_word_count_errors(true);
+///
+/// assert!(faults.parity_errors());
+/// assert!(!faults.sync_errors());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Faults {
+    parity: bool,
+    sync: bool,
+    word_count: bool,
+    reserved: bool,
+    mode_code: bool,
+}
+
+impl Faults {
+    /// Create a new set of faults with nothing enabled
+    pub const fn new() -> Self {
+        Self {
+            parity: false,
+            sync: false,
+            word_count: false,
+            reserved: false,
+            mode_code: false,
+        }
+    }
+
+    /// Constructor method to enable or disable parity errors
+    ///
+    /// Generated words have an inverted parity bit. Generated
+    /// messages have an inverted parity bit on one of their words.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to inject the fault
+    ///
+    pub const fn with_parity_errors(mut self, enabled: bool) -> Self {
+        self.parity = enabled;
+        self
+    }
+
+    /// Constructor method to enable or disable sync errors
+    ///
+    /// Generated packets have a sync pattern that is neither the
+    /// data sync nor the service sync.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to inject the fault
+    ///
+    pub const fn with_sync_errors(mut self, enabled: bool) -> Self {
+        self.sync = enabled;
+        self
+    }
+
+    /// Constructor method to enable or disable word count errors
+    ///
+    /// Generated messages start with a command word and contain
+    /// more or fewer data words than the command word asks for.
+    /// Messages with room for fewer than two words are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to inject the fault
+    ///
+    pub const fn with_word_count_errors(mut self, enabled: bool) -> Self {
+        self.word_count = enabled;
+        self
+    }
+
+    /// Constructor method to enable or disable reserved bits
+    ///
+    /// Generated status words have a non-zero value in the
+    /// reserved field, and generated messages start with a
+    /// status word unless word count errors are also enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to inject the fault
+    ///
+    pub const fn with_reserved_bits(mut self, enabled: bool) -> Self {
+        self.reserved = enabled;
+        self
+    }
+
+    /// Constructor method to enable or disable unknown mode codes
+    ///
+    /// Generated command words are always mode code commands and
+    /// may use any value of the mode code field, including values
+    /// that are reserved by the standard.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - True to inject the fault
+    ///
+    pub const fn with_unknown_mode_codes(mut self, enabled: bool) -> Self {
+        self.mode_code = enabled;
+        self
+    }
+
+    /// Check if parity errors are enabled
+    #[must_use = "Returned value is not used"]
+    pub const fn parity_errors(&self) -> bool {
+        self.parity
+    }
+
+    /// Check if sync errors are enabled
+    #[must_use = "Returned value is not used"]
+    pub const fn sync_errors(&self) -> bool {
+        self.sync
+    }
+
+    /// Check if word count errors are enabled
+    #[must_use = "Returned value is not used"]
+    pub const fn word_count_errors(&self) -> bool {
+        self.word_count
+    }
+
+    /// Check if reserved bits are enabled
+    #[must_use = "Returned value is not used"]
+    pub const fn reserved_bits(&self) -> bool {
+        self.reserved
+    }
+
+    /// Check if unknown mode codes are enabled
+    #[must_use = "Returned value is not used"]
+    pub const fn unknown_mode_codes(&self) -> bool {
+        self.mode_code
+    }
+}
+
+/// A source of values for the generators
+///
+/// Implemented for `arbitrary::Unstructured` and for a byte buffer
+/// produced by proptest, so both share the same generation rules.
+trait Source {
+    /// Get a value between `min` and `max` (inclusive)
+    fn range(&mut self, min: u16, max: u16) -> u16;
+
+    /// Get a random boolean
+    fn flag(&mut self) -> bool {
+        self.range(0, 1) == 1
+    }
+
+    /// Get an index into a collection of the given length
+    fn index(&mut self, length: usize) -> usize {
+        self.range(0, length.saturating_sub(1) as u16).into()
+    }
+}
+
+/// Finish a word by calculating parity and optionally inverting it
+fn finish<T: Word>(word: T, faults: &Faults) -> T {
+    let mut word = word.with_calculated_parity();
+    if faults.parity {
+        word.set_parity(word.parity() ^ 1);
+    }
+    word
+}
+
+/// Generate a mode code command word
+fn mode_code_word(source: &mut dyn Source, faults: &Faults) -> CommandWord {
+    let code = if faults.mode_code {
+        ModeCode::from(source.range(0, MAX_FIELD))
+    } else {
+        let codes = || {
+            (0..=MAX_FIELD)
+                .map(ModeCode::from)
+                .filter(|c| !c.is_unknown())
+        };
+        let index = source.index(codes().count());
+        codes().nth(index).unwrap_or(ModeCode::DynamicBusControl)
+    };
+
+    let direction = match code.is_receive() {
+        true => TransmitReceive::Receive,
+        false => TransmitReceive::Transmit,
+    };
+
+    let address = match code.is_broadcast() {
+        true => source.range(0, MAX_FIELD),
+        false => source.range(0, MAX_FIELD - 1),
+    };
+
+    let subaddress = match source.flag() {
+        true => MAX_FIELD,
+        false => 0,
+    };
+
+    CommandWord::new()
+        .with_address(Address::from(address))
+        .with_transmit_receive(direction)
+        .with_subaddress(SubAddress::from(subaddress))
+        .with_mode_code(code)
+}
+
+/// Generate a command word with a word count no larger than `limit`
+fn data_command_word(source: &mut dyn Source, limit: usize) -> CommandWord {
+    let direction = TransmitReceive::from(source.range(0, 1));
+
+    let address = match direction.is_transmit() {
+        true => source.range(0, MAX_FIELD - 1),
+        false => source.range(0, MAX_FIELD),
+    };
+
+    let subaddress = source.range(1, MAX_FIELD - 1);
+    let count = source.range(1, limit.clamp(1, MAX_DATA) as u16);
+
+    CommandWord::new()
+        .with_address(Address::from(address))
+        .with_transmit_receive(direction)
+        .with_subaddress(SubAddress::from(subaddress))
+        .with_word_count(count as u8)
+}
+
+/// Generate a command word
+fn command_word(source: &mut dyn Source, faults: &Faults) -> CommandWord {
+    let word = match faults.mode_code || source.flag() {
+        true => mode_code_word(source, faults),
+        false => data_command_word(source, MAX_DATA),
+    };
+    finish(word, faults)
+}
+
+/// Generate a status word
+fn status_word(source: &mut dyn Source, faults: &Faults) -> StatusWord {
+    let mut word = StatusWord::from_value(source.range(0, u16::MAX))
+        .with_address(Address::from(source.range(0, MAX_FIELD - 1)))
+        .with_reserved(Reserved::None);
+
+    if faults.reserved {
+        word.set_reserved(Reserved::from(source.range(1, 0b111)));
+    }
+
+    finish(word, faults)
+}
+
+/// Generate a data word
+fn data_word(source: &mut dyn Source, faults: &Faults) -> DataWord {
+    finish(DataWord::from_value(source.range(0, u16::MAX)), faults)
+}
+
+/// Generate a word of any kind
+fn word_type(source: &mut dyn Source, faults: &Faults) -> WordType {
+    match source.range(0, 2) {
+        0 => command_word(source, faults).into(),
+        1 => status_word(source, faults).into(),
+        _ => data_word(source, faults).into(),
+    }
+}
+
+/// Generate a packet containing a word of any kind
+fn packet(source: &mut dyn Source, faults: &Faults) -> Packet {
+    let word = word_type(source, faults);
+
+    let sync = match (faults.sync, word.is_data()) {
+        (true, _) => BAD_SYNC[source.index(BAD_SYNC.len())],
+        (false, true) => Packet::DATA_SYNC,
+        (false, false) => Packet::SERV_SYNC,
+    };
+
+    Packet::new(sync, word.bytes(), word.parity())
+}
+
+/// Generate a message that fits in `WORDS` words
+///
+/// Command messages always use a normal subaddress, because the
+/// message treats the mode code of a command as its word count.
+fn message<const WORDS: usize>(source: &mut dyn Source, faults: &Faults) -> Message<WORDS> {
+    let mut message = Message::new();
+
+    // no header word will fit
+    if WORDS == 0 {
+        return message;
+    }
+
+    let limit = (WORDS - 1).min(MAX_DATA);
+    let valid = faults.with_parity_errors(false);
+
+    let command = match (faults.word_count, faults.reserved) {
+        _ if limit == 0 => false,
+        (true, _) => true,
+        (false, true) => false,
+        (false, false) => source.flag(),
+    };
+
+    let (header, count): (WordType, usize) = if command {
+        let word = data_command_word(source, limit).with_calculated_parity();
+        let count = match faults.word_count {
+            true => match source.index(limit) {
+                index if index < word.count() => index,
+                index => index + 1,
+            },
+            false => word.count(),
+        };
+        (word.into(), count)
+    } else {
+        let word = status_word(source, &valid);
+        (word.into(), source.index(limit + 1))
+    };
+
+    // the index of the word with a parity error, if any
+    let bad = match faults.parity {
+        true => source.index(count + 1),
+        false => usize::MAX,
+    };
+
+    let flip = |index: usize, parity: u8| match index == bad {
+        true => parity ^ 1,
+        false => parity,
+    };
+
+    match header {
+        WordType::Command(w) => message.add(w.with_parity(flip(0, w.parity()))),
+        WordType::Status(w) => message.add(w.with_parity(flip(0, w.parity()))),
+        _ => (),
+    }
+
+    for index in 1..=count {
+        let word = data_word(source, &valid);
+        message.add(word.with_parity(flip(index, word.parity())));
+    }
+
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A source that cycles through a fixed list of values
+    struct Cycle {
+        values: &'static [u16],
+        index: usize,
+    }
+
+    impl Cycle {
+        fn new(values: &'static [u16]) -> Self {
+            Self { values, index: 0 }
+        }
+    }
+
+    impl Source for Cycle {
+        fn range(&mut self, min: u16, max: u16) -> u16 {
+            let value = self.values[self.index % self.values.len()];
+            self.index += 1;
+            min + value % (max - min).saturating_add(1).max(1)
+        }
+    }
+
+    const VALUES: &[u16] = &[0, 1, 7, 31, 1000, 65535, 12, 5, 3, 2, 30, 9, 17, 4];
+
+    #[test]
+    fn test_faults_builder() {
+        let faults = Faults::new()
+            .with_parity_errors(true)
+            .with_sync_errors(true)
+            .with_word_count_errors(true)
+            .with_reserved_bits(true)
+            .with_unknown_mode_codes(true);
+
+        assert!(faults.parity_errors());
+        assert!(faults.sync_errors());
+        assert!(faults.word_count_errors());
+        assert!(faults.reserved_bits());
+        assert!(faults.unknown_mode_codes());
+        assert_eq!(Faults::default(), Faults::new());
+    }
+
+    #[test]
+    fn test_generate_words_valid() {
+        let faults = Faults::new();
+        let mut source = Cycle::new(VALUES);
+
+        for _ in 0..100 {
+            let word = command_word(&mut source, &faults);
+            assert!(word.check_parity());
+            assert!(word.word_count() >= 1);
+            assert!(!(word.is_transmit() && !word.is_mode_code() && word.address().is_broadcast()));
+            assert!(!word.is_mode_code() || !word.mode_code().is_unknown());
+
+            let word = status_word(&mut source, &faults);
+            assert!(word.check_parity());
+            assert_eq!(word.reserved(), Reserved::None);
+            assert!(!word.address().is_broadcast());
+
+            let word = data_word(&mut source, &faults);
+            assert!(word.check_parity());
+        }
+    }
+
+    #[test]
+    fn test_generate_words_faults() {
+        let faults = Faults::new()
+            .with_parity_errors(true)
+            .with_reserved_bits(true)
+            .with_unknown_mode_codes(true);
+        let mut source = Cycle::new(VALUES);
+
+        for _ in 0..100 {
+            let word = command_word(&mut source, &faults);
+            assert!(!word.check_parity());
+            assert!(word.is_mode_code());
+
+            let word = status_word(&mut source, &faults);
+            assert!(!word.check_parity());
+            assert!(word.reserved().is_value());
+
+            let word = data_word(&mut source, &faults);
+            assert!(!word.check_parity());
+        }
+    }
+
+    #[test]
+    fn test_generate_packets() {
+        let mut source = Cycle::new(VALUES);
+
+        for _ in 0..100 {
+            assert!(packet(&mut source, &Faults::new()).is_valid());
+
+            let faults = Faults::new().with_sync_errors(true);
+            let item = packet(&mut source, &faults);
+            assert!(item.check_parity());
+            assert!(!item.check_sync());
+        }
+    }
+
+    #[test]
+    fn test_generate_messages_valid() {
+        let mut source = Cycle::new(VALUES);
+
+        for _ in 0..100 {
+            assert!(message::<33>(&mut source, &Faults::new()).is_valid());
+            assert!(message::<4>(&mut source, &Faults::new()).is_valid());
+            assert!(message::<1>(&mut source, &Faults::new()).is_valid());
+        }
+    }
+
+    #[test]
+    fn test_generate_messages_faults() {
+        let mut source = Cycle::new(VALUES);
+
+        for _ in 0..100 {
+            let faults = Faults::new().with_parity_errors(true);
+            assert!(message::<8>(&mut source, &faults).validate().is_err());
+
+            let faults = Faults::new().with_word_count_errors(true);
+            let item = message::<8>(&mut source, &faults);
+            assert!(item.is_command());
+            assert_ne!(item.command().map(CommandWord::count), Some(item.count()));
+
+            let faults = Faults::new().with_reserved_bits(true);
+            let item = message::<8>(&mut source, &faults);
+            assert!(item.status().unwrap().reserved().is_value());
+        }
+    }
+
+    #[test]
+    fn test_generate_message_empty() {
+        let mut source = Cycle::new(VALUES);
+        let item = message::<0>(&mut source, &Faults::new());
+        assert!(item.is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+//! Implementations of proptest::arbitrary::Arbitrary for flags, words and messages
+//!
+//! Flags cover every value of their field. Words, packets and messages
+//! are generated from a buffer of bytes that proptest shrinks towards
+//! zero, and take [Faults] as their parameters so that faults can be
+//! injected with `any_with`.
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection::vec;
+use proptest::prop_oneof;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use super::{Faults, Source};
+use crate::errors::{MessageError, SubsystemError, TerminalError};
+use crate::flags::*;
+use crate::{CommandWord, DataWord, Fault, Message, Packet, StatusWord, WordType};
+
+/// The maximum number of bytes used to generate a single value
+const MAX_BYTES: usize = 256;
+
+/// A source that reads values from a buffer of bytes
+///
+/// Once the buffer is used up, every value is the minimum.
+struct Bytes<'a> {
+    data: &'a [u8],
+}
+
+impl Source for Bytes<'_> {
+    fn range(&mut self, min: u16, max: u16) -> u16 {
+        let (value, rest) = match self.data {
+            [a, b, rest @ ..] => (u16::from_be_bytes([*a, *b]), rest),
+            _ => (0, &[][..]),
+        };
+        self.data = rest;
+        min + (u32::from(value) % (u32::from(max - min) + 1)) as u16
+    }
+}
+
+/// Create a strategy that generates values from bytes
+fn generated<T: core::fmt::Debug + 'static>(
+    faults: Faults,
+    generator: fn(&mut dyn Source, &Faults) -> T,
+) -> BoxedStrategy<T> {
+    vec(any::<u8>(), 0..=MAX_BYTES)
+        .prop_map(move |data| generator(&mut Bytes { data: &data }, &faults))
+        .boxed()
+}
+
+/// Implement Arbitrary for a flag enum with values from 0 to `$max`
+macro_rules! impl_flag {
+    ( $name:ident, $max:expr ) => {
+        impl Arbitrary for $name {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                (0u8..=$max).prop_map(Self::from).boxed()
+            }
+        }
+    };
+}
+
+impl_flag!(Address, 0b11111);
+impl_flag!(SubAddress, 0b11111);
+impl_flag!(TransmitReceive, 1);
+impl_flag!(ModeCode, 0b11111);
+impl_flag!(Instrumentation, 1);
+impl_flag!(ServiceRequest, 1);
+impl_flag!(Reserved, 0b111);
+impl_flag!(BroadcastReceived, 1);
+impl_flag!(TerminalBusy, 1);
+impl_flag!(DynamicBusAcceptance, 1);
+impl_flag!(MessageError, 1);
+impl_flag!(SubsystemError, 1);
+impl_flag!(TerminalError, 1);
+
+/// Implement Arbitrary for a type using a generator
+macro_rules! impl_generated {
+    ( $name:ty, $generator:expr ) => {
+        impl Arbitrary for $name {
+            type Parameters = Faults;
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(faults: Self::Parameters) -> Self::Strategy {
+                generated(faults, $generator)
+            }
+        }
+    };
+}
+
+impl_generated!(CommandWord, super::command_word);
+impl_generated!(StatusWord, super::status_word);
+impl_generated!(DataWord, super::data_word);
+impl_generated!(WordType, super::word_type);
+impl_generated!(Packet, super::packet);
+
+impl<const WORDS: usize> Arbitrary for Message<WORDS> {
+    type Parameters = Faults;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(faults: Self::Parameters) -> Self::Strategy {
+        generated(faults, super::message::<WORDS>)
+    }
+}
+
+impl Arbitrary for Fault {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let index = || 0..=super::MAX_FAULT_INDEX;
+        prop_oneof![
+            index().prop_map(Self::Parity),
+            index().prop_map(Self::Sync),
+            index().prop_map(Self::LongWordCount),
+            index().prop_map(Self::ShortWordCount),
+            (index(), index()).prop_map(|(index, bits)| Self::Gap { index, bits }),
+            any::<Address>().prop_map(Self::StatusAddress),
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Word;
+    use proptest::arbitrary::any_with;
+    use proptest::{prop_assert, prop_assert_eq};
+
+    proptest::proptest! {
+        #[test]
+        fn test_strategy_flags(address in any::<Address>(), reserved in any::<Reserved>()) {
+            prop_assert!(u8::from(address) <= 0b11111);
+            prop_assert!(u8::from(reserved) <= 0b111);
+        }
+
+        #[test]
+        fn test_strategy_words(
+            command in any::<CommandWord>(),
+            status in any::<StatusWord>(),
+            data in any::<DataWord>(),
+            word in any::<WordType>(),
+        ) {
+            prop_assert!(command.check_parity());
+            prop_assert!(status.check_parity());
+            prop_assert!(status.reserved().is_none());
+            prop_assert!(data.check_parity());
+            prop_assert!(word.check_parity());
+        }
+
+        #[test]
+        fn test_strategy_packet(packet in any::<Packet>()) {
+            prop_assert!(packet.is_valid());
+        }
+
+        #[test]
+        fn test_strategy_message(message in any::<Message<33>>()) {
+            prop_assert!(message.is_valid());
+        }
+
+        #[test]
+        fn test_strategy_faults(
+            packet in any_with::<Packet>(Faults::new().with_sync_errors(true)),
+            message in any_with::<Message<8>>(Faults::new().with_word_count_errors(true)),
+        ) {
+            prop_assert!(!packet.check_sync());
+            prop_assert!(!message.is_valid());
+        }
+
+        #[test]
+        fn test_strategy_message_roundtrip(message in any::<Message<33>>(), fault in any::<Fault>()) {
+            let length = (message.length() * 20).div_ceil(8);
+            let mut buffer = [0u8; 256];
+
+            message.write(&mut buffer).unwrap();
+            let result = match message.is_command() {
+                true => Message::<33>::read_command(&buffer[..length]),
+                false => Message::<33>::read_status(&buffer[..length]),
+            };
+            prop_assert_eq!(result, Ok(message.clone()));
+
+            if fault.write_message(&message, &mut buffer).is_ok() {
+                let _ = Message::<33>::read_command(&buffer);
+                let _ = Message::<33>::read_status(&buffer);
+            }
+        }
+    }
+}
@@ -0,0 +1,1333 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::string::{String, ToString};
+use std::vec::Vec;
+use std::{format, vec};
+
+/// Rust keywords and generated method names that can't be used as parameter names
+const RESERVED_NAMES: &[&str] = &[
+    "as",
+    "async",
+    "await",
+    "break",
+    "const",
+    "continue",
+    "crate",
+    "dyn",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "impl",
+    "in",
+    "let",
+    "loop",
+    "match",
+    "mod",
+    "move",
+    "mut",
+    "pub",
+    "ref",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "super",
+    "trait",
+    "true",
+    "type",
+    "unsafe",
+    "use",
+    "where",
+    "while",
+    "abstract",
+    "become",
+    "box",
+    "do",
+    "final",
+    "macro",
+    "override",
+    "priv",
+    "typeof",
+    "unsized",
+    "virtual",
+    "yield",
+    "try",
+    "new",
+    "read",
+    "write",
+    "message",
+    "into_message",
+    "try_from",
+    "default",
+];
+
+/// Names that can't be used for messages because they would
+/// shadow types used by generated or surrounding code
+const RESERVED_TYPES: &[&str] = &["Self", "Option", "Result", "Default"];
+
+/// Types that can be used for parameters
+const TYPES: &[&str] = &["u8", "u16", "u32", "i8", "i16", "i32", "f32", "f64", "bool"];
+
+/// An error found while reading an interface control document
+///
+/// The line is the 1-based line of the source document on which
+/// the error was found.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct IcdError {
+    /// The line of the source document
+    line: usize,
+
+    /// A description of the error
+    message: String,
+}
+
+impl IcdError {
+    /// Create a new error for a line of the source document
+    fn new<T: Into<String>>(line: usize, message: T) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// Get the line of the source document
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Get a description of the error
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for IcdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for IcdError {}
+
+/// A parameter read from an interface control document
+#[derive(Clone, PartialEq, Debug)]
+struct ParameterSpec {
+    name: String,
+    kind: String,
+    word: usize,
+    mask: u16,
+    signed: bool,
+    scale: f64,
+    offset: f64,
+    unit: String,
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    description: String,
+    line: usize,
+}
+
+/// A message read from an interface control document
+#[derive(Clone, PartialEq, Debug)]
+struct MessageSpec {
+    name: String,
+    address: u8,
+    subaddress: u8,
+    transmit: bool,
+    word_count: u8,
+    description: String,
+    parameters: Vec<ParameterSpec>,
+    line: usize,
+}
+
+/// Generates Rust bindings from interface control documents
+///
+/// The generator reads message and parameter definitions from CSV or
+/// simple XML documents and emits Rust source that declares each message
+/// with the [icd_message](crate::icd_message) macro. The generated types
+/// wrap a [Message](crate::Message) initiated by a
+/// [CommandWord](crate::CommandWord), and read parameters through a
+/// [Field](crate::Field) of the data word.
+///
+/// This is intended to run on the host, usually in a build script, so
+/// that the bindings are regenerated whenever the ICD changes:
+///
+/// ```rust,no_run
+/// # use mil_std_1553b::*;
+/// # fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+///     let out = std::env::var("OUT_DIR")?;
+///
+///     Generator::new()
+///         .with_file("icd/navigation.csv")?
+///         .with_file("icd/air_data.xml")?
+///         .write(std::path::Path::new(&out).join("icd.rs"))?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// And then in the crate:
+///
+/// ```rust,ignore
+/// include!(concat!(env!("OUT_DIR"), "/icd.rs"));
+/// ```
+///
+/// ## CSV documents
+///
+/// Each row of a CSV document describes one parameter, and rows with
+/// the same message name are grouped into one message. The first row
+/// is a header that names the columns, in any order:
+///
+/// | Column        | Required | Description                               |
+/// |---------------|----------|-------------------------------------------|
+/// | `message`     | yes      | Name of the message                       |
+/// | `address`     | yes      | Terminal address (0-31)                   |
+/// | `subaddress`  | yes      | Subaddress (1-30)                         |
+/// | `direction`   | yes      | `T`/`Transmit` or `R`/`Receive`           |
+/// | `word_count`  | yes      | Number of data words (1-32)               |
+/// | `parameter`   | yes      | Name of the parameter                     |
+/// | `word`        | yes      | Index of the data word (0 is the first)   |
+/// | `mask`        | yes      | Bits of the data word (`0x`, `0b` or decimal) |
+/// | `type`        | no       | Rust type of the value (default `u16`)    |
+/// | `signed`      | no       | Two's complement raw value (default false)|
+/// | `scale`       | no       | Value of the least significant bit        |
+/// | `offset`      | no       | Value of a raw zero                       |
+/// | `unit`        | no       | Engineering unit                          |
+/// | `min`/`max`   | no       | Valid range of the engineering value      |
+/// | `description` | no       | Documentation for the parameter           |
+///
+/// Empty lines and lines beginning with `#` are ignored.
+///
+/// ## XML documents
+///
+/// XML documents contain `message` elements with the message columns as
+/// attributes, each of which contains `parameter` elements with the
+/// parameter columns as attributes:
+///
+/// ```xml
+/// <icd>
+///   <message name="NavData" address="5" subaddress="3" direction="T" word_count="2">
+///     <parameter name="altitude" word="0" mask="0xFFFF" type="f32" scale="0.5" unit="ft"/>
+///   </message>
+/// </icd>
+/// ```
+///
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Generator {
+    messages: Vec<MessageSpec>,
+}
+
+impl Generator {
+    /// Create a new, empty generator
+    pub fn new() -> Self {
+        Self {
+            messages: Vec::new(),
+        }
+    }
+
+    /// Constructor method to read a CSV document
+    ///
+    /// See [read_csv][Self::read_csv] for more information.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The contents of a CSV document
+    ///
+    pub fn with_csv(mut self, text: &str) -> Result<Self, IcdError> {
+        self.read_csv(text)?;
+        Ok(self)
+    }
+
+    /// Constructor method to read an XML document
+    ///
+    /// See [read_xml][Self::read_xml] for more information.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The contents of an XML document
+    ///
+    pub fn with_xml(mut self, text: &str) -> Result<Self, IcdError> {
+        self.read_xml(text)?;
+        Ok(self)
+    }
+
+    /// Constructor method to read a CSV or XML file
+    ///
+    /// See [read_file][Self::read_file] for more information.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of a document
+    ///
+    pub fn with_file<P: AsRef<Path>>(mut self, path: P) -> Result<Self, IcdError> {
+        self.read_file(path)?;
+        Ok(self)
+    }
+
+    /// Get the number of messages that have been read
+    pub fn count(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Read a CSV or XML file
+    ///
+    /// The format of the document is chosen using the extension
+    /// of the file (`.xml` for XML, anything else for CSV).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of a document
+    ///
+    pub fn read_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), IcdError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| IcdError::new(0, format!("{}: {}", path.display(), e)))?;
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(e) if e.eq_ignore_ascii_case("xml") => self.read_xml(&text),
+            _ => self.read_csv(&text),
+        }
+    }
+
+    /// Read messages from a CSV document
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The contents of a CSV document
+    ///
+    pub fn read_csv(&mut self, text: &str) -> Result<(), IcdError> {
+        let mut header: Option<Vec<String>> = None;
+        let mut messages: Vec<MessageSpec> = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let number = index + 1;
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let cells = split_csv(line, number)?;
+
+            let columns = match &header {
+                Some(h) => h,
+                None => {
+                    header = Some(cells.iter().map(|c| normalize(c)).collect());
+                    continue;
+                }
+            };
+
+            let record = Record {
+                line: number,
+                values: columns
+                    .iter()
+                    .map(String::as_str)
+                    .zip(cells.iter().map(String::as_str))
+                    .collect(),
+            };
+
+            let message = record.message()?;
+            let parameter = record.parameter()?;
+
+            match messages.iter_mut().find(|m| m.name == message.name) {
+                Some(m) if m.same_command(&message) => m.parameters.push(parameter),
+                Some(m) => {
+                    return Err(IcdError::new(
+                        number,
+                        format!(
+                            "message '{}' has a different command than on line {}",
+                            m.name, m.line
+                        ),
+                    ))
+                }
+                None => messages.push(MessageSpec {
+                    parameters: vec![parameter],
+                    ..message
+                }),
+            }
+        }
+
+        self.extend(messages)
+    }
+
+    /// Read messages from an XML document
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The contents of an XML document
+    ///
+    pub fn read_xml(&mut self, text: &str) -> Result<(), IcdError> {
+        let mut messages: Vec<MessageSpec> = Vec::new();
+        let mut open = false;
+
+        for element in XmlElements::new(text) {
+            let element = element?;
+            match (element.name.as_str(), element.closing) {
+                ("message", false) => {
+                    let record = Record {
+                        line: element.line,
+                        values: element
+                            .attributes
+                            .iter()
+                            .map(|(k, v)| match k.as_str() {
+                                "name" => ("message", v.as_str()),
+                                "description" => ("message_description", v.as_str()),
+                                k => (k, v.as_str()),
+                            })
+                            .collect(),
+                    };
+                    messages.push(record.message()?);
+                    open = !element.empty;
+                }
+                ("message", true) => open = false,
+                ("parameter", false) => {
+                    let record = Record {
+                        line: element.line,
+                        values: element
+                            .attributes
+                            .iter()
+                            .map(|(k, v)| match k.as_str() {
+                                "name" => ("parameter", v.as_str()),
+                                k => (k, v.as_str()),
+                            })
+                            .collect(),
+                    };
+                    match messages.last_mut() {
+                        Some(m) if open => m.parameters.push(record.parameter()?),
+                        _ => {
+                            return Err(IcdError::new(
+                                element.line,
+                                "parameter is not inside a message",
+                            ))
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        self.extend(messages)
+    }
+
+    /// Generate Rust source for all messages that have been read
+    pub fn generate(&self) -> String {
+        let mut output = String::new();
+        output.push_str("// This file was generated from an interface control document.\n");
+        output.push_str("// Do not edit it directly; regenerate it when the ICD changes.\n");
+
+        for message in self.messages.iter() {
+            output.push('\n');
+            message.generate(&mut output);
+        }
+
+        output
+    }
+
+    /// Generate Rust source and write it to a file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the output file
+    ///
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), IcdError> {
+        let path = path.as_ref();
+        fs::write(path, self.generate())
+            .map_err(|e| IcdError::new(0, format!("{}: {}", path.display(), e)))
+    }
+
+    /// Validate and add messages to the generator
+    fn extend(&mut self, messages: Vec<MessageSpec>) -> Result<(), IcdError> {
+        for (index, message) in messages.iter().enumerate() {
+            message.validate()?;
+
+            let name = message.type_name();
+            if let Some(m) = self
+                .messages
+                .iter()
+                .chain(messages[..index].iter())
+                .find(|m| m.type_name() == name)
+            {
+                return Err(IcdError::new(
+                    message.line,
+                    format!("message '{}' was already defined on line {}", name, m.line),
+                ));
+            }
+        }
+
+        self.messages.extend(messages);
+        Ok(())
+    }
+}
+
+impl MessageSpec {
+    /// Check if two messages are initiated by the same command
+    fn same_command(&self, other: &Self) -> bool {
+        self.address == other.address
+            && self.subaddress == other.subaddress
+            && self.transmit == other.transmit
+            && self.word_count == other.word_count
+    }
+
+    /// Get the name of the generated struct
+    fn type_name(&self) -> String {
+        self.name
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                let mut chars = p.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Check the message and its parameters for errors
+    fn validate(&self) -> Result<(), IcdError> {
+        let line = self.line;
+        let name = self.type_name();
+
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return Err(IcdError::new(
+                line,
+                format!("message name '{}' is not a valid identifier", self.name),
+            ));
+        }
+        if self.name.contains(char::is_control) {
+            return Err(IcdError::new(
+                line,
+                "message name must not contain control characters",
+            ));
+        }
+        if RESERVED_TYPES.contains(&name.as_str()) {
+            return Err(IcdError::new(
+                line,
+                format!("message name '{}' is reserved", name),
+            ));
+        }
+        if self.address > 31 {
+            return Err(IcdError::new(line, "address must be between 0 and 31"));
+        }
+        if self.subaddress < 1 || self.subaddress > 30 {
+            return Err(IcdError::new(line, "subaddress must be between 1 and 30"));
+        }
+        if self.word_count < 1 || self.word_count > 32 {
+            return Err(IcdError::new(line, "word count must be between 1 and 32"));
+        }
+        if self.parameters.is_empty() {
+            return Err(IcdError::new(
+                line,
+                format!("message '{}' has no parameters", self.name),
+            ));
+        }
+
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            parameter.validate(self.word_count)?;
+
+            let methods = parameter.methods();
+            for p in self.parameters[..index].iter() {
+                if let Some(method) = p.methods().into_iter().find(|m| methods.contains(m)) {
+                    return Err(IcdError::new(
+                        parameter.line,
+                        format!(
+                            "method '{}' was already defined by parameter '{}' on line {}",
+                            method,
+                            p.getter(),
+                            p.line
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the macro invocation for the message
+    fn generate(&self, output: &mut String) {
+        let direction = match self.transmit {
+            true => "Transmit",
+            false => "Receive",
+        };
+
+        output.push_str("::mil_std_1553b::icd_message! {\n");
+        if !self.description.is_empty() {
+            output.push_str(&doc(4, &self.description));
+            output.push_str("    ///\n");
+        }
+        output.push_str(&format!(
+            "    /// `{}`: RT {}, SA {}, {}, {} data words\n",
+            self.name,
+            self.address,
+            self.subaddress,
+            direction.to_ascii_lowercase(),
+            self.word_count
+        ));
+        output.push_str(&format!("    pub struct {} {{\n", self.type_name()));
+        output.push_str(&format!("        address: {},\n", self.address));
+        output.push_str(&format!("        subaddress: {},\n", self.subaddress));
+        output.push_str(&format!(
+            "        transmit_receive: ::mil_std_1553b::TransmitReceive::{},\n",
+            direction
+        ));
+        output.push_str(&format!("        word_count: {},\n", self.word_count));
+        output.push_str("    }\n");
+
+        for parameter in self.parameters.iter() {
+            output.push('\n');
+            parameter.generate(output);
+        }
+
+        output.push_str("}\n");
+    }
+}
+
+impl ParameterSpec {
+    /// Get the name of the generated getter
+    fn getter(&self) -> String {
+        let mut result = String::new();
+        let mut previous = '_';
+
+        for c in self.name.chars() {
+            let c = if c.is_ascii_alphanumeric() { c } else { '_' };
+            if c.is_ascii_uppercase() && previous.is_ascii_lowercase() {
+                result.push('_');
+            }
+            if c != '_' || !result.ends_with('_') {
+                result.push(c.to_ascii_lowercase());
+            }
+            previous = c;
+        }
+
+        result.trim_matches('_').to_string()
+    }
+
+    /// Get the names of the generated getter and setter
+    fn methods(&self) -> [String; 2] {
+        let getter = self.getter();
+        let setter = format!("set_{}", getter);
+        [getter, setter]
+    }
+
+    /// Check the parameter for errors
+    fn validate(&self, count: u8) -> Result<(), IcdError> {
+        let line = self.line;
+        let getter = self.getter();
+
+        if !getter.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return Err(IcdError::new(
+                line,
+                format!("parameter name '{}' is not a valid identifier", self.name),
+            ));
+        }
+        if RESERVED_NAMES.contains(&getter.as_str()) {
+            return Err(IcdError::new(
+                line,
+                format!("parameter name '{}' is reserved", getter),
+            ));
+        }
+        if self.unit.contains(char::is_control) {
+            return Err(IcdError::new(
+                line,
+                "unit must not contain control characters",
+            ));
+        }
+        if !TYPES.contains(&self.kind.as_str()) {
+            return Err(IcdError::new(
+                line,
+                format!("unsupported parameter type '{}'", self.kind),
+            ));
+        }
+        if self.word >= count as usize {
+            return Err(IcdError::new(
+                line,
+                format!("word {} is outside of a {} word message", self.word, count),
+            ));
+        }
+        if self.mask == 0 {
+            return Err(IcdError::new(line, "mask must not be zero"));
+        }
+        let bits = u32::from(self.mask) >> self.mask.trailing_zeros();
+        if bits & (bits + 1) != 0 {
+            return Err(IcdError::new(line, "mask must be contiguous"));
+        }
+        if self.scale == 0.0 {
+            return Err(IcdError::new(line, "scale must not be zero"));
+        }
+
+        Ok(())
+    }
+
+    /// Write the parameter declaration
+    fn generate(&self, output: &mut String) {
+        let [getter, setter] = self.methods();
+
+        output.push_str(&doc(4, &self.description));
+        if !self.unit.is_empty() {
+            output.push_str(&format!("    /// Unit: {}\n", self.unit));
+        }
+        output.push_str(&format!(
+            "    {}, {}: {} = ::mil_std_1553b::Parameter::new({}, {:#06X})",
+            getter, setter, self.kind, self.word, self.mask
+        ));
+
+        if self.signed {
+            output.push_str("\n        .with_signed()");
+        }
+        if self.scale != 1.0 {
+            output.push_str(&format!("\n        .with_scale({:?})", self.scale));
+        }
+        if self.offset != 0.0 {
+            output.push_str(&format!("\n        .with_offset({:?})", self.offset));
+        }
+        if !self.unit.is_empty() {
+            output.push_str(&format!("\n        .with_unit({:?})", self.unit));
+        }
+        if self.minimum.is_some() || self.maximum.is_some() {
+            output.push_str(&format!(
+                "\n        .with_range({}, {})",
+                self.minimum
+                    .map(|v| format!("{:?}", v))
+                    .unwrap_or("f64::MIN".into()),
+                self.maximum
+                    .map(|v| format!("{:?}", v))
+                    .unwrap_or("f64::MAX".into()),
+            ));
+        }
+
+        output.push_str(";\n");
+    }
+}
+
+/// A set of named values read from a CSV row or XML element
+struct Record<'a> {
+    line: usize,
+    values: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Record<'a> {
+    /// Get a value by name
+    fn get(&self, name: &str) -> Option<&'a str> {
+        self.values
+            .iter()
+            .find(|(k, _)| *k == name)
+            .map(|(_, v)| v.trim())
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Get a value by name or fail
+    fn require(&self, name: &str) -> Result<&'a str, IcdError> {
+        self.get(name)
+            .ok_or_else(|| IcdError::new(self.line, format!("missing value for '{}'", name)))
+    }
+
+    /// Get a value by name as an integer
+    fn integer(&self, name: &str) -> Result<u64, IcdError> {
+        let value = self.require(name)?;
+        let result = if let Some(v) = value.strip_prefix("0x").or(value.strip_prefix("0X")) {
+            u64::from_str_radix(&v.replace('_', ""), 16)
+        } else if let Some(v) = value.strip_prefix("0b").or(value.strip_prefix("0B")) {
+            u64::from_str_radix(&v.replace('_', ""), 2)
+        } else {
+            value.replace('_', "").parse()
+        };
+        result.map_err(|_| {
+            IcdError::new(
+                self.line,
+                format!("invalid integer '{}' for '{}'", value, name),
+            )
+        })
+    }
+
+    /// Get a value by name as an integer that must fit a type
+    fn bounded<T: TryFrom<u64>>(&self, name: &str) -> Result<T, IcdError> {
+        T::try_from(self.integer(name)?)
+            .map_err(|_| IcdError::new(self.line, format!("value for '{}' is too large", name)))
+    }
+
+    /// Get an optional value by name as a float
+    fn float(&self, name: &str) -> Result<Option<f64>, IcdError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(v) => match v.parse::<f64>() {
+                Ok(f) if f.is_finite() => Ok(Some(f)),
+                _ => Err(IcdError::new(
+                    self.line,
+                    format!("invalid number '{}' for '{}'", v, name),
+                )),
+            },
+        }
+    }
+
+    /// Get an optional value by name as a bool
+    fn boolean(&self, name: &str) -> Result<bool, IcdError> {
+        match self.get(name).map(|v| v.to_ascii_lowercase()).as_deref() {
+            None | Some("false" | "no" | "n" | "0") => Ok(false),
+            Some("true" | "yes" | "y" | "1") => Ok(true),
+            Some(v) => Err(IcdError::new(
+                self.line,
+                format!("invalid boolean '{}' for '{}'", v, name),
+            )),
+        }
+    }
+
+    /// Read the message columns of the record
+    fn message(&self) -> Result<MessageSpec, IcdError> {
+        let transmit = match self.require("direction")?.to_ascii_lowercase().as_str() {
+            "t" | "transmit" | "tx" => true,
+            "r" | "receive" | "rx" => false,
+            v => {
+                return Err(IcdError::new(
+                    self.line,
+                    format!("invalid direction '{}'", v),
+                ))
+            }
+        };
+
+        Ok(MessageSpec {
+            name: self.require("message")?.to_string(),
+            address: self.bounded("address")?,
+            subaddress: self.bounded("subaddress")?,
+            transmit,
+            word_count: self.bounded("word_count")?,
+            description: self.get("message_description").unwrap_or("").to_string(),
+            parameters: Vec::new(),
+            line: self.line,
+        })
+    }
+
+    /// Read the parameter columns of the record
+    fn parameter(&self) -> Result<ParameterSpec, IcdError> {
+        Ok(ParameterSpec {
+            name: self.require("parameter")?.to_string(),
+            kind: self.get("type").unwrap_or("u16").to_string(),
+            word: self.bounded("word")?,
+            mask: self.bounded("mask")?,
+            signed: self.boolean("signed")?,
+            scale: self.float("scale")?.unwrap_or(1.0),
+            offset: self.float("offset")?.unwrap_or(0.0),
+            unit: self.get("unit").unwrap_or("").to_string(),
+            minimum: self.float("min")?,
+            maximum: self.float("max")?,
+            description: self.get("description").unwrap_or("").to_string(),
+            line: self.line,
+        })
+    }
+}
+
+/// Normalize a column or attribute name
+fn normalize(name: &str) -> String {
+    name.trim().to_ascii_lowercase().replace([' ', '-'], "_")
+}
+
+/// Format text as doc comment lines
+fn doc(indent: usize, text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| format!("{:indent$}/// {}\n", "", l, indent = indent))
+        .collect()
+}
+
+/// Split a line of a CSV document into cells
+fn split_csv(line: &str, number: usize) -> Result<Vec<String>, IcdError> {
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                cell.push('"');
+                let _ = chars.next();
+            }
+            ('"', true) => quoted = false,
+            ('"', false) if cell.trim().is_empty() => {
+                cell.clear();
+                quoted = true;
+            }
+            (',', false) => cells.push(core::mem::take(&mut cell)),
+            (c, _) => cell.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(IcdError::new(number, "unterminated quoted value"));
+    }
+
+    cells.push(cell);
+    Ok(cells)
+}
+
+/// An element tag read from an XML document
+struct XmlElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+    closing: bool,
+    empty: bool,
+    line: usize,
+}
+
+/// An iterator over the element tags of an XML document
+struct XmlElements<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> XmlElements<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    /// Get the line of a position in the document
+    fn line(&self, position: usize) -> usize {
+        self.text[..position].matches('\n').count() + 1
+    }
+
+    /// Parse the contents of a tag (without angle brackets)
+    fn parse(&self, tag: &str, line: usize) -> Result<XmlElement, IcdError> {
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(t) => (true, t),
+            None => (false, tag),
+        };
+        let (empty, tag) = match tag.strip_suffix('/') {
+            Some(t) => (true, t),
+            None => (false, tag),
+        };
+
+        let tag = tag.trim();
+        let end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let name = normalize(&tag[..end]);
+        let mut rest = tag[end..].trim_start();
+        let mut attributes = Vec::new();
+
+        while !rest.is_empty() {
+            let equals = rest
+                .find('=')
+                .ok_or_else(|| IcdError::new(line, "expected '=' after attribute name"))?;
+            let key = normalize(&rest[..equals]);
+            let value = rest[equals + 1..].trim_start();
+
+            let quote = value
+                .chars()
+                .next()
+                .filter(|c| *c == '"' || *c == '\'')
+                .ok_or_else(|| IcdError::new(line, "expected quoted attribute value"))?;
+            let close = value[1..]
+                .find(quote)
+                .ok_or_else(|| IcdError::new(line, "unterminated attribute value"))?;
+
+            attributes.push((key, unescape(&value[1..close + 1])));
+            rest = value[close + 2..].trim_start();
+        }
+
+        Ok(XmlElement {
+            name,
+            attributes,
+            closing,
+            empty,
+            line,
+        })
+    }
+}
+
+impl Iterator for XmlElements<'_> {
+    type Item = Result<XmlElement, IcdError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.position + self.text[self.position..].find('<')?;
+            let line = self.line(start);
+
+            let rest = &self.text[start..];
+            let (skip, terminator) = if rest.starts_with("<!--") {
+                (4, "-->")
+            } else if rest.starts_with("<?") {
+                (2, "?>")
+            } else if rest.starts_with("<!") {
+                (2, ">")
+            } else {
+                (1, ">")
+            };
+
+            let end = match skip {
+                1 => tag_end(&rest[1..]),
+                _ => rest[skip..].find(terminator),
+            };
+
+            let Some(end) = end else {
+                self.position = self.text.len();
+                return Some(Err(IcdError::new(line, "unterminated tag")));
+            };
+
+            self.position = start + skip + end + terminator.len();
+
+            if skip == 1 {
+                let tag = &rest[1..1 + end];
+                return Some(self.parse(tag, line));
+            }
+        }
+    }
+}
+
+/// Find the closing bracket of a tag, skipping quoted attribute values
+fn tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '>') => return Some(index),
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Replace the predefined XML entities and character references in a value
+///
+/// Anything that isn't a known entity or a valid character
+/// reference is left as it is.
+fn unescape(value: &str) -> String {
+    let mut result = String::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        match rest
+            .find(';')
+            .and_then(|end| Some((entity(&rest[1..end])?, end)))
+        {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Decode the name of an entity (without `&` and `;`)
+fn entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "amp" => Some('&'),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix('x') {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "\
+# navigation messages
+message,address,subaddress,direction,word_count,parameter,word,mask,type,signed,scale,unit,min,max,description
+NavData,5,3,T,2,altitude,0,0xFFFF,f32,true,0.5,ft,-1000,50000,Pressure altitude
+NavData,5,3,T,2,Valid Flag,1,0x8000,bool,,,,,,
+";
+
+    const XML: &str = r#"<?xml version="1.0"?>
+<icd>
+  <!-- air data messages -->
+  <message name="air data" address="12" subaddress="5" direction="Receive" word_count="1">
+    <parameter name="temperature" word="0" mask="0b0000000011111111" type="i16"
+               signed="yes" offset="-40" unit="&#176;C &amp; more"
+               description="valid when x &gt; 3 and y > 2"/>
+  </message>
+</icd>
+"#;
+
+    #[test]
+    fn test_generator_new() {
+        let generator = Generator::new();
+        assert_eq!(generator.count(), 0);
+        assert_eq!(generator, Generator::default());
+    }
+
+    #[test]
+    fn test_generator_read_csv() {
+        let generator = Generator::new().with_csv(CSV).unwrap();
+        assert_eq!(generator.count(), 1);
+
+        let message = &generator.messages[0];
+        assert_eq!(message.name, "NavData");
+        assert_eq!(message.address, 5);
+        assert_eq!(message.subaddress, 3);
+        assert!(message.transmit);
+        assert_eq!(message.word_count, 2);
+        assert_eq!(message.parameters.len(), 2);
+
+        let parameter = &message.parameters[0];
+        assert_eq!(parameter.kind, "f32");
+        assert_eq!(parameter.mask, 0xFFFF);
+        assert!(parameter.signed);
+        assert_eq!(parameter.scale, 0.5);
+        assert_eq!(parameter.minimum, Some(-1000.0));
+        assert_eq!(parameter.maximum, Some(50000.0));
+
+        let parameter = &message.parameters[1];
+        assert_eq!(parameter.getter(), "valid_flag");
+        assert!(!parameter.signed);
+        assert_eq!(parameter.scale, 1.0);
+    }
+
+    #[test]
+    fn test_generator_read_xml() {
+        let generator = Generator::new().with_xml(XML).unwrap();
+        assert_eq!(generator.count(), 1);
+
+        let message = &generator.messages[0];
+        assert_eq!(message.type_name(), "AirData");
+        assert!(!message.transmit);
+        assert_eq!(message.line, 4);
+
+        let parameter = &message.parameters[0];
+        assert_eq!(parameter.mask, 0x00FF);
+        assert_eq!(parameter.offset, -40.0);
+        assert_eq!(parameter.unit, "°C & more");
+        assert_eq!(parameter.description, "valid when x > 3 and y > 2");
+    }
+
+    #[test]
+    fn test_generator_generate() {
+        let output = Generator::new().with_csv(CSV).unwrap().generate();
+        let expected = "\
+::mil_std_1553b::icd_message! {
+    /// `NavData`: RT 5, SA 3, transmit, 2 data words
+    pub struct NavData {
+        address: 5,
+        subaddress: 3,
+        transmit_receive: ::mil_std_1553b::TransmitReceive::Transmit,
+        word_count: 2,
+    }
+
+    /// Pressure altitude
+    /// Unit: ft
+    altitude, set_altitude: f32 = ::mil_std_1553b::Parameter::new(0, 0xFFFF)
+        .with_signed()
+        .with_scale(0.5)
+        .with_unit(\"ft\")
+        .with_range(-1000.0, 50000.0);
+
+    valid_flag, set_valid_flag: bool = ::mil_std_1553b::Parameter::new(1, 0x8000);
+}
+";
+        assert!(output.starts_with("// This file was generated"));
+        assert!(output.ends_with(expected));
+    }
+
+    #[test]
+    fn test_generator_generate_open_range() {
+        let csv = "\
+message,address,subaddress,direction,word_count,parameter,word,mask,min
+Test,1,1,R,1,value,0,0x00FF,2.5
+";
+        let output = Generator::new().with_csv(csv).unwrap().generate();
+        assert!(output.contains(".with_range(2.5, f64::MAX);"));
+    }
+
+    #[test]
+    fn test_generator_fail_method_collision() {
+        let csv = "\
+message,address,subaddress,direction,word_count,parameter,word,mask
+Test,1,1,R,1,x,0,0x00FF
+Test,1,1,R,1,set_x,0,0xFF00
+";
+        let error = Generator::new().with_csv(csv).unwrap_err();
+        assert_eq!(error.line(), 3);
+        assert_eq!(
+            error.message(),
+            "method 'set_x' was already defined by parameter 'x' on line 2"
+        );
+
+        let xml = "\
+<message name=\"a\" address=\"1\" subaddress=\"1\" direction=\"R\" word_count=\"1\">
+<parameter name=\"set_x\" word=\"0\" mask=\"1\"/>
+<parameter name=\"x\" word=\"0\" mask=\"2\"/>
+<parameter name=\"x\" word=\"0\" mask=\"4\"/>
+</message>";
+        let error = Generator::new().with_xml(xml).unwrap_err();
+        assert_eq!(error.line(), 3);
+    }
+
+    #[test]
+    fn test_generator_xml_fail_control_characters() {
+        let xml = "\
+<message name=\"a\" address=\"1\" subaddress=\"1\" direction=\"R\" word_count=\"1\">
+<parameter name=\"x\" word=\"0\" mask=\"1\" unit=\"ft&#10;pub fn x() {}\"/>
+</message>";
+        let error = Generator::new().with_xml(xml).unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(error.message(), "unit must not contain control characters");
+
+        let xml = "\
+<message name=\"a&#10;pub fn x() {}\" address=\"1\" subaddress=\"1\" direction=\"R\" word_count=\"1\">
+<parameter name=\"x\" word=\"0\" mask=\"1\"/>
+</message>";
+        let error = Generator::new().with_xml(xml).unwrap_err();
+        assert_eq!(error.line(), 1);
+    }
+
+    #[test]
+    fn test_generator_xml_quoted_bracket() {
+        let mut elements = XmlElements::new("<a b=\"1 > 0\" c='>'/><d>");
+        let element = elements.next().unwrap().unwrap();
+        assert_eq!(element.name, "a");
+        assert!(element.empty);
+        assert_eq!(element.attributes[0], ("b".into(), "1 > 0".into()));
+        assert_eq!(element.attributes[1], ("c".into(), ">".into()));
+        assert_eq!(elements.next().unwrap().unwrap().name, "d");
+        assert!(elements.next().is_none());
+    }
+
+    #[test]
+    fn test_generator_xml_unescape() {
+        assert_eq!(unescape("&lt;&gt;&quot;&apos;&amp;"), "<>\"'&");
+        assert_eq!(unescape("&#176;C &#xB0;F &#x3c;"), "°C °F <");
+        assert_eq!(unescape("&amp;lt;"), "&lt;");
+        assert_eq!(
+            unescape("a & b &bad; &#xZZ; &#1114112; &"),
+            "a & b &bad; &#xZZ; &#1114112; &"
+        );
+    }
+
+    #[test]
+    fn test_generator_csv_quoted() {
+        let cells = split_csv(r#"a,"b, ""c""",d"#, 1).unwrap();
+        assert_eq!(cells, vec!["a", "b, \"c\"", "d"]);
+    }
+
+    #[test]
+    fn test_generator_csv_fail_unterminated() {
+        let result = split_csv(r#"a,"b"#, 3);
+        assert_eq!(result.unwrap_err().line(), 3);
+    }
+
+    #[test]
+    fn test_generator_csv_fail_missing_column() {
+        let csv = "message,address\nTest,1\n";
+        let error = Generator::new().with_csv(csv).unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(error.message(), "missing value for 'direction'");
+    }
+
+    #[test]
+    fn test_generator_csv_fail_different_command() {
+        let csv = "\
+message,address,subaddress,direction,word_count,parameter,word,mask
+Test,1,1,R,1,first,0,0x00FF
+Test,2,1,R,1,second,0,0xFF00
+";
+        let error = Generator::new().with_csv(csv).unwrap_err();
+        assert_eq!(error.line(), 3);
+    }
+
+    #[test]
+    fn test_generator_csv_fail_duplicate_parameter() {
+        let csv = "\
+message,address,subaddress,direction,word_count,parameter,word,mask
+Test,1,1,R,1,first,0,0x00FF
+Test,1,1,R,1,First,0,0xFF00
+";
+        let error = Generator::new().with_csv(csv).unwrap_err();
+        assert_eq!(error.line(), 3);
+    }
+
+    #[test]
+    fn test_generator_fail_duplicate_message() {
+        let csv = "\
+message,address,subaddress,direction,word_count,parameter,word,mask
+Test,1,1,R,1,first,0,0x00FF
+";
+        let mut generator = Generator::new().with_csv(csv).unwrap();
+        assert!(generator.read_csv(csv).is_err());
+        assert_eq!(generator.count(), 1);
+    }
+
+    #[test]
+    fn test_generator_fail_invalid_values() {
+        let header =
+            "message,address,subaddress,direction,word_count,parameter,word,mask,type,scale\n";
+        let rows = [
+            "Test,32,1,R,1,a,0,0x00FF,u16,1",
+            "Test,1,0,R,1,a,0,0x00FF,u16,1",
+            "Test,1,31,R,1,a,0,0x00FF,u16,1",
+            "Test,1,1,X,1,a,0,0x00FF,u16,1",
+            "Test,1,1,R,0,a,0,0x00FF,u16,1",
+            "Test,1,1,R,1,a,1,0x00FF,u16,1",
+            "Test,1,1,R,1,a,0,0x0000,u16,1",
+            "Test,1,1,R,1,a,0,0x0F0F,u16,1",
+            "Test,1,1,R,1,a,0,0x10000,u16,1",
+            "Test,1,1,R,1,a,0,0x00FF,u64,1",
+            "Test,1,1,R,1,a,0,0x00FF,u16,0",
+            "Test,1,1,R,1,a,0,0x00FF,u16,nan",
+            "Test,1,1,R,1,type,0,0x00FF,u16,1",
+            "Test,1,1,R,1,try_from,0,0x00FF,u16,1",
+            "Test,1,1,R,1,Default,0,0x00FF,u16,1",
+            "self,1,1,R,1,a,0,0x00FF,u16,1",
+            "Option,1,1,R,1,a,0,0x00FF,u16,1",
+            "result,1,1,R,1,a,0,0x00FF,u16,1",
+            "default,1,1,R,1,a,0,0x00FF,u16,1",
+            "Test,1,1,R,1,1st,0,0x00FF,u16,1",
+            "1Test,1,1,R,1,a,0,0x00FF,u16,1",
+        ];
+        for row in rows {
+            let csv = format!("{}{}\n", header, row);
+            let result = Generator::new().with_csv(&csv);
+            assert_eq!(result.map_err(|e| e.line()), Err(2), "{}", row);
+        }
+    }
+
+    #[test]
+    fn test_generator_xml_fail_parameter_outside_message() {
+        let xml = "<icd>\n<parameter name=\"a\" word=\"0\" mask=\"1\"/>\n</icd>";
+        let error = Generator::new().with_xml(xml).unwrap_err();
+        assert_eq!(error.line(), 2);
+    }
+
+    #[test]
+    fn test_generator_xml_fail_unterminated() {
+        let xml = "<icd>\n<message name=\"a\"";
+        let error = Generator::new().with_xml(xml).unwrap_err();
+        assert_eq!(error.line(), 2);
+
+        let xml = "<icd>\n<message name=\"a>";
+        assert!(Generator::new().with_xml(xml).is_err());
+    }
+
+    #[test]
+    fn test_generator_read_file_fail_missing() {
+        let error = Generator::new().with_file("missing.csv").unwrap_err();
+        assert_eq!(error.line(), 0);
+    }
+
+    #[test]
+    fn test_icd_error_display() {
+        let error = IcdError::new(4, "bad value");
+        assert_eq!(format!("{}", error), "line 4: bad value");
+    }
+}
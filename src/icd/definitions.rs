@@ -0,0 +1,406 @@
+use crate::icd::{Parameter, Readings};
+use crate::{
+    errors::*, Address, CommandWord, DataWord, Message, SubAddress, TransmitReceive, Word,
+};
+
+/// The definition of a message from an Interface Control Document (ICD)
+///
+/// A definition describes the command word that initiates the message
+/// (terminal address, subaddress, direction and word count) and the
+/// [Parameter]s that are carried in its data words. Definitions are
+/// constructed in const contexts, and are usually generated by the
+/// [icd_message](crate::icd_message) macro rather than built by hand.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     const NAV: Definition = Definition::new("NavData")
+///         .with_address(5)
+///         .with_subaddress(3)
+///         .with_transmit_receive(TransmitReceive::Transmit)
+///         .with_word_count(1)
+///         .with_parameters(&[
+///             Parameter::new(0, 0xFFFF).with_name("altitude")
+///         ]);
+///
+///     let message = NAV.message::<2>();
+///     assert!(NAV.matches(message.command().unwrap()));
+///     assert_eq!(NAV.parameter("altitude").map(Parameter::word), Some(0));
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Definition {
+    /// The name of the message
+    name: &'static str,
+
+    /// The terminal address of the message
+    address: u8,
+
+    /// The subaddress of the message
+    subaddress: u8,
+
+    /// The direction of the message
+    transmit_receive: TransmitReceive,
+
+    /// The number of data words in the message
+    word_count: u8,
+
+    /// The parameters carried by the message
+    parameters: &'static [Parameter],
+}
+
+impl Definition {
+    /// Create a new, empty definition with a name
+    ///
+    /// The definition starts at subaddress 1, because subaddresses
+    /// 0 and 31 are reserved for mode codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the message
+    ///
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            address: 0,
+            subaddress: 1,
+            transmit_receive: TransmitReceive::Receive,
+            word_count: 0,
+            parameters: &[],
+        }
+    }
+
+    /// Constructor method to set the terminal address
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal
+    ///
+    pub const fn with_address(mut self, address: u8) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Constructor method to set the subaddress
+    ///
+    /// # Arguments
+    ///
+    /// * `subaddress` - The subaddress of the message (1-30)
+    ///
+    pub const fn with_subaddress(mut self, subaddress: u8) -> Self {
+        self.subaddress = subaddress;
+        self
+    }
+
+    /// Constructor method to set the direction of transmission
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The direction from the point of view of the terminal
+    ///
+    pub const fn with_transmit_receive(mut self, value: TransmitReceive) -> Self {
+        self.transmit_receive = value;
+        self
+    }
+
+    /// Constructor method to set the number of data words
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of data words (1-32)
+    ///
+    pub const fn with_word_count(mut self, count: u8) -> Self {
+        self.word_count = count;
+        self
+    }
+
+    /// Constructor method to set the parameters
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - The parameters carried by the message
+    ///
+    pub const fn with_parameters(mut self, parameters: &'static [Parameter]) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    /// Get the number of words needed to hold a message
+    ///
+    /// The size includes the command word, and a word count of
+    /// zero (or greater than 31) is interpreted as 32 data words
+    /// in the same way as [CommandWord::word_count].
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of data words
+    ///
+    pub const fn size(count: u8) -> usize {
+        match count {
+            1..=31 => count as usize + 1,
+            _ => 33,
+        }
+    }
+
+    /// Get the name of the message
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the terminal address of the message
+    pub fn address(&self) -> Address {
+        Address::from(self.address)
+    }
+
+    /// Get the subaddress of the message
+    pub fn subaddress(&self) -> SubAddress {
+        SubAddress::from(self.subaddress)
+    }
+
+    /// Get the direction of the message
+    pub const fn transmit_receive(&self) -> TransmitReceive {
+        self.transmit_receive
+    }
+
+    /// Get the number of data words in the message
+    pub const fn word_count(&self) -> u8 {
+        self.word_count
+    }
+
+    /// Get the parameters carried by the message
+    pub const fn parameters(&self) -> &'static [Parameter] {
+        self.parameters
+    }
+
+    /// Find a parameter by name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the parameter
+    ///
+    pub fn parameter(&self, name: &str) -> Option<&'static Parameter> {
+        self.parameters.iter().find(|p| p.name() == name)
+    }
+
+    /// Create the command word that initiates this message
+    ///
+    /// A word count of zero (or greater than 31) is sent as 32
+    /// data words, matching the [size](Self::size) of the message.
+    pub fn command(&self) -> CommandWord {
+        let count = match self.word_count {
+            1..=31 => self.word_count,
+            _ => 0,
+        };
+        CommandWord::new()
+            .with_address(self.address())
+            .with_subaddress(self.subaddress())
+            .with_transmit_receive(self.transmit_receive)
+            .with_word_count(count)
+            .with_calculated_parity()
+    }
+
+    /// Check if a command word initiates this message
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - A command word to compare
+    ///
+    #[must_use = "Returned value is not used"]
+    pub fn matches(&self, command: &CommandWord) -> bool {
+        command.as_value() == self.command().as_value()
+    }
+
+    /// Create a message with the command word and empty data words
+    ///
+    /// If the message doesn't have room for the command word and
+    /// all data words, [Message::is_valid] will return false and
+    /// [Message::validate] will return an error.
+    pub fn message<const WORDS: usize>(&self) -> Message<WORDS> {
+        let command = self.command();
+        let mut message = Message::new().with_command(command);
+
+        for _ in 0..command.count() {
+            message.add_data(DataWord::new());
+        }
+
+        message
+    }
+
+    /// Check that a message was initiated by this definition
+    ///
+    /// Fails if the message is invalid or was initiated by
+    /// a different command word.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - A message to check
+    ///
+    pub fn check<const WORDS: usize>(&self, message: &Message<WORDS>) -> Result<()> {
+        message.validate()?;
+        match message.command() {
+            Some(c) if self.matches(c) => Ok(()),
+            _ => Err(Error::InvalidMessage),
+        }
+    }
+
+    /// Decode all parameters of a message into engineering values
+    ///
+    /// Returns an iterator of [Reading](crate::Reading)s, one for each
+    /// parameter in the definition. Parameters that are out of range,
+    /// have a parity error, or are missing from the message are reported
+    /// with the appropriate [Validity](crate::Validity) rather than
+    /// failing to decode the whole message.
+    ///
+    /// Fails only if the message wasn't initiated by the command word
+    /// of this definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - A message to decode
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use mil_std_1553b::*;
+    /// # fn main() -> Result<()> {
+    ///     const NAV: Definition = Definition::new("NavData")
+    ///         .with_address(5)
+    ///         .with_subaddress(3)
+    ///         .with_word_count(1)
+    ///         .with_parameters(&[
+    ///             Parameter::new(0, 0xFFFF)
+    ///                 .with_name("speed")
+    ///                 .with_unit("kts")
+    ///                 .with_range(0.0, 500.0)
+    ///         ]);
+    ///
+    ///     let message = NAV.message::<2>();
+    ///
+    ///     for reading in NAV.decode(&message)? {
+    ///         assert_eq!(reading.name(), "speed");
+    ///         assert_eq!(reading.value(), Some(0.0));
+    ///         assert_eq!(reading.validity(), Validity::Valid);
+    ///     }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode<'a, const WORDS: usize>(
+        &self,
+        message: &'a Message<WORDS>,
+    ) -> Result<Readings<'a, WORDS>> {
+        match message.command() {
+            Some(c) if self.matches(c) => Ok(Readings::new(self, message)),
+            _ => Err(Error::InvalidMessage),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMETERS: &[Parameter] = &[
+        Parameter::new(0, 0xFFFF).with_name("first"),
+        Parameter::new(1, 0x00FF).with_name("second"),
+    ];
+
+    const DEFINITION: Definition = Definition::new("Test")
+        .with_address(5)
+        .with_subaddress(3)
+        .with_transmit_receive(TransmitReceive::Transmit)
+        .with_word_count(2)
+        .with_parameters(PARAMETERS);
+
+    #[test]
+    fn test_definition_clone() {
+        let item1 = DEFINITION;
+        let item2 = Clone::clone(&item1);
+        assert_eq!(item1, item2);
+    }
+
+    #[test]
+    fn test_definition_new() {
+        let item = Definition::new("Empty");
+        assert_eq!(item.name(), "Empty");
+        assert_eq!(item.address(), Address::Value(0));
+        assert_eq!(item.subaddress(), SubAddress::Value(1));
+        assert!(!item.command().is_mode_code());
+        assert_eq!(item.transmit_receive(), TransmitReceive::Receive);
+        assert_eq!(item.word_count(), 0);
+        assert!(item.parameters().is_empty());
+    }
+
+    #[test]
+    fn test_definition_constructors() {
+        assert_eq!(DEFINITION.name(), "Test");
+        assert_eq!(DEFINITION.address(), Address::Value(5));
+        assert_eq!(DEFINITION.subaddress(), SubAddress::Value(3));
+        assert_eq!(DEFINITION.transmit_receive(), TransmitReceive::Transmit);
+        assert_eq!(DEFINITION.word_count(), 2);
+        assert_eq!(DEFINITION.parameters().len(), 2);
+    }
+
+    #[test]
+    fn test_definition_size() {
+        assert_eq!(Definition::size(1), 2);
+        assert_eq!(Definition::size(31), 32);
+        assert_eq!(Definition::size(32), 33);
+        assert_eq!(Definition::size(0), 33);
+    }
+
+    #[test]
+    fn test_definition_command_word_count() {
+        for count in [0, 32, 40, u8::MAX] {
+            let definition = DEFINITION.with_word_count(count);
+            let command = definition.command();
+            assert_eq!(command.count() + 1, Definition::size(count), "{}", count);
+            assert_eq!(definition.message::<33>().length(), Definition::size(count));
+        }
+    }
+
+    #[test]
+    fn test_definition_parameter() {
+        assert_eq!(DEFINITION.parameter("second"), Some(&PARAMETERS[1]));
+        assert_eq!(DEFINITION.parameter("third"), None);
+    }
+
+    #[test]
+    fn test_definition_command() {
+        let command = DEFINITION.command();
+        assert_eq!(command.address(), Address::Value(5));
+        assert_eq!(command.subaddress(), SubAddress::Value(3));
+        assert!(command.is_transmit());
+        assert_eq!(command.word_count(), 2);
+        assert!(command.check_parity());
+        assert!(DEFINITION.matches(&command));
+        assert!(!DEFINITION.matches(&command.with_word_count(3)));
+    }
+
+    #[test]
+    fn test_definition_message() {
+        let message = DEFINITION.message::<3>();
+        assert!(message.is_full());
+        assert_eq!(message.count(), 2);
+        assert!(DEFINITION.check(&message).is_ok());
+    }
+
+    #[test]
+    fn test_definition_message_fail_too_small() {
+        let message = DEFINITION.message::<2>();
+        assert!(!message.is_valid());
+        assert!(DEFINITION.check(&message).is_err());
+    }
+
+    #[test]
+    fn test_definition_check_fail_wrong_command() {
+        let message = Message::<3>::new()
+            .with_command(DEFINITION.command().with_subaddress(SubAddress::Value(4)))
+            .with_data(DataWord::new())
+            .with_data(DataWord::new());
+        assert_eq!(DEFINITION.check(&message), Err(Error::InvalidMessage));
+    }
+}
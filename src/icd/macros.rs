@@ -0,0 +1,335 @@
+/// Declare a strongly-typed message from an Interface Control Document (ICD)
+///
+/// This macro generates a struct that wraps a [Message](crate::Message) with
+/// room for the command word and all data words of the message. Each declared
+/// parameter gets a getter and a setter that convert between the raw bits of
+/// the data word and a typed engineering value using a [Parameter](crate::Parameter)
+/// definition. The full ICD metadata is available as a
+/// [Definition](crate::Definition) through the generated `DEFINITION` constant.
+///
+/// Parameter types must implement [Value](crate::Value). Getters return `None`
+/// if the wrapped message doesn't contain the data word of the parameter, and
+/// setters fail in the same situation. A subaddress outside of 1-30 (0 and 31
+/// are mode code subaddresses), or a parameter with a data word index that
+/// isn't less than the word count of the message, fails to compile:
+///
+/// ```rust,compile_fail
+/// # use mil_std_1553b::*;
+///     icd_message! {
+///         /// A message with one data word
+///         pub struct Short {
+///             address: 12,
+///             subaddress: 5,
+///             transmit_receive: TransmitReceive::Receive,
+///             word_count: 1,
+///         }
+///
+///         /// A parameter in the second data word
+///         value, set_value: u16 = Parameter::new(1, 0x00FF);
+///     }
+/// ```
+///
+/// ```rust,compile_fail
+/// # use mil_std_1553b::*;
+///     icd_message! {
+///         /// A message on a mode code subaddress
+///         pub struct ModeCode {
+///             address: 12,
+///             subaddress: 0,
+///             transmit_receive: TransmitReceive::Receive,
+///             word_count: 1,
+///         }
+///
+///         /// A parameter in the first data word
+///         value, set_value: u16 = Parameter::new(0, 0x00FF);
+///     }
+/// ```
+///
+/// The generated struct has the following methods:
+///
+/// * `new` - Create a message with the command word and zeroed data words
+/// * `read` - Parse the message from a packed byte buffer
+/// * `write` - Write the message to a packed byte buffer
+/// * `message` - Get a reference to the wrapped message
+/// * `into_message` - Unwrap the message
+///
+/// It also implements `Default` and `TryFrom<Message<N>>`, which fails if the
+/// message is invalid or was initiated by a different command word.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     icd_message! {
+///         /// Output of the air data computer
+///         pub struct AirData {
+///             address: 12,
+///             subaddress: 5,
+///             transmit_receive: TransmitReceive::Transmit,
+///             word_count: 2,
+///         }
+///
+///         /// Pressure altitude in feet
+///         altitude, set_altitude: f32 = Parameter::new(0, 0xFFFF)
+///             .with_signed()
+///             .with_scale(0.5)
+///             .with_unit("ft");
+///
+///         /// Weight on wheels
+///         on_ground, set_on_ground: bool = Parameter::new(1, 0b1000000000000000);
+///     }
+///
+///     let mut air = AirData::new();
+///     air.set_altitude(-250.5)?;
+///     air.set_on_ground(true)?;
+///
+///     assert_eq!(air.altitude(), Some(-250.5));
+///     assert_eq!(air.on_ground(), Some(true));
+///     assert_eq!(AirData::DEFINITION.parameters().len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[macro_export]
+macro_rules! icd_message {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            address: $address:expr,
+            subaddress: $subaddress:expr,
+            transmit_receive: $transmit_receive:expr,
+            word_count: $count:expr $(,)?
+        }
+
+        $(
+            $(#[$pmeta:meta])*
+            $getter:ident, $setter:ident : $type:ty = $parameter:expr;
+        )*
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            ::core::clone::Clone,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::fmt::Debug,
+        )]
+        $vis struct $name {
+            message: $crate::Message<{ $crate::Definition::size($count) }>,
+        }
+
+        const _: () = assert!(
+            matches!($subaddress, 1..=30),
+            concat!("subaddress of `", stringify!($name), "` must be between 1 and 30"),
+        );
+
+        $(
+            const _: () = assert!(
+                $parameter.word() < $crate::Definition::size($count) - 1,
+                concat!("parameter `", stringify!($getter), "` is outside of the message"),
+            );
+        )*
+
+        impl $name {
+            /// The ICD definition of this message
+            $vis const DEFINITION: $crate::Definition = $crate::Definition::new(stringify!($name))
+                .with_address($address)
+                .with_subaddress($subaddress)
+                .with_transmit_receive($transmit_receive)
+                .with_word_count($count)
+                .with_parameters(&[
+                    $( $parameter.with_name(stringify!($getter)) ),*
+                ]);
+
+            /// Create a message with the command word and zeroed data words
+            $vis fn new() -> Self {
+                Self {
+                    message: Self::DEFINITION.message(),
+                }
+            }
+
+            /// Parse the message from a packed byte buffer
+            ///
+            /// Fails if the buffer doesn't contain a valid message
+            /// initiated by the command word of this definition.
+            $vis fn read(data: &[u8]) -> $crate::Result<Self> {
+                <Self as ::core::convert::TryFrom<_>>::try_from(
+                    $crate::Message::read_command(data)?,
+                )
+            }
+
+            /// Write the message to a packed byte buffer
+            $vis fn write(&self, data: &mut [u8]) -> $crate::Result<()> {
+                self.message.write(data)
+            }
+
+            /// Get a reference to the wrapped message
+            $vis fn message(&self) -> &$crate::Message<{ $crate::Definition::size($count) }> {
+                &self.message
+            }
+
+            /// Unwrap the message
+            $vis fn into_message(self) -> $crate::Message<{ $crate::Definition::size($count) }> {
+                self.message
+            }
+
+            $(
+                $(#[$pmeta])*
+                $vis fn $getter(&self) -> ::core::option::Option<$type> {
+                    let parameter: $crate::Parameter = $parameter;
+                    parameter
+                        .get(&self.message)
+                        .map(<$type as $crate::Value>::from_engineering)
+                }
+
+                #[doc = concat!("Set the value of [", stringify!($getter), "](Self::", stringify!($getter), ")")]
+                $vis fn $setter(&mut self, value: $type) -> $crate::Result<()> {
+                    let parameter: $crate::Parameter = $parameter;
+                    parameter.set(
+                        &mut self.message,
+                        <$type as $crate::Value>::to_engineering(value),
+                    )
+                }
+            )*
+        }
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::Message<{ $crate::Definition::size($count) }>> for $name {
+            type Error = $crate::Error;
+
+            fn try_from(message: $crate::Message<{ $crate::Definition::size($count) }>) -> $crate::Result<Self> {
+                Self::DEFINITION.check(&message).map(|_| Self { message })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Message, Parameter, TransmitReceive};
+
+    crate::icd_message! {
+        /// Navigation test message
+        pub(crate) struct NavData {
+            address: 5,
+            subaddress: 3,
+            transmit_receive: TransmitReceive::Transmit,
+            word_count: 3,
+        }
+
+        /// Altitude in feet
+        altitude, set_altitude: f32 = Parameter::new(0, 0xFFFF)
+            .with_signed()
+            .with_scale(0.5)
+            .with_unit("ft");
+
+        /// Heading in degrees
+        heading, set_heading: f64 = Parameter::new(1, 0xFFFF)
+            .with_scale(360.0 / 65536.0)
+            .with_unit("deg");
+
+        /// Navigation mode
+        mode, set_mode: u8 = Parameter::new(2, 0b0000000000001111);
+
+        /// Navigation is valid
+        valid, set_valid: bool = Parameter::new(2, 0b1000000000000000);
+    }
+
+    /// Declared where prelude names are shadowed
+    mod shadowed {
+        use crate::{Parameter, TransmitReceive};
+
+        pub(super) type Option = ();
+        pub(super) type Default = ();
+        pub(super) type TryFrom = ();
+
+        crate::icd_message! {
+            /// Message with a parameter named like a trait method
+            pub(super) struct Shadowed {
+                address: 5,
+                subaddress: 4,
+                transmit_receive: TransmitReceive::Receive,
+                word_count: 1,
+            }
+
+            /// A parameter that shadows `TryFrom::try_from`
+            try_from, set_try_from: u16 = Parameter::new(0, 0x00FF);
+        }
+    }
+
+    #[test]
+    fn test_icd_message_shadowed_names() {
+        let _: (shadowed::Option, shadowed::Default, shadowed::TryFrom) = ((), (), ());
+
+        let mut message = shadowed::Shadowed::default();
+        message.set_try_from(12).unwrap();
+
+        let mut buffer = [0u8; 5];
+        message.write(&mut buffer).unwrap();
+
+        let result = shadowed::Shadowed::read(&buffer).unwrap();
+        assert_eq!(result.try_from(), Some(12));
+        assert_eq!(result.message(), message.message());
+        assert!(result.into_message().is_valid());
+    }
+
+    #[test]
+    fn test_icd_message_new() {
+        let nav = NavData::new();
+        assert_eq!(nav.message().count(), 3);
+        assert!(nav.message().is_valid());
+        assert_eq!(nav, NavData::default());
+    }
+
+    #[test]
+    fn test_icd_message_definition() {
+        let definition = NavData::DEFINITION;
+        assert_eq!(definition.name(), "NavData");
+        assert_eq!(definition.word_count(), 3);
+        assert_eq!(definition.parameters().len(), 4);
+        assert_eq!(definition.parameters()[0].name(), "altitude");
+        assert_eq!(definition.parameters()[1].unit(), "deg");
+        assert_eq!(definition.parameters()[3].name(), "valid");
+    }
+
+    #[test]
+    fn test_icd_message_get_set() {
+        let mut nav = NavData::new();
+        nav.set_altitude(-1200.5).unwrap();
+        nav.set_heading(90.0).unwrap();
+        nav.set_mode(0b1010).unwrap();
+        nav.set_valid(true).unwrap();
+
+        assert_eq!(nav.altitude(), Some(-1200.5));
+        assert_eq!(nav.heading(), Some(90.0));
+        assert_eq!(nav.mode(), Some(0b1010));
+        assert_eq!(nav.valid(), Some(true));
+        assert!(nav.message().is_valid());
+    }
+
+    #[test]
+    fn test_icd_message_read_write() {
+        let mut buffer = [0u8; 10];
+        let mut nav = NavData::new();
+        nav.set_altitude(100.0).unwrap();
+        nav.write(&mut buffer).unwrap();
+
+        let result = NavData::read(&buffer).unwrap();
+        assert_eq!(result.altitude(), Some(100.0));
+        assert_eq!(result, nav);
+    }
+
+    #[test]
+    fn test_icd_message_try_from_fail() {
+        let message = NavData::new().into_message().with_data(0b0000000000000001);
+        assert!(NavData::try_from(message).is_err());
+
+        let message = Message::<4>::new().with_command(0b0000000000000011);
+        assert!(NavData::try_from(message).is_err());
+    }
+}
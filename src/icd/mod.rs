@@ -0,0 +1,14 @@
+//! Message definitions from interface control documents
+
+#[cfg(feature = "codegen")]
+mod codegen;
+mod definitions;
+mod macros;
+mod parameters;
+mod readings;
+
+#[cfg(feature = "codegen")]
+pub use codegen::{Generator, IcdError};
+pub use definitions::Definition;
+pub use parameters::{Parameter, Value};
+pub use readings::{Reading, Readings, Validity};
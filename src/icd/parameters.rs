@@ -0,0 +1,509 @@
+use crate::{errors::*, DataWord, Field, Message};
+
+/// A value that can be converted to and from an engineering value
+///
+/// This trait is implemented for the primitive types that can be
+/// used as the type of a parameter in an [icd_message](crate::icd_message)
+/// definition. Conversions to integer types saturate at the bounds
+/// of the type, and conversions to `bool` are true for any value
+/// other than zero.
+pub trait Value: Copy {
+    /// Convert an engineering value into this type
+    fn from_engineering(value: f64) -> Self;
+
+    /// Convert this type into an engineering value
+    fn to_engineering(self) -> f64;
+}
+
+/// A named parameter stored in one of the data words of a message
+///
+/// Parameters are described by an Interface Control Document (ICD)
+/// as a range of bits within a data word, along with the scaling
+/// needed to convert the raw bits into an engineering value:
+///
+/// ```text
+/// value = raw * scale + offset
+/// ```
+///
+/// Parameters are constructed in const contexts so that an entire
+/// ICD message can be declared as a constant.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     const ALTITUDE: Parameter = Parameter::new(0, 0xFFFF)
+///         .with_name("altitude")
+///         .with_unit("ft")
+///         .with_signed()
+///         .with_scale(0.5);
+///
+///     let word = DataWord::from(0xFFFE);
+///     assert_eq!(ALTITUDE.decode(&word), -1.0);
+///     assert_eq!(ALTITUDE.encode(-1.0), 0xFFFE);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Parameter {
+    /// The name of the parameter
+    name: &'static str,
+
+    /// The unit of the engineering value
+    unit: &'static str,
+
+    /// The index of the data word containing the parameter
+    word: usize,
+
+    /// The bits of the data word containing the parameter
+    field: Field,
+
+    /// True if the raw value is two's complement
+    signed: bool,
+
+    /// The multiplier applied to the raw value
+    scale: f64,
+
+    /// The value added to the scaled raw value
+    offset: f64,
+
+    /// The minimum valid engineering value
+    minimum: Option<f64>,
+
+    /// The maximum valid engineering value
+    maximum: Option<f64>,
+}
+
+impl Parameter {
+    /// Create a new parameter given a data word index and mask
+    ///
+    /// The parameter is unsigned and unscaled until configured
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The index of the data word (0 is the first data word)
+    /// * `mask` - The bits of the data word that hold the value
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mask is zero or its bits aren't contiguous,
+    /// which fails to compile when called in a const context.
+    ///
+    pub const fn new(word: usize, mask: u16) -> Self {
+        let bits = (mask as u32) >> mask.trailing_zeros();
+        assert!(mask != 0, "parameter mask must not be zero");
+        assert!(bits & (bits + 1) == 0, "parameter mask must be contiguous");
+        Self {
+            name: "",
+            unit: "",
+            word,
+            field: Field::from(mask),
+            signed: false,
+            scale: 1.0,
+            offset: 0.0,
+            minimum: None,
+            maximum: None,
+        }
+    }
+
+    /// Constructor method to set the name of the parameter
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the parameter
+    ///
+    pub const fn with_name(mut self, name: &'static str) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// Constructor method to set the unit of the parameter
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The unit of the engineering value
+    ///
+    pub const fn with_unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Constructor method to interpret the raw value as two's complement
+    pub const fn with_signed(mut self) -> Self {
+        self.signed = true;
+        self
+    }
+
+    /// Constructor method to set the scale of the parameter
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The engineering value of the least significant bit
+    ///
+    pub const fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Constructor method to set the offset of the parameter
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The engineering value of a raw value of zero
+    ///
+    pub const fn with_offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Constructor method to set the valid range of the parameter
+    ///
+    /// # Arguments
+    ///
+    /// * `minimum` - The minimum valid engineering value
+    /// * `maximum` - The maximum valid engineering value
+    ///
+    pub const fn with_range(mut self, minimum: f64, maximum: f64) -> Self {
+        self.minimum = Some(minimum);
+        self.maximum = Some(maximum);
+        self
+    }
+
+    /// Get the name of the parameter
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the unit of the parameter
+    pub const fn unit(&self) -> &'static str {
+        self.unit
+    }
+
+    /// Get the index of the data word containing the parameter
+    pub const fn word(&self) -> usize {
+        self.word
+    }
+
+    /// Get the field used to read the raw value
+    pub const fn field(&self) -> Field {
+        self.field
+    }
+
+    /// Check if the raw value is two's complement
+    #[must_use = "Returned value is not used"]
+    pub const fn is_signed(&self) -> bool {
+        self.signed
+    }
+
+    /// Get the scale of the parameter
+    pub const fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Get the offset of the parameter
+    pub const fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Get the minimum valid engineering value, if any
+    pub const fn minimum(&self) -> Option<f64> {
+        self.minimum
+    }
+
+    /// Get the maximum valid engineering value, if any
+    pub const fn maximum(&self) -> Option<f64> {
+        self.maximum
+    }
+
+    /// Check if an engineering value is within the valid range
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - An engineering value to check
+    ///
+    #[must_use = "Returned value is not used"]
+    pub fn is_in_range(&self, value: f64) -> bool {
+        self.minimum.map(|m| value >= m).unwrap_or(true)
+            && self.maximum.map(|m| value <= m).unwrap_or(true)
+    }
+
+    /// Get the raw bits of the parameter from a data word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - A data word to read
+    ///
+    pub fn raw(&self, word: &DataWord) -> u16 {
+        self.field.read(word)
+    }
+
+    /// Convert the raw bits of the parameter to an engineering value
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - A data word to read
+    ///
+    pub fn decode(&self, word: &DataWord) -> f64 {
+        let raw = self.raw(word);
+        let width = self.field.width();
+
+        let value = if self.signed && width > 0 {
+            // shift the sign bit of the field into the sign bit of an
+            // i16 and back again to extend it.
+            let shift = 16 - width;
+            (((raw << shift) as i16) >> shift) as f64
+        } else {
+            raw as f64
+        };
+
+        value * self.scale + self.offset
+    }
+
+    /// Convert an engineering value into the raw bits of the parameter
+    ///
+    /// The value is rounded to the nearest raw value and clamped to
+    /// the range that can be represented by the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - An engineering value to convert
+    ///
+    pub fn encode(&self, value: f64) -> u16 {
+        let width = self.field.width();
+        let (min, max) = match (self.signed, width) {
+            (_, 0) => (0, 0),
+            (true, w) => (-(1i64 << (w - 1)), (1i64 << (w - 1)) - 1),
+            (false, w) => (0, (1i64 << w) - 1),
+        };
+
+        let raw = (value - self.offset) / self.scale;
+        let raw = if raw < 0.0 { raw - 0.5 } else { raw + 0.5 } as i64;
+        let raw = raw.clamp(min, max);
+
+        (raw as u16) & u16::MAX.checked_shr(16 - width).unwrap_or(0)
+    }
+
+    /// Read the engineering value of the parameter from a message
+    ///
+    /// Returns `None` if the message doesn't contain the data
+    /// word for this parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - A message to read from
+    ///
+    pub fn get<const WORDS: usize>(&self, message: &Message<WORDS>) -> Option<f64> {
+        message.at(self.word).map(|w| self.decode(w))
+    }
+
+    /// Write the engineering value of the parameter to a message
+    ///
+    /// Fails if the message doesn't contain the data word
+    /// for this parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - A message to write to
+    /// * `value` - An engineering value to write
+    ///
+    pub fn set<const WORDS: usize>(&self, message: &mut Message<WORDS>, value: f64) -> Result<()> {
+        let raw = self.encode(value);
+        let word = message.at_mut(self.word).ok_or(Error::OutOfBounds)?;
+        self.field.write(word, raw);
+        Ok(())
+    }
+}
+
+macro_rules! impl_value {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl Value for $t {
+                fn from_engineering(value: f64) -> Self {
+                    value as $t
+                }
+
+                fn to_engineering(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_value!(u8, u16, u32, i8, i16, i32, f32);
+
+impl Value for f64 {
+    fn from_engineering(value: f64) -> Self {
+        value
+    }
+
+    fn to_engineering(self) -> f64 {
+        self
+    }
+}
+
+impl Value for bool {
+    fn from_engineering(value: f64) -> Self {
+        value != 0.0
+    }
+
+    fn to_engineering(self) -> f64 {
+        match self {
+            true => 1.0,
+            false => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CommandWord, Word};
+
+    #[test]
+    fn test_parameter_clone() {
+        let item1 = Parameter::new(0, 0xFFFF).with_name("altitude");
+        let item2 = Clone::clone(&item1);
+        assert_eq!(item1, item2);
+    }
+
+    #[test]
+    fn test_parameter_new() {
+        let item = Parameter::new(2, 0b0000000011110000);
+        assert_eq!(item.name(), "");
+        assert_eq!(item.unit(), "");
+        assert_eq!(item.word(), 2);
+        assert_eq!(item.field(), Field::from(0b0000000011110000));
+        assert!(!item.is_signed());
+        assert_eq!(item.scale(), 1.0);
+        assert_eq!(item.offset(), 0.0);
+        assert_eq!(item.minimum(), None);
+        assert_eq!(item.maximum(), None);
+    }
+
+    #[test]
+    fn test_parameter_constructors() {
+        let item = Parameter::new(0, 0xFFFF)
+            .with_name("speed")
+            .with_unit("kts")
+            .with_signed()
+            .with_scale(0.25)
+            .with_offset(10.0)
+            .with_range(0.0, 100.0);
+        assert_eq!(item.name(), "speed");
+        assert_eq!(item.unit(), "kts");
+        assert!(item.is_signed());
+        assert_eq!(item.scale(), 0.25);
+        assert_eq!(item.offset(), 10.0);
+        assert_eq!(item.minimum(), Some(0.0));
+        assert_eq!(item.maximum(), Some(100.0));
+    }
+
+    #[test]
+    fn test_parameter_is_in_range() {
+        let item = Parameter::new(0, 0xFFFF).with_range(-1.0, 1.0);
+        assert!(item.is_in_range(0.0));
+        assert!(item.is_in_range(1.0));
+        assert!(!item.is_in_range(1.5));
+        assert!(!item.is_in_range(-1.5));
+        assert!(Parameter::new(0, 0xFFFF).is_in_range(f64::MAX));
+    }
+
+    #[test]
+    fn test_parameter_decode_unsigned() {
+        let item = Parameter::new(0, 0b0000111100000000).with_scale(2.0);
+        let word = DataWord::from(0b0000101000000000);
+        assert_eq!(item.raw(&word), 0b1010);
+        assert_eq!(item.decode(&word), 20.0);
+    }
+
+    #[test]
+    fn test_parameter_decode_signed() {
+        let item = Parameter::new(0, 0b0000111100000000).with_signed();
+        let word = DataWord::from(0b0000101000000000);
+        assert_eq!(item.decode(&word), -6.0);
+    }
+
+    #[test]
+    fn test_parameter_decode_offset() {
+        let item = Parameter::new(0, 0x00FF).with_scale(0.5).with_offset(-40.0);
+        let word = DataWord::from(100);
+        assert_eq!(item.decode(&word), 10.0);
+    }
+
+    #[test]
+    fn test_parameter_encode_rounded() {
+        let item = Parameter::new(0, 0x00FF).with_scale(0.5);
+        assert_eq!(item.encode(10.2), 20);
+        assert_eq!(item.encode(10.3), 21);
+    }
+
+    #[test]
+    fn test_parameter_encode_signed() {
+        let item = Parameter::new(0, 0b0000111100000000).with_signed();
+        assert_eq!(item.encode(-6.0), 0b1010);
+        assert_eq!(item.encode(-100.0), 0b1000);
+        assert_eq!(item.encode(100.0), 0b0111);
+    }
+
+    #[test]
+    fn test_parameter_encode_clamped() {
+        let item = Parameter::new(0, 0x00FF);
+        assert_eq!(item.encode(-1.0), 0);
+        assert_eq!(item.encode(1000.0), 255);
+    }
+
+    #[test]
+    #[should_panic(expected = "parameter mask must not be zero")]
+    fn test_parameter_new_empty_mask() {
+        let _ = Parameter::new(0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "parameter mask must be contiguous")]
+    fn test_parameter_new_split_mask() {
+        let _ = Parameter::new(0, 0b0000_1111_0000_1111);
+    }
+
+    #[test]
+    fn test_parameter_get_set() {
+        let item = Parameter::new(1, 0xFF00).with_scale(0.5);
+        let mut message = Message::<3>::new()
+            .with_command(CommandWord::new().with_word_count(2))
+            .with_data(DataWord::new())
+            .with_data(DataWord::new());
+
+        item.set(&mut message, 3.5).unwrap();
+        assert_eq!(item.get(&message), Some(3.5));
+        assert_eq!(message.at(1).map(Word::as_value), Some(0x0700));
+        assert!(message.is_valid());
+    }
+
+    #[test]
+    fn test_parameter_get_set_missing() {
+        let item = Parameter::new(4, 0xFF00);
+        let mut message = Message::<2>::new()
+            .with_command(CommandWord::new().with_word_count(1))
+            .with_data(DataWord::new());
+
+        assert_eq!(item.get(&message), None);
+        assert_eq!(item.set(&mut message, 1.0), Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_value_conversions() {
+        assert_eq!(u8::from_engineering(300.0), 255);
+        assert_eq!(i16::from_engineering(-12.7), -12);
+        assert_eq!(f32::from_engineering(1.5), 1.5);
+        assert_eq!(f64::from_engineering(1.5), 1.5);
+        assert!(bool::from_engineering(1.0));
+        assert!(!bool::from_engineering(0.0));
+        assert_eq!(true.to_engineering(), 1.0);
+        assert_eq!(false.to_engineering(), 0.0);
+        assert_eq!(12u16.to_engineering(), 12.0);
+        assert_eq!(1.5f64.to_engineering(), 1.5);
+    }
+}
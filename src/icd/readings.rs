@@ -0,0 +1,299 @@
+use crate::icd::{Definition, Parameter};
+use crate::{Message, Word};
+
+/// The validity of a decoded parameter
+///
+/// Problems with individual parameters are reported using this
+/// enum rather than failing to decode the whole message, so that
+/// the valid parameters of a message can still be displayed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum Validity {
+    /// The parameter was decoded and is within its valid range
+    Valid = 0,
+
+    /// The parameter was decoded but is outside of its valid range
+    OutOfRange = 1,
+
+    /// The data word containing the parameter has a bad parity bit
+    ParityError = 2,
+
+    /// The message doesn't contain the data word for the parameter
+    Missing = 3,
+}
+
+impl Validity {
+    /// Check if the enum is the 'Valid' variant
+    #[must_use = "Returned value is not used"]
+    pub const fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    /// Check if the enum is the 'OutOfRange' variant
+    #[must_use = "Returned value is not used"]
+    pub const fn is_out_of_range(&self) -> bool {
+        matches!(self, Self::OutOfRange)
+    }
+
+    /// Check if the enum is the 'ParityError' variant
+    #[must_use = "Returned value is not used"]
+    pub const fn is_parity_error(&self) -> bool {
+        matches!(self, Self::ParityError)
+    }
+
+    /// Check if the enum is the 'Missing' variant
+    #[must_use = "Returned value is not used"]
+    pub const fn is_missing(&self) -> bool {
+        matches!(self, Self::Missing)
+    }
+}
+
+/// A parameter decoded from a message into an engineering value
+///
+/// Readings are created by [Definition::decode], and contain the
+/// engineering value of the parameter along with its name, unit and
+/// [Validity]. The value is available for out-of-range parameters
+/// and parameters with parity errors, but not for missing parameters.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Reading {
+    /// The definition of the parameter
+    parameter: &'static Parameter,
+
+    /// The decoded engineering value
+    value: Option<f64>,
+
+    /// The validity of the value
+    validity: Validity,
+}
+
+impl Reading {
+    /// Decode a parameter from a message
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The parameter to decode
+    /// * `message` - The message containing the parameter
+    ///
+    pub fn new<const WORDS: usize>(
+        parameter: &'static Parameter,
+        message: &Message<WORDS>,
+    ) -> Self {
+        let (value, validity) = match message.at(parameter.word()) {
+            None => (None, Validity::Missing),
+            Some(w) => {
+                let value = parameter.decode(w);
+                let validity = if !w.check_parity() {
+                    Validity::ParityError
+                } else if !parameter.is_in_range(value) {
+                    Validity::OutOfRange
+                } else {
+                    Validity::Valid
+                };
+                (Some(value), validity)
+            }
+        };
+
+        Self {
+            parameter,
+            value,
+            validity,
+        }
+    }
+
+    /// Get the definition of the parameter
+    pub const fn parameter(&self) -> &'static Parameter {
+        self.parameter
+    }
+
+    /// Get the name of the parameter
+    pub const fn name(&self) -> &'static str {
+        self.parameter.name()
+    }
+
+    /// Get the unit of the engineering value
+    pub const fn unit(&self) -> &'static str {
+        self.parameter.unit()
+    }
+
+    /// Get the engineering value, if the parameter wasn't missing
+    pub const fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    /// Get the validity of the engineering value
+    pub const fn validity(&self) -> Validity {
+        self.validity
+    }
+
+    /// Check if the engineering value is valid
+    #[must_use = "Returned value is not used"]
+    pub const fn is_valid(&self) -> bool {
+        self.validity.is_valid()
+    }
+}
+
+/// An iterator over the decoded parameters of a message
+///
+/// See [Definition::decode] for more information.
+#[derive(Copy, Clone, Debug)]
+pub struct Readings<'a, const WORDS: usize> {
+    /// The parameters to decode
+    parameters: &'static [Parameter],
+
+    /// The message to decode
+    message: &'a Message<WORDS>,
+
+    /// The index of the next parameter
+    index: usize,
+}
+
+impl<'a, const WORDS: usize> Readings<'a, WORDS> {
+    /// Create an iterator over the parameters of a definition
+    ///
+    /// # Arguments
+    ///
+    /// * `definition` - The definition of the message
+    /// * `message` - The message to decode
+    ///
+    pub fn new(definition: &Definition, message: &'a Message<WORDS>) -> Self {
+        Self {
+            parameters: definition.parameters(),
+            message,
+            index: 0,
+        }
+    }
+}
+
+impl<const WORDS: usize> Iterator for Readings<'_, WORDS> {
+    type Item = Reading;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parameter = self.parameters.get(self.index)?;
+        self.index += 1;
+        Some(Reading::new(parameter, self.message))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.parameters.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const WORDS: usize> ExactSizeIterator for Readings<'_, WORDS> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CommandWord, DataWord, TransmitReceive};
+
+    const PARAMETERS: &[Parameter] = &[
+        Parameter::new(0, 0xFF00)
+            .with_name("speed")
+            .with_unit("kts")
+            .with_range(0.0, 100.0),
+        Parameter::new(0, 0x00FF).with_name("heading"),
+        Parameter::new(1, 0xFFFF).with_name("altitude"),
+        Parameter::new(2, 0xFFFF).with_name("missing"),
+    ];
+
+    const DEFINITION: Definition = Definition::new("Test")
+        .with_address(1)
+        .with_subaddress(2)
+        .with_transmit_receive(TransmitReceive::Transmit)
+        .with_word_count(2)
+        .with_parameters(PARAMETERS);
+
+    fn message(first: DataWord, second: DataWord) -> Message<3> {
+        Message::<3>::new()
+            .with_command(DEFINITION.command())
+            .with_data(first)
+            .with_data(second)
+    }
+
+    #[test]
+    fn test_validity_clone() {
+        let item1 = Validity::OutOfRange;
+        let item2 = Clone::clone(&item1);
+        assert_eq!(item1, item2);
+    }
+
+    #[test]
+    fn test_validity_checks() {
+        assert!(Validity::Valid.is_valid());
+        assert!(Validity::OutOfRange.is_out_of_range());
+        assert!(Validity::ParityError.is_parity_error());
+        assert!(Validity::Missing.is_missing());
+        assert!(!Validity::Missing.is_valid());
+    }
+
+    #[test]
+    fn test_reading_valid() {
+        let message = message(DataWord::from(0x2A10), DataWord::from(7));
+        let reading = Reading::new(&PARAMETERS[0], &message);
+        assert_eq!(reading.name(), "speed");
+        assert_eq!(reading.unit(), "kts");
+        assert_eq!(reading.value(), Some(42.0));
+        assert_eq!(reading.validity(), Validity::Valid);
+        assert_eq!(reading.parameter(), &PARAMETERS[0]);
+        assert!(reading.is_valid());
+    }
+
+    #[test]
+    fn test_reading_out_of_range() {
+        let message = message(DataWord::from(0xFF00), DataWord::from(7));
+        let reading = Reading::new(&PARAMETERS[0], &message);
+        assert_eq!(reading.value(), Some(255.0));
+        assert_eq!(reading.validity(), Validity::OutOfRange);
+    }
+
+    #[test]
+    fn test_reading_parity_error() {
+        let word = DataWord::from(0x0F00).with_parity(0);
+        let message = message(word, DataWord::from(7));
+        let reading = Reading::new(&PARAMETERS[0], &message);
+        assert_eq!(reading.value(), Some(15.0));
+        assert_eq!(reading.validity(), Validity::ParityError);
+    }
+
+    #[test]
+    fn test_reading_missing() {
+        let message = message(DataWord::from(0), DataWord::from(7));
+        let reading = Reading::new(&PARAMETERS[3], &message);
+        assert_eq!(reading.value(), None);
+        assert_eq!(reading.validity(), Validity::Missing);
+    }
+
+    #[test]
+    fn test_readings_iterator() {
+        let word = DataWord::from(0x0F00).with_parity(0);
+        let message = message(word, DataWord::from(7));
+        let mut readings = DEFINITION.decode(&message).unwrap();
+
+        assert_eq!(readings.len(), 4);
+        assert_eq!(
+            readings.next().map(|r| r.validity()),
+            Some(Validity::ParityError)
+        );
+        assert_eq!(
+            readings.next().map(|r| r.validity()),
+            Some(Validity::ParityError)
+        );
+        assert_eq!(readings.next().map(|r| r.value()), Some(Some(7.0)));
+        assert_eq!(
+            readings.next().map(|r| r.validity()),
+            Some(Validity::Missing)
+        );
+        assert_eq!(readings.next(), None);
+        assert_eq!(readings.len(), 0);
+    }
+
+    #[test]
+    fn test_readings_fail_wrong_command() {
+        let message = Message::<3>::new()
+            .with_command(CommandWord::new().with_word_count(2))
+            .with_data(DataWord::new())
+            .with_data(DataWord::new());
+        assert!(DEFINITION.decode(&message).is_err());
+    }
+}
@@ -3,7 +3,6 @@
     dead_code,
     arithmetic_overflow,
     absolute_paths_not_starting_with_crate,
-    box_pointers,
     elided_lifetimes_in_paths,
     explicit_outlives_requirements,
     ffi_unwind_calls,
@@ -38,20 +37,73 @@
 )]
 #![doc = include_str!("../README.md")]
 
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod controller;
 mod errors;
+mod events;
 mod fields;
 mod flags;
+mod icd;
 mod message;
+mod parse;
+mod terminal;
 mod word;
 
+#[cfg(feature = "serde")]
+mod serialize;
+
+#[cfg(feature = "defmt")]
+mod logging;
+
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod generate;
+
+// dev-dependencies that are only used by feature-gated tests
+#[cfg(all(test, not(feature = "serde")))]
+use {rmp_serde as _, serde_json as _};
+
+// dev-dependencies that are only used by benchmarks
+#[cfg(test)]
+use criterion as _;
+
 #[cfg(feature = "derive")]
 pub use mil_std_1553b_derive as derive;
 
 pub use crate::fields::Field;
 
-pub use crate::message::{Message, Packet};
+pub use crate::events::{Event, Events};
+
+pub use crate::controller::{
+    Action, BusControl, ControlState, Handling, Outcome, RetryPolicy, ServiceRequests,
+};
+
+pub use crate::icd::{Definition, Parameter, Reading, Readings, Validity, Value};
 
-pub use crate::errors::{Error, MessageError, Result, SubsystemError, SystemError, TerminalError};
+#[cfg(feature = "codegen")]
+pub use crate::icd::{Generator, IcdError};
+
+pub use crate::message::{
+    Aligned, Fault, Injection, InvalidMasks, Layout, LayoutMut, Message, MessageRef, Packed,
+    Packet, PacketReader, PacketWriter, SideChannel, Transfer, Violation,
+};
+
+pub use crate::terminal::{
+    BitFault, BitWord, Buffer, Buffers, CircularBuffer, DoubleBuffer, History, Illegalization,
+    SelfTest, SingleBuffer, TimeTag, TransmitterSelect, Transmitters,
+};
+
+#[cfg(feature = "serde")]
+pub use crate::serialize::raw;
+
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub use crate::generate::Faults;
+
+pub use crate::errors::{
+    Error, MessageError, ParseError, ParseErrorKind, Result, SubsystemError, SystemError,
+    TerminalError,
+};
 
 pub use crate::word::{CommandWord, DataWord, Header, StatusWord, Word, WordType};
 
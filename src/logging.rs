@@ -0,0 +1,121 @@
+//! Implementations of defmt::Format for words, packets and messages
+//!
+//! Flags and errors derive their implementations where they are
+//! declared. Words are written in decoded form using the usual
+//! abbreviations, e.g. `CMD RT5 T SA3 WC2` for a command word or
+//! `STS RT5 SR BUSY` for a status word with flags set.
+
+use defmt::{Format, Formatter};
+
+use crate::{CommandWord, DataWord, Message, Packet, StatusWord, Word, WordType};
+
+impl Format for CommandWord {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(
+            f,
+            "CMD RT{=u8} {=str} SA{=u8}",
+            u8::from(self.address()),
+            if self.is_transmit() { "T" } else { "R" },
+            u8::from(self.subaddress())
+        );
+
+        if self.is_mode_code() {
+            defmt::write!(f, " MC {}", self.mode_code());
+        } else {
+            defmt::write!(f, " WC{=u8}", self.word_count());
+        }
+
+        if !self.check_parity() {
+            defmt::write!(f, " PARITY");
+        }
+    }
+}
+
+impl Format for StatusWord {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "STS RT{=u8}", u8::from(self.address()));
+
+        if self.message_error().is_error() {
+            defmt::write!(f, " ME");
+        }
+        if self.instrumentation().is_command() {
+            defmt::write!(f, " INSTR");
+        }
+        if self.service_request().is_service() {
+            defmt::write!(f, " SR");
+        }
+        if self.reserved().is_value() {
+            defmt::write!(f, " RSV{=u8}", u8::from(self.reserved()));
+        }
+        if self.broadcast_received().is_received() {
+            defmt::write!(f, " BCR");
+        }
+        if self.terminal_busy().is_busy() {
+            defmt::write!(f, " BUSY");
+        }
+        if self.subsystem_error().is_error() {
+            defmt::write!(f, " SSF");
+        }
+        if self.dynamic_bus_acceptance().is_accepted() {
+            defmt::write!(f, " DBCA");
+        }
+        if self.terminal_error().is_error() {
+            defmt::write!(f, " TF");
+        }
+        if !self.check_parity() {
+            defmt::write!(f, " PARITY");
+        }
+    }
+}
+
+impl Format for DataWord {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "DATA 0x{=u16:04X}", self.as_value());
+
+        if !self.check_parity() {
+            defmt::write!(f, " PARITY");
+        }
+    }
+}
+
+impl Format for WordType {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            Self::None => defmt::write!(f, "NONE"),
+            Self::Command(w) => w.format(f),
+            Self::Status(w) => w.format(f),
+            Self::Data(w) => w.format(f),
+        }
+    }
+}
+
+impl Format for Packet {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(
+            f,
+            "PKT {=u8:03b} 0x{=u16:04X} {=u8}",
+            self.sync,
+            u16::from_be_bytes(self.body),
+            self.parity
+        );
+    }
+}
+
+impl<const WORDS: usize> Format for Message<WORDS> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "[");
+
+        for (index, word) in self.words().enumerate() {
+            if index > 0 {
+                defmt::write!(f, ", ");
+            }
+            word.format(f);
+        }
+
+        defmt::write!(f, "]");
+
+        if let Err(e) = self.validate() {
+            defmt::write!(f, " INVALID {}", e);
+        }
+    }
+}
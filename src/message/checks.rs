@@ -0,0 +1,229 @@
+use super::PACKET_BITS;
+use crate::Packet;
+
+/// The number of bytes that hold 64 packed words
+const CHUNK_BYTES: usize = 160;
+
+/// The number of bytes that hold two packed words
+const PAIR_BYTES: usize = 5;
+
+/// Bit n is set if the nibble n has an odd number of ones
+const NIBBLE_PARITY: u64 = 0x6996;
+
+/// Bit n is set if n is a valid sync pattern (data or service)
+const VALID_SYNC: u64 = 1 << Packet::DATA_SYNC | 1 << Packet::SERV_SYNC;
+
+/// Bulk parity and sync checks over packed words
+///
+/// Checks every 20-bit word of a packed buffer (as written by
+/// [Message::write](crate::Message::write)) and yields a bitmask
+/// of the invalid words for each group of 64 words. Bit `n` of a
+/// mask is set if word `n` of the group has bad parity or an
+/// invalid sync pattern, and the last mask only covers the words
+/// that are left.
+///
+/// Words are checked two at a time from each five bytes of the
+/// buffer, using a branch-free parity fold and lookup tables
+/// held in constants. This is much faster than reading each
+/// [Packet] and calling [check_parity](Packet::check_parity),
+/// and doesn't need a hardware popcount instruction.
+///
+/// [Message::validate](crate::Message::validate) doesn't use these
+/// checks, because a [Message](crate::Message) holds words that
+/// were already decoded from the buffer. Use them to screen large
+/// captures before reading the messages out of them.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let data = [0b10000011, 0b00001100, 0b00100010, 0b11010000, 0b11010011];
+///     let mut masks = InvalidMasks::new(&data);
+///
+///     // the parity of the second word is wrong
+///     assert_eq!(masks.next(), Some(0b10));
+///     assert_eq!(masks.next(), None);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct InvalidMasks<'a> {
+    /// The bytes that haven't been checked
+    data: &'a [u8],
+}
+
+impl<'a> InvalidMasks<'a> {
+    /// Create bulk checks over a packed buffer
+    ///
+    /// The first word must be aligned to the beginning of the
+    /// buffer, and any bits after the last whole word are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of packed words
+    ///
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Get the number of words that haven't been checked
+    pub fn remaining(&self) -> usize {
+        self.data.len().saturating_mul(8) / PACKET_BITS
+    }
+}
+
+impl Iterator for InvalidMasks<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining() == 0 {
+            return None;
+        }
+
+        let (chunk, rest) = self.data.split_at(self.data.len().min(CHUNK_BYTES));
+        self.data = rest;
+
+        let mut mask = 0;
+        let mut pairs = chunk.chunks_exact(PAIR_BYTES);
+
+        for (index, pair) in pairs.by_ref().enumerate() {
+            mask |= check_pair(load(pair)) << (index * 2);
+        }
+
+        // a single word may be left at the end of the buffer
+        let tail = pairs.remainder();
+        if tail.len() >= 3 {
+            let index = chunk.len() / PAIR_BYTES;
+            mask |= (check_pair(load(&tail[..3]) << 16) & 1) << (index * 2);
+        }
+
+        Some(mask)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let masks = self.remaining().div_ceil(64);
+        (masks, Some(masks))
+    }
+}
+
+impl ExactSizeIterator for InvalidMasks<'_> {}
+
+/// Load big-endian bytes into the low bits of a u64
+#[inline]
+fn load(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |v, b| (v << 8) | u64::from(*b))
+}
+
+/// Check the two words in the low 40 bits of a value
+///
+/// Returns a mask with bit 0 set if the first (upper) word is
+/// invalid, and bit 1 set if the second (lower) word is invalid.
+#[inline]
+fn check_pair(value: u64) -> u64 {
+    let a = (value >> 20) & 0xfffff;
+    let b = value & 0xfffff;
+
+    // fold the 17 bits of body and parity of each word
+    // into a nibble, with the words in separate lanes
+    let mut x = ((a & 0x1ffff) << 32) | (b & 0x1ffff);
+    x ^= (x >> 16) & 0x0000_ffff_0000_ffff;
+    x ^= (x >> 8) & 0x0000_00ff_0000_00ff;
+    x ^= (x >> 4) & 0x0000_000f_0000_000f;
+
+    // odd parity means the total number of ones is odd
+    let parity_a = NIBBLE_PARITY >> ((x >> 32) & 0xf);
+    let parity_b = NIBBLE_PARITY >> (x & 0xf);
+
+    let sync_a = VALID_SYNC >> (a >> 17);
+    let sync_b = VALID_SYNC >> (b >> 17);
+
+    let valid_a = parity_a & sync_a & 1;
+    let valid_b = parity_b & sync_b & 1;
+
+    (valid_a ^ 1) | ((valid_b ^ 1) << 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PacketReader, PacketWriter};
+
+    /// Get the masks by checking each packet on its own
+    fn expected(data: &[u8]) -> std::vec::Vec<u64> {
+        let packets: std::vec::Vec<Packet> = PacketReader::new(data).collect();
+        packets
+            .chunks(64)
+            .map(|c| {
+                c.iter()
+                    .enumerate()
+                    .filter(|(_, p)| !p.is_valid())
+                    .fold(0, |m, (i, _)| m | 1 << i)
+            })
+            .collect()
+    }
+
+    /// Build a buffer of packets with every sync and parity combination
+    fn capture(words: usize) -> std::vec::Vec<u8> {
+        let mut data = std::vec![0u8; (words * PACKET_BITS).div_ceil(8)];
+        let mut writer = PacketWriter::new(&mut data);
+        for i in 0..words {
+            let value = (i as u16).wrapping_mul(0x9e37);
+            let packet = Packet::new((i % 8) as u8, value.to_be_bytes(), ((i / 8) % 2) as u8);
+            writer.push(&packet).unwrap();
+        }
+        data
+    }
+
+    #[test]
+    fn test_invalid_masks_matches_packets() {
+        for words in 0..200 {
+            let data = capture(words);
+            let masks: std::vec::Vec<u64> = InvalidMasks::new(&data).collect();
+            assert_eq!(masks, expected(&data), "{} words", words);
+        }
+    }
+
+    #[test]
+    fn test_invalid_masks_trailing_bytes() {
+        for extra in 0..8 {
+            let mut data = capture(7);
+            data.resize(data.len() + extra, 0xff);
+            let masks: std::vec::Vec<u64> = InvalidMasks::new(&data).collect();
+            assert_eq!(masks, expected(&data), "{} extra bytes", extra);
+        }
+    }
+
+    #[test]
+    fn test_invalid_masks_valid_message() {
+        let message = crate::Message::<3>::new()
+            .with_command("05-R-03-02".parse::<crate::CommandWord>().unwrap())
+            .with_data(crate::DataWord::from(0x1234u16))
+            .with_data(crate::DataWord::from(0xabcdu16));
+
+        let mut data = [0u8; 8];
+        message.write(&mut data).unwrap();
+
+        let mut masks = InvalidMasks::new(&data);
+        assert_eq!(masks.len(), 1);
+        assert_eq!(masks.next(), Some(0));
+        assert_eq!(masks.next(), None);
+    }
+
+    #[test]
+    fn test_invalid_masks_empty() {
+        assert_eq!(InvalidMasks::new(&[]).next(), None);
+        assert_eq!(InvalidMasks::new(&[0xff, 0xff]).next(), None);
+        assert_eq!(InvalidMasks::new(&[0xff; 3]).remaining(), 1);
+    }
+
+    #[test]
+    fn test_invalid_masks_groups() {
+        let data = capture(130);
+        let masks = InvalidMasks::new(&data);
+        assert_eq!(masks.len(), 3);
+        let total: u32 = expected(&data).iter().map(|m| m.count_ones()).sum();
+        assert_eq!(masks.map(u64::count_ones).sum::<u32>(), total);
+    }
+}
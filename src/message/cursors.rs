@@ -0,0 +1,313 @@
+use super::PACKET_BITS;
+use crate::errors::{Error, Result};
+use crate::Packet;
+
+/// An iterator over the 20-bit packets of a byte buffer
+///
+/// Walks the buffer as a series of packed (sync, body, parity)
+/// triplets, starting at any bit offset. Iteration stops when
+/// there aren't enough bits left for a whole packet.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let data = [0b10000011, 0b00001100, 0b00100010, 0b11010000, 0b11010010];
+///     let mut packets = PacketReader::new(&data);
+///
+///     assert!(packets.next().unwrap().is_service());
+///     assert!(packets.next().unwrap().is_data());
+///     assert_eq!(packets.next(), None);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct PacketReader<'a> {
+    /// The bytes to read from
+    data: &'a [u8],
+
+    /// The bit offset of the next packet
+    offset: usize,
+}
+
+impl<'a> PacketReader<'a> {
+    /// Create a reader that starts at the beginning of a buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of bytes to read
+    ///
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// Constructor method to start reading at a bit offset
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The **bit** offset of the first packet
+    ///
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Get the bit offset of the next packet
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get the number of whole packets left in the buffer
+    pub fn remaining(&self) -> usize {
+        self.bits().saturating_sub(self.offset) / PACKET_BITS
+    }
+
+    /// Skip a number of bits, such as a gap between words
+    ///
+    /// # Arguments
+    ///
+    /// * `bits` - The number of bits to skip
+    ///
+    pub fn advance(&mut self, bits: usize) {
+        self.offset = self.offset.saturating_add(bits);
+    }
+
+    /// Get the total number of bits in the buffer
+    fn bits(&self) -> usize {
+        self.data.len().saturating_mul(8)
+    }
+}
+
+impl Iterator for PacketReader<'_> {
+    type Item = Packet;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining() == 0 {
+            return None;
+        }
+        let packet = Packet::read(&self.data[self.offset / 8..], self.offset % 8).ok()?;
+        self.offset += PACKET_BITS;
+        Some(packet)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for PacketReader<'_> {}
+
+/// A cursor that appends 20-bit packets to a byte buffer
+///
+/// Each packet is written directly after the last, starting
+/// at any bit offset. Bits before the first packet are kept,
+/// and the unused bits of the last byte written are cleared.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let command: CommandWord = "05-R-03-01".parse().unwrap();
+///     let data = DataWord::from(0x1234u16);
+///
+///     let mut buffer = [0u8; 6];
+///     let mut writer = PacketWriter::new(&mut buffer);
+///
+///     writer.push(&Packet::try_from(WordType::from(command))?)?;
+///     writer.advance(4);
+///     writer.push(&Packet::try_from(WordType::from(data))?)?;
+///
+///     assert_eq!(writer.offset(), 44);
+///
+///     let mut packets = PacketReader::new(&buffer);
+///     assert_eq!(packets.next().unwrap().as_word::<CommandWord>()?, command);
+///     packets.advance(4);
+///     assert_eq!(packets.next().unwrap().as_word::<DataWord>()?, data);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug)]
+pub struct PacketWriter<'a> {
+    /// The bytes to write to
+    data: &'a mut [u8],
+
+    /// The bit offset of the next packet
+    offset: usize,
+}
+
+impl<'a> PacketWriter<'a> {
+    /// Create a writer that starts at the beginning of a buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of bytes to write
+    ///
+    pub fn new(data: &'a mut [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// Constructor method to start writing at a bit offset
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The **bit** offset of the first packet
+    ///
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Get the bit offset of the next packet
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get the number of bytes that have been written to
+    pub fn len(&self) -> usize {
+        self.offset.div_ceil(8)
+    }
+
+    /// Check if nothing has been written
+    #[must_use = "Returned value is not used"]
+    pub fn is_empty(&self) -> bool {
+        self.offset == 0
+    }
+
+    /// Get the number of whole packets that still fit in the buffer
+    pub fn remaining(&self) -> usize {
+        self.data
+            .len()
+            .saturating_mul(8)
+            .saturating_sub(self.offset)
+            / PACKET_BITS
+    }
+
+    /// Skip a number of bits, such as a gap between words
+    ///
+    /// Skipped bits are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `bits` - The number of bits to skip
+    ///
+    pub fn advance(&mut self, bits: usize) {
+        self.offset = self.offset.saturating_add(bits);
+    }
+
+    /// Write a packet at the cursor and move past it
+    ///
+    /// Fails without writing if the packet doesn't fit.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The packet to write
+    ///
+    pub fn push(&mut self, packet: &Packet) -> Result<()> {
+        if self.remaining() == 0 {
+            return Err(Error::OutOfBounds);
+        }
+        packet.write(&mut self.data[self.offset / 8..], self.offset % 8)?;
+        self.offset += PACKET_BITS;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: [u8; 5] = [0b10000011, 0b00001100, 0b00100010, 0b11010000, 0b11010010];
+
+    #[test]
+    fn test_reader_packets() {
+        let mut packets = PacketReader::new(&DATA);
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets.next(), Packet::read(&DATA, 0).ok());
+        assert_eq!(packets.offset(), 20);
+        assert_eq!(packets.next(), Packet::read(&DATA[2..], 4).ok());
+        assert_eq!(packets.next(), None);
+        assert_eq!(packets.len(), 0);
+    }
+
+    #[test]
+    fn test_reader_offset() {
+        let mut buffer = [0u8; 4];
+        buffer[0] = DATA[0] >> 3;
+        buffer[1] = (DATA[0] << 5) | (DATA[1] >> 3);
+        buffer[2] = (DATA[1] << 5) | (DATA[2] >> 3);
+        buffer[3] = DATA[2] << 5;
+
+        let mut packets = PacketReader::new(&buffer).with_offset(3);
+        assert_eq!(packets.next(), Packet::read(&DATA, 0).ok());
+        assert_eq!(packets.next(), None);
+    }
+
+    #[test]
+    fn test_reader_short() {
+        assert_eq!(PacketReader::new(&DATA[..2]).next(), None);
+        assert_eq!(PacketReader::new(&DATA[..3]).with_offset(5).next(), None);
+        assert_eq!(
+            PacketReader::new(&DATA).with_offset(usize::MAX).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_writer_round_trip() {
+        let mut buffer = [0u8; 5];
+        let mut writer = PacketWriter::new(&mut buffer);
+        for packet in PacketReader::new(&DATA) {
+            writer.push(&packet).unwrap();
+        }
+        assert_eq!(writer.len(), 5);
+        assert_eq!(writer.remaining(), 0);
+        assert_eq!(buffer, DATA);
+    }
+
+    #[test]
+    fn test_writer_offset() {
+        let packet = Packet::read(&DATA, 0).unwrap();
+        let mut buffer = [0b1110_0000, 0, 0, 0];
+        let mut writer = PacketWriter::new(&mut buffer).with_offset(3);
+        assert!(!writer.is_empty());
+        writer.push(&packet).unwrap();
+        assert_eq!(writer.offset(), 23);
+
+        // bits before the offset are kept
+        assert_eq!(buffer[0] & 0b1110_0000, 0b1110_0000);
+        let mut packets = PacketReader::new(&buffer).with_offset(3);
+        assert_eq!(packets.next(), Some(packet));
+    }
+
+    #[test]
+    fn test_writer_full() {
+        let packet = Packet::read(&DATA, 0).unwrap();
+        let mut buffer = [0u8; 4];
+        let mut writer = PacketWriter::new(&mut buffer);
+        assert!(writer.is_empty());
+        assert_eq!(writer.push(&packet), Ok(()));
+        assert_eq!(writer.push(&packet), Err(Error::OutOfBounds));
+        assert_eq!(writer.offset(), 20);
+    }
+
+    #[test]
+    fn test_writer_gap() {
+        let packet = Packet::read(&DATA, 0).unwrap();
+        let mut buffer = [0u8; 6];
+        let mut writer = PacketWriter::new(&mut buffer);
+        writer.push(&packet).unwrap();
+        writer.advance(4);
+        writer.push(&packet).unwrap();
+        assert_eq!(writer.len(), 6);
+
+        let mut packets = PacketReader::new(&buffer);
+        assert_eq!(packets.next(), Some(packet));
+        packets.advance(4);
+        assert_eq!(packets.next(), Some(packet));
+    }
+}
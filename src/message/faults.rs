@@ -0,0 +1,523 @@
+use super::PACKET_BITS;
+use crate::errors::{Error, Result};
+use crate::flags::Address;
+use crate::word::{DataWord, WordType};
+use crate::{Message, Packet, PacketWriter, Transfer, Word};
+
+/// A named fault to inject while writing words to a buffer
+///
+/// Faults are applied to the packed bit stream as it is written,
+/// so the message or transfer being written stays valid. Word
+/// indexes count every word written, starting with the header.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let message = Message::<3>::new()
+///         .with_command(CommandWord::new()
+///             .with_subaddress(SubAddress::Value(2))
+///             .with_word_count(2)
+///             .build()?
+///         )
+///         .with_data(DataWord::new().with_calculated_parity())
+///         .with_data(DataWord::new().with_calculated_parity())
+///         .build()?;
+///
+///     let mut buffer = [0u8; 8];
+///     let injection = Fault::Parity(2).write_message(&message, &mut buffer)?;
+///
+///     assert_eq!(injection.word(), 2);
+///     assert_eq!(injection.bits(), 60);
+///     assert!(Packet::read(&buffer[5..], 0)?.check_parity() == false);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Fault {
+    /// Invert the parity bit of the word at an index
+    Parity(usize),
+
+    /// Swap the data and service sync of the word at an index
+    Sync(usize),
+
+    /// Write some number of extra data words after the message
+    LongWordCount(usize),
+
+    /// Leave some number of data words off the end of the message
+    ShortWordCount(usize),
+
+    /// Leave a gap of some number of bits before the word at an index
+    Gap {
+        /// The index of the word after the gap
+        index: usize,
+
+        /// The number of idle bits in the gap
+        bits: usize,
+    },
+
+    /// Write the status word of a message with a different address
+    StatusAddress(Address),
+}
+
+/// A record of a fault that was injected into a buffer
+///
+/// Returned by the write methods of [Fault] so that logs can
+/// show exactly what was done to the bit stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Injection {
+    fault: Fault,
+    word: usize,
+    bits: usize,
+}
+
+impl Injection {
+    /// Get the fault that was injected
+    pub fn fault(&self) -> Fault {
+        self.fault
+    }
+
+    /// Get the index of the first word affected by the fault
+    pub fn word(&self) -> usize {
+        self.word
+    }
+
+    /// Get the total number of bits written, including any gap
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+}
+
+impl Fault {
+    /// Write a packet to a byte array with this fault
+    ///
+    /// Only [Parity](Self::Parity), [Sync](Self::Sync) and
+    /// [Gap](Self::Gap) faults at index 0 can be applied to
+    /// a single packet, other faults return an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The packet to write
+    /// * `data` - A slice of bytes to write
+    /// * `offset` - The **bit** offset at which to write
+    ///
+    pub fn write_packet(
+        &self,
+        packet: &Packet,
+        data: &mut [u8],
+        offset: usize,
+    ) -> Result<Injection> {
+        self.check_word_fault()?;
+        self.write(core::iter::once(Ok(*packet)), 1, data, offset)
+    }
+
+    /// Write the command words of a transfer to a byte array with this fault
+    ///
+    /// Only [Parity](Self::Parity), [Sync](Self::Sync) and
+    /// [Gap](Self::Gap) faults can be applied to a transfer,
+    /// other faults return an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `transfer` - The transfer to write
+    /// * `data` - A slice of bytes to write
+    ///
+    pub fn write_transfer(&self, transfer: &Transfer, data: &mut [u8]) -> Result<Injection> {
+        self.check_word_fault()?;
+        let commands = [Some(transfer.command()), transfer.transmit_command()];
+        let count = commands.iter().flatten().count();
+        let packets = commands
+            .into_iter()
+            .flatten()
+            .map(|c| Packet::try_from(WordType::from(*c)));
+        self.write(packets, count, data, 0)
+    }
+
+    /// Write a message to a byte array with this fault
+    ///
+    /// [ShortWordCount](Self::ShortWordCount) fails if the message
+    /// has fewer data words than should be dropped, and
+    /// [StatusAddress](Self::StatusAddress) fails if the message
+    /// doesn't start with a status word.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to write
+    /// * `data` - A slice of bytes to write
+    ///
+    pub fn write_message<const WORDS: usize>(
+        &self,
+        message: &Message<WORDS>,
+        data: &mut [u8],
+    ) -> Result<Injection> {
+        let (count, extra) = match *self {
+            Self::ShortWordCount(n) if n > message.count() => return Err(Error::InvalidMessage),
+            Self::ShortWordCount(n) => (message.length() - n, 0),
+            Self::LongWordCount(n) => (message.length(), n),
+            _ => (message.length(), 0),
+        };
+
+        let status = match *self {
+            Self::StatusAddress(a) => match message.status() {
+                Some(w) => Some(w.with_address(a).with_calculated_parity()),
+                None => return Err(Error::InvalidMessage),
+            },
+            _ => None,
+        };
+
+        let filler = WordType::from(DataWord::new().with_calculated_parity());
+        let packets = message
+            .words()
+            .take(count)
+            .enumerate()
+            .map(|(i, w)| match (i, status) {
+                (0, Some(s)) => Packet::try_from(WordType::from(s)),
+                _ => Packet::try_from(w),
+            })
+            .chain(core::iter::repeat_n(Packet::try_from(filler), extra));
+
+        let total = count.checked_add(extra).ok_or(Error::OutOfBounds)?;
+        self.write(packets, total, data, 0)
+    }
+
+    /// Fail if the fault needs a message to be applied
+    fn check_word_fault(&self) -> Result<()> {
+        match self {
+            Self::Parity(_) | Self::Sync(_) | Self::Gap { .. } => Ok(()),
+            _ => Err(Error::InvalidMessage),
+        }
+    }
+
+    /// Get the index of the first word affected by the fault
+    fn target(&self, count: usize) -> Result<usize> {
+        let (index, limit) = match *self {
+            Self::Parity(i) | Self::Sync(i) | Self::Gap { index: i, .. } => (i, count),
+            Self::LongWordCount(n) => (count.saturating_sub(n), count),
+            Self::ShortWordCount(_) => (count, usize::MAX),
+            Self::StatusAddress(_) => (0, count),
+        };
+
+        if index < limit {
+            Ok(index)
+        } else {
+            Err(Error::OutOfBounds)
+        }
+    }
+
+    /// Apply the fault to a packet if it targets the given index
+    fn apply(&self, index: usize, mut packet: Packet) -> Packet {
+        match *self {
+            Self::Parity(i) if i == index => {
+                packet.parity ^= 1;
+            }
+            Self::Sync(i) if i == index => {
+                packet.sync = match packet.is_data() {
+                    true => Packet::SERV_SYNC,
+                    false => Packet::DATA_SYNC,
+                };
+            }
+            _ => (),
+        }
+        packet
+    }
+
+    /// Write packets to a byte array, applying the fault
+    fn write(
+        &self,
+        packets: impl Iterator<Item = Result<Packet>>,
+        count: usize,
+        data: &mut [u8],
+        offset: usize,
+    ) -> Result<Injection> {
+        let word = self.target(count)?;
+
+        let gap = match *self {
+            Self::Gap { bits, .. } => bits,
+            _ => 0,
+        };
+
+        let end = count
+            .checked_mul(PACKET_BITS)
+            .and_then(|b| b.checked_add(gap))
+            .and_then(|b| b.checked_add(offset))
+            .ok_or(Error::OutOfBounds)?;
+        let length = end.div_ceil(8);
+
+        if data.len() < length {
+            return Err(Error::OutOfBounds);
+        }
+
+        // clear everything after the offset so gaps are idle
+        let first = offset / 8;
+        if first < length {
+            data[first] &= !(u8::MAX >> (offset % 8));
+            data[first + 1..length].fill(0);
+        }
+
+        let mut writer = PacketWriter::new(data).with_offset(offset);
+        for (index, packet) in packets.enumerate() {
+            if index == word {
+                writer.advance(gap);
+            }
+            writer.push(&self.apply(index, packet?))?;
+        }
+
+        Ok(Injection {
+            fault: *self,
+            word,
+            bits: end - offset,
+        })
+    }
+}
+
+impl core::fmt::Display for Fault {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Parity(_) => write!(f, "parity error"),
+            Self::Sync(_) => write!(f, "sync error"),
+            Self::LongWordCount(n) => write!(f, "word count too long by {}", n),
+            Self::ShortWordCount(n) => write!(f, "word count too short by {}", n),
+            Self::Gap { bits, .. } => write!(f, "gap of {} bits", bits),
+            Self::StatusAddress(a) => write!(f, "status from address {}", a),
+        }
+    }
+}
+
+impl core::fmt::Display for Injection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} at word {} ({} bits written)",
+            self.fault, self.word, self.bits
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CommandWord, StatusWord, SubAddress, TransmitReceive};
+
+    fn command_message() -> Message<3> {
+        Message::new()
+            .with_command(
+                CommandWord::new()
+                    .with_address(Address::Value(5))
+                    .with_subaddress(SubAddress::Value(2))
+                    .with_word_count(2)
+                    .with_calculated_parity(),
+            )
+            .with_data(DataWord::from_value(0x1234).with_calculated_parity())
+            .with_data(DataWord::from_value(0xABCD).with_calculated_parity())
+    }
+
+    fn status_message() -> Message<2> {
+        Message::new()
+            .with_status(
+                StatusWord::new()
+                    .with_address(Address::Value(7))
+                    .with_calculated_parity(),
+            )
+            .with_data(DataWord::from_value(0x00FF).with_calculated_parity())
+    }
+
+    fn read(data: &[u8], bit: usize) -> Packet {
+        Packet::read(&data[bit / 8..], bit % 8).unwrap()
+    }
+
+    #[test]
+    fn test_fault_parity() {
+        let mut data = [0u8; 8];
+        let injection = Fault::Parity(1)
+            .write_message(&command_message(), &mut data)
+            .unwrap();
+
+        assert_eq!(injection.fault(), Fault::Parity(1));
+        assert_eq!(injection.word(), 1);
+        assert_eq!(injection.bits(), 60);
+        assert!(read(&data, 0).is_valid());
+        assert!(!read(&data, 20).check_parity());
+        assert!(read(&data, 40).is_valid());
+    }
+
+    #[test]
+    fn test_fault_sync() {
+        let mut data = [0u8; 8];
+        let _ = Fault::Sync(2)
+            .write_message(&command_message(), &mut data)
+            .unwrap();
+
+        let packet = read(&data, 40);
+        assert!(packet.is_service());
+        assert!(packet.check_parity());
+        assert_eq!(packet.body, [0xAB, 0xCD]);
+    }
+
+    #[test]
+    fn test_fault_long_word_count() {
+        let mut data = [0u8; 13];
+        let injection = Fault::LongWordCount(2)
+            .write_message(&command_message(), &mut data)
+            .unwrap();
+
+        assert_eq!(injection.word(), 3);
+        assert_eq!(injection.bits(), 100);
+        assert!(read(&data, 60).is_data());
+        assert!(read(&data, 80).is_valid());
+
+        let result = Message::<5>::read_command(&data).unwrap();
+        assert_eq!(result.count(), 2);
+    }
+
+    #[test]
+    fn test_fault_short_word_count() {
+        let mut data = [0u8; 5];
+        let injection = Fault::ShortWordCount(1)
+            .write_message(&command_message(), &mut data)
+            .unwrap();
+
+        assert_eq!(injection.word(), 2);
+        assert_eq!(injection.bits(), 40);
+        assert_eq!(
+            Message::<3>::read_command(&data),
+            Err(Error::InvalidMessage)
+        );
+    }
+
+    #[test]
+    fn test_fault_short_word_count_fail() {
+        let mut data = [0u8; 8];
+        let result = Fault::ShortWordCount(3).write_message(&command_message(), &mut data);
+        assert_eq!(result, Err(Error::InvalidMessage));
+    }
+
+    #[test]
+    fn test_fault_gap() {
+        let mut data = [0xFFu8; 9];
+        let injection = Fault::Gap { index: 1, bits: 4 }
+            .write_message(&command_message(), &mut data)
+            .unwrap();
+
+        assert_eq!(injection.bits(), 64);
+        assert!(read(&data, 0).is_valid());
+        assert_eq!(data[2] & 0b1111, 0);
+        assert_eq!(read(&data, 24).body, [0x12, 0x34]);
+        assert_eq!(read(&data, 44).body, [0xAB, 0xCD]);
+        assert_eq!(data[8], 0xFF);
+    }
+
+    #[test]
+    fn test_fault_status_address() {
+        let mut data = [0u8; 5];
+        let injection = Fault::StatusAddress(Address::Value(9))
+            .write_message(&status_message(), &mut data)
+            .unwrap();
+
+        assert_eq!(injection.word(), 0);
+
+        let message = Message::<2>::read_status(&data).unwrap();
+        let status = message.status().unwrap();
+        assert_eq!(status.address(), Address::Value(9));
+        assert!(status.check_parity());
+    }
+
+    #[test]
+    fn test_fault_status_address_fail() {
+        let mut data = [0u8; 8];
+        let result =
+            Fault::StatusAddress(Address::Value(9)).write_message(&command_message(), &mut data);
+        assert_eq!(result, Err(Error::InvalidMessage));
+    }
+
+    #[test]
+    fn test_fault_index_out_of_bounds() {
+        let mut data = [0u8; 8];
+        let result = Fault::Parity(3).write_message(&command_message(), &mut data);
+        assert_eq!(result, Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_fault_buffer_too_small() {
+        let mut data = [0u8; 7];
+        let result = Fault::Parity(0).write_message(&command_message(), &mut data);
+        assert_eq!(result, Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_fault_overflow() {
+        let mut data = [0u8; 8];
+        let packet = Packet::new(Packet::DATA_SYNC, [0x12, 0x34], 0);
+
+        let result = Fault::LongWordCount(usize::MAX).write_message(&command_message(), &mut data);
+        assert_eq!(result, Err(Error::OutOfBounds));
+
+        let result = Fault::Gap {
+            index: 0,
+            bits: usize::MAX,
+        }
+        .write_message(&command_message(), &mut data);
+        assert_eq!(result, Err(Error::OutOfBounds));
+
+        let result = Fault::Parity(0).write_packet(&packet, &mut data, usize::MAX);
+        assert_eq!(result, Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_fault_write_packet() {
+        let mut data = [0u8; 4];
+        let packet = Packet::new(Packet::DATA_SYNC, [0x12, 0x34], 0);
+        let injection = Fault::Parity(0)
+            .write_packet(&packet, &mut data, 6)
+            .unwrap();
+
+        assert_eq!(injection.bits(), 20);
+        assert_eq!(Packet::read(&data, 6).unwrap().parity, 1);
+
+        let result = Fault::LongWordCount(1).write_packet(&packet, &mut data, 0);
+        assert_eq!(result, Err(Error::InvalidMessage));
+    }
+
+    #[test]
+    fn test_fault_write_transfer() {
+        let receive = CommandWord::new()
+            .with_address(Address::Value(1))
+            .with_subaddress(SubAddress::Value(2))
+            .with_transmit_receive(TransmitReceive::Receive)
+            .with_word_count(4)
+            .with_calculated_parity();
+        let transmit = receive
+            .with_address(Address::Value(5))
+            .with_transmit_receive(TransmitReceive::Transmit)
+            .with_calculated_parity();
+        let transfer = Transfer::rt_to_rt(receive, transmit).unwrap();
+
+        let mut data = [0u8; 5];
+        let injection = Fault::Sync(1).write_transfer(&transfer, &mut data).unwrap();
+
+        assert_eq!(injection.bits(), 40);
+        assert!(read(&data, 0).is_service());
+        assert!(read(&data, 20).is_data());
+        assert_eq!(read(&data, 20).as_word::<CommandWord>(), Ok(transmit));
+    }
+
+    #[test]
+    fn test_fault_display() {
+        let injection = Injection {
+            fault: Fault::Gap { index: 1, bits: 4 },
+            word: 1,
+            bits: 64,
+        };
+        assert_eq!(
+            std::format!("{}", injection),
+            "gap of 4 bits at word 1 (64 bits written)"
+        );
+        assert_eq!(
+            std::format!("{}", Fault::StatusAddress(Address::Value(9))),
+            "status from address 09"
+        );
+    }
+}
@@ -0,0 +1,523 @@
+use super::PACKET_BITS;
+use crate::errors::{parity, Error, Result};
+use crate::{Packet, PacketReader};
+
+/// A storage format that packets can be read from
+///
+/// Layouts wrap a buffer and give access to the packets in it
+/// by index, so that [Message]s can be read from any hardware
+/// format with [read_from](crate::Message::read_from). See
+/// [Packed], [Aligned] and [SideChannel] for the formats
+/// provided by this crate.
+///
+/// [Message]: crate::Message
+pub trait Layout {
+    /// Get the number of whole words the buffer can hold
+    fn capacity(&self) -> usize;
+
+    /// Read the packet at a word index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the word
+    ///
+    fn read(&self, index: usize) -> Result<Packet>;
+}
+
+/// A storage format that packets can be written to
+///
+/// See [Layout] for more information.
+pub trait LayoutMut: Layout {
+    /// Write a packet at a word index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the word
+    /// * `packet` - The packet to write
+    ///
+    fn write(&mut self, index: usize, packet: &Packet) -> Result<()>;
+}
+
+/// Words packed back-to-back as 20-bit triplets
+///
+/// This is the format used by [Message::read_command](crate::Message::read_command)
+/// and [Message::write](crate::Message::write): 3-bit sync, 16-bit
+/// body and 1-bit parity, with no space between words.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let data = [0b10000011, 0b00001100, 0b00100010, 0b11010000, 0b11010010];
+///     let layout = Packed::new(&data);
+///
+///     assert_eq!(layout.capacity(), 2);
+///     assert!(layout.read(0)?.is_service());
+///     assert!(layout.read(1)?.is_data());
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packed<B> {
+    buffer: B,
+}
+
+impl<B> Packed<B> {
+    /// Wrap a byte buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The bytes to read or write
+    ///
+    pub fn new(buffer: B) -> Self {
+        Self { buffer }
+    }
+
+    /// Unwrap the byte buffer
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B: AsRef<[u8]>> Layout for Packed<B> {
+    fn capacity(&self) -> usize {
+        self.buffer.as_ref().len().saturating_mul(8) / PACKET_BITS
+    }
+
+    fn read(&self, index: usize) -> Result<Packet> {
+        let offset = index.checked_mul(PACKET_BITS).ok_or(Error::OutOfBounds)?;
+        PacketReader::new(self.buffer.as_ref())
+            .with_offset(offset)
+            .next()
+            .ok_or(Error::OutOfBounds)
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> LayoutMut for Packed<B> {
+    fn write(&mut self, index: usize, packet: &Packet) -> Result<()> {
+        let offset = index.checked_mul(PACKET_BITS).ok_or(Error::OutOfBounds)?;
+        if index >= self.capacity() {
+            return Err(Error::OutOfBounds);
+        }
+
+        // the bytes that hold the word, shared with its neighbours
+        let start = offset / 8;
+        let shift = offset % 8;
+        let bytes = &mut self.buffer.as_mut()[start..(offset + PACKET_BITS).div_ceil(8)];
+
+        let mut window = [0u8; 4];
+        window[..bytes.len()].copy_from_slice(bytes);
+
+        // replace only the 20 bits of the word
+        let value = (u32::from(packet.sync & 0b111) << 17
+            | u32::from(u16::from_be_bytes(packet.body)) << 1
+            | u32::from(packet.parity & 0b1))
+            << (12 - shift);
+        let mask = 0xffff_f000 >> shift;
+        let result = (u32::from_be_bytes(window) & !mask) | value;
+
+        bytes.copy_from_slice(&result.to_be_bytes()[..bytes.len()]);
+        Ok(())
+    }
+}
+
+/// One word per u32, with sync and parity in flag bits
+///
+/// Each word is stored in the low 20 bits of a u32, as used by
+/// many capture cards:
+///
+/// | Bits   | Field  |
+/// |--------|--------|
+/// | 0-15   | Body   |
+/// | 16     | Parity |
+/// | 17-19  | Sync   |
+/// | 20-31  | Unused |
+///
+/// Unused bits are ignored when reading and cleared when writing.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let mut words = [0u32; 2];
+///     let mut layout = Aligned::new(&mut words);
+///
+///     layout.write(1, &Packet::try_from(WordType::from(DataWord::from(0x1234u16)))?)?;
+///
+///     assert_eq!(words[1], 0x0002_1234);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Aligned<B> {
+    buffer: B,
+}
+
+impl Aligned<()> {
+    /// The bit of the parity flag
+    pub const PARITY_SHIFT: u32 = 16;
+
+    /// The lowest bit of the sync flags
+    pub const SYNC_SHIFT: u32 = 17;
+}
+
+impl<B> Aligned<B> {
+    /// Wrap a buffer of u32 words
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The words to read or write
+    ///
+    pub fn new(buffer: B) -> Self {
+        Self { buffer }
+    }
+
+    /// Unwrap the buffer of u32 words
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<B: AsRef<[u32]>> Layout for Aligned<B> {
+    fn capacity(&self) -> usize {
+        self.buffer.as_ref().len()
+    }
+
+    fn read(&self, index: usize) -> Result<Packet> {
+        let value = *self.buffer.as_ref().get(index).ok_or(Error::OutOfBounds)?;
+        Ok(Packet::new(
+            ((value >> Aligned::SYNC_SHIFT) & 0b111) as u8,
+            (value as u16).to_be_bytes(),
+            ((value >> Aligned::PARITY_SHIFT) & 0b1) as u8,
+        ))
+    }
+}
+
+impl<B: AsRef<[u32]> + AsMut<[u32]>> LayoutMut for Aligned<B> {
+    fn write(&mut self, index: usize, packet: &Packet) -> Result<()> {
+        let value = self
+            .buffer
+            .as_mut()
+            .get_mut(index)
+            .ok_or(Error::OutOfBounds)?;
+        *value = u32::from(u16::from_be_bytes(packet.body))
+            | u32::from(packet.parity & 0b1) << Aligned::PARITY_SHIFT
+            | u32::from(packet.sync & 0b111) << Aligned::SYNC_SHIFT;
+        Ok(())
+    }
+}
+
+/// 16-bit words with sync and parity in a separate flag buffer
+///
+/// Bus chipsets usually give the 16-bit body of each word in one
+/// register, and report the kind of sync and any errors that were
+/// found in a separate status register. This layout reads the
+/// bodies from one buffer and a flag byte for each word from
+/// another:
+///
+/// * [SERVICE](Self::SERVICE) is set for command/status sync
+///   and cleared for data sync
+/// * [PARITY_ERROR](Self::PARITY_ERROR) is set if the word had bad parity
+/// * [SYNC_ERROR](Self::SYNC_ERROR) is set if the word had an invalid sync
+///
+/// Other flag bits are ignored when reading and cleared when writing.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let data = [0x1234u16, 0x5678];
+///     let flags = [SideChannel::SERVICE, SideChannel::PARITY_ERROR];
+///     let layout = SideChannel::new(&data, &flags);
+///
+///     assert!(layout.read(0)?.is_valid());
+///     assert!(layout.read(0)?.is_service());
+///     assert!(!layout.read(1)?.check_parity());
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SideChannel<D, F> {
+    data: D,
+    flags: F,
+}
+
+impl SideChannel<(), ()> {
+    /// The flag bit for command/status sync
+    pub const SERVICE: u8 = 0b001;
+
+    /// The flag bit for a parity error
+    pub const PARITY_ERROR: u8 = 0b010;
+
+    /// The flag bit for an invalid sync
+    pub const SYNC_ERROR: u8 = 0b100;
+}
+
+impl<D, F> SideChannel<D, F> {
+    /// Wrap a buffer of word bodies and a buffer of flags
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The 16-bit bodies of the words
+    /// * `flags` - The flag byte of each word
+    ///
+    pub fn new(data: D, flags: F) -> Self {
+        Self { data, flags }
+    }
+
+    /// Unwrap the body and flag buffers
+    pub fn into_inner(self) -> (D, F) {
+        (self.data, self.flags)
+    }
+}
+
+impl<D: AsRef<[u16]>, F: AsRef<[u8]>> Layout for SideChannel<D, F> {
+    fn capacity(&self) -> usize {
+        self.data.as_ref().len().min(self.flags.as_ref().len())
+    }
+
+    fn read(&self, index: usize) -> Result<Packet> {
+        let body = *self.data.as_ref().get(index).ok_or(Error::OutOfBounds)?;
+        let flags = *self.flags.as_ref().get(index).ok_or(Error::OutOfBounds)?;
+
+        let sync = match (
+            flags & SideChannel::SYNC_ERROR,
+            flags & SideChannel::SERVICE,
+        ) {
+            (0, 0) => Packet::DATA_SYNC,
+            (0, _) => Packet::SERV_SYNC,
+            _ => 0,
+        };
+
+        let parity = match flags & SideChannel::PARITY_ERROR {
+            0 => parity(body),
+            _ => parity(body) ^ 1,
+        };
+
+        Ok(Packet::new(sync, body.to_be_bytes(), parity))
+    }
+}
+
+impl<D, F> LayoutMut for SideChannel<D, F>
+where
+    D: AsRef<[u16]> + AsMut<[u16]>,
+    F: AsRef<[u8]> + AsMut<[u8]>,
+{
+    fn write(&mut self, index: usize, packet: &Packet) -> Result<()> {
+        if index >= self.capacity() {
+            return Err(Error::OutOfBounds);
+        }
+
+        let mut flags = 0;
+        if packet.is_service() {
+            flags |= SideChannel::SERVICE;
+        }
+        if !packet.check_parity() {
+            flags |= SideChannel::PARITY_ERROR;
+        }
+        if !packet.check_sync() {
+            flags |= SideChannel::SYNC_ERROR;
+        }
+
+        self.data.as_mut()[index] = u16::from_be_bytes(packet.body);
+        self.flags.as_mut()[index] = flags;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word::{CommandWord, DataWord, StatusWord, Word, WordType};
+    use crate::Message;
+
+    fn message() -> Message<3> {
+        Message::new()
+            .with_command("05-R-03-02".parse::<CommandWord>().unwrap())
+            .with_data(DataWord::from(0x1234u16))
+            .with_data(DataWord::from(0xabcdu16))
+    }
+
+    fn packet(word: WordType) -> Packet {
+        Packet::try_from(word).unwrap()
+    }
+
+    #[test]
+    fn test_packed_round_trip() {
+        let mut buffer = [0u8; 8];
+        let mut layout = Packed::new(&mut buffer);
+        assert_eq!(layout.capacity(), 3);
+
+        message().write_to(&mut layout).unwrap();
+        let result = Message::<3>::read_from::<CommandWord, _>(&layout).unwrap();
+        assert_eq!(result, message());
+
+        let mut bytes = [0u8; 8];
+        message().write(&mut bytes).unwrap();
+        assert_eq!(*layout.into_inner(), bytes);
+    }
+
+    #[test]
+    fn test_packed_random_access() {
+        let words: std::vec::Vec<Packet> = (0..7u16)
+            .map(|i| packet(DataWord::from(0xffff - i * 0x1111).into()))
+            .collect();
+
+        let mut layout = Packed::new([0u8; 18]);
+        for (index, word) in words.iter().enumerate().rev() {
+            layout.write(index, word).unwrap();
+        }
+        for (index, word) in words.iter().enumerate() {
+            assert_eq!(layout.read(index), Ok(*word));
+        }
+    }
+
+    #[test]
+    fn test_packed_keeps_neighbours() {
+        let mut layout = Packed::new([0xffu8; 5]);
+        let word = packet(DataWord::from(0u16).into());
+        layout.write(0, &word).unwrap();
+        assert_eq!(layout.read(0), Ok(word));
+        assert_eq!(layout.into_inner(), [0x20, 0x00, 0x1f, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn test_packed_out_of_bounds() {
+        let mut layout = Packed::new([0u8; 5]);
+        let word = packet(DataWord::from(1u16).into());
+        assert_eq!(layout.read(2), Err(Error::OutOfBounds));
+        assert_eq!(layout.read(usize::MAX), Err(Error::OutOfBounds));
+        assert_eq!(layout.write(2, &word), Err(Error::OutOfBounds));
+        assert_eq!(layout.write(usize::MAX, &word), Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_aligned_format() {
+        let mut layout = Aligned::new([0xfff0_0000u32; 2]);
+        let command = packet(
+            CommandWord::from_value(0x8421)
+                .with_calculated_parity()
+                .into(),
+        );
+        layout.write(0, &command).unwrap();
+
+        let value = layout.into_inner()[0];
+        assert_eq!(value & 0xffff, 0x8421);
+        assert_eq!((value >> 16) & 0b1, u32::from(command.parity));
+        assert_eq!((value >> 17) & 0b111, u32::from(Packet::SERV_SYNC));
+        assert_eq!(value >> 20, 0);
+    }
+
+    #[test]
+    fn test_aligned_ignores_unused_bits() {
+        let layout = Aligned::new([0xfff3_1234u32]);
+        let packet = layout.read(0).unwrap();
+        assert!(packet.is_data());
+        assert_eq!(packet.body, [0x12, 0x34]);
+        assert_eq!(layout.read(1), Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_aligned_round_trip() {
+        let mut layout = Aligned::new([0u32; 4]);
+        message().write_to(&mut layout).unwrap();
+        assert_eq!(layout.into_inner()[3], 0);
+
+        let result = Message::<3>::read_from::<CommandWord, _>(&layout).unwrap();
+        assert_eq!(result, message());
+    }
+
+    #[test]
+    fn test_side_channel_flags() {
+        let mut layout = SideChannel::new([0u16; 3], [0u8; 3]);
+        layout
+            .write(
+                0,
+                &packet(
+                    StatusWord::from_value(0x2800)
+                        .with_calculated_parity()
+                        .into(),
+                ),
+            )
+            .unwrap();
+        layout.write(1, &Packet::new(0b111, [0, 1], 1)).unwrap();
+        layout.write(2, &Packet::new(0b001, [0, 1], 1)).unwrap();
+
+        let (data, flags) = layout.into_inner();
+        assert_eq!(data, [0x2800, 1, 1]);
+        assert_eq!(
+            flags,
+            [
+                SideChannel::SERVICE,
+                SideChannel::SYNC_ERROR | SideChannel::PARITY_ERROR,
+                SideChannel::PARITY_ERROR
+            ]
+        );
+    }
+
+    #[test]
+    fn test_side_channel_read() {
+        let layout = SideChannel::new(
+            [1u16, 1, 1],
+            [0, SideChannel::PARITY_ERROR, SideChannel::SYNC_ERROR],
+        );
+        assert!(layout.read(0).unwrap().is_valid());
+        assert!(layout.read(0).unwrap().is_data());
+        assert!(!layout.read(1).unwrap().check_parity());
+        assert!(!layout.read(2).unwrap().check_sync());
+    }
+
+    #[test]
+    fn test_side_channel_capacity() {
+        let mut layout = SideChannel::new([0u16; 3], [0u8; 2]);
+        assert_eq!(layout.capacity(), 2);
+        assert_eq!(layout.read(2), Err(Error::OutOfBounds));
+        assert_eq!(
+            layout.write(2, &packet(DataWord::from(1u16).into())),
+            Err(Error::OutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_side_channel_round_trip() {
+        let mut layout = SideChannel::new([0u16; 3], [0u8; 3]);
+        message().write_to(&mut layout).unwrap();
+        let result = Message::<3>::read_from::<CommandWord, _>(&layout).unwrap();
+        assert_eq!(result, message());
+    }
+
+    #[test]
+    fn test_layout_status_message() {
+        let status = StatusWord::new()
+            .with_address(crate::flags::Address::Value(5))
+            .with_calculated_parity();
+        let message = Message::<4>::new()
+            .with_status(status)
+            .with_data(DataWord::from(7u16));
+
+        let mut layout = Aligned::new([0u32; 2]);
+        message.write_to(&mut layout).unwrap();
+        let result = Message::<4>::read_from::<StatusWord, _>(&layout).unwrap();
+        assert_eq!(result, message);
+    }
+
+    #[test]
+    fn test_layout_too_small() {
+        let mut layout = Aligned::new([0u32; 2]);
+        assert_eq!(message().write_to(&mut layout), Err(Error::OutOfBounds));
+
+        // a command for two data words with only one given
+        let header = packet(*message().words().next().unwrap());
+        layout.write(0, &header).unwrap();
+        assert_eq!(
+            Message::<3>::read_from::<CommandWord, _>(&layout),
+            Err(Error::InvalidMessage)
+        );
+    }
+}
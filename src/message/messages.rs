@@ -1,6 +1,6 @@
 use crate::word::WordType;
 use crate::word::{CommandWord, DataWord, StatusWord};
-use crate::{errors::*, Header, Packet, Word};
+use crate::{errors::*, Header, Layout, LayoutMut, Packed, Packet, Violation, Word};
 
 /// Default value for word array
 const ARRAY_NONE: WordType = WordType::None;
@@ -16,6 +16,8 @@ const ARRAY_NONE: WordType = WordType::None;
 ///
 /// Messages do not validate larger messaging patterns that
 /// require context about previous messages or terminal type.
+/// The full rules of the standard for a single message can
+/// be checked with [violations][Self::violations].
 ///
 /// ## Example
 ///
@@ -267,7 +269,24 @@ impl<const WORDS: usize> Message<WORDS> {
     /// * `index` - An index
     ///
     pub fn at(&self, index: usize) -> Option<&DataWord> {
-        if let Some(WordType::Data(w)) = &self.words.get(index + 1) {
+        if let Some(WordType::Data(w)) = index.checked_add(1).and_then(|i| self.words.get(i)) {
+            Some(w)
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable data word from the message by index
+    ///
+    /// An index of 0 will return the first *data word*, not
+    /// the leading command or status word.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - An index
+    ///
+    pub fn at_mut(&mut self, index: usize) -> Option<&mut DataWord> {
+        if let Some(WordType::Data(w)) = index.checked_add(1).and_then(|i| self.words.get_mut(i)) {
             Some(w)
         } else {
             None
@@ -287,13 +306,21 @@ impl<const WORDS: usize> Message<WORDS> {
     where
         T: TryFrom<&'a DataWord>,
     {
-        if let Some(WordType::Data(w)) = &self.words.get(index + 1) {
+        if let Some(WordType::Data(w)) = index.checked_add(1).and_then(|i| self.words.get(i)) {
             T::try_from(w).ok()
         } else {
             None
         }
     }
 
+    /// Get an iterator over the words of the message
+    ///
+    /// Empty slots are skipped, so the iterator yields
+    /// [length][Self::length] words.
+    pub fn words(&self) -> impl Iterator<Item = &WordType> + '_ {
+        self.words.iter().filter(|w| w.is_some())
+    }
+
     /// Add a word to the message
     ///
     /// # Arguments
@@ -404,7 +431,9 @@ impl<const WORDS: usize> Message<WORDS> {
     /// * `data` - Words to add
     ///
     pub fn set_string(&mut self, data: &str) {
-        self.words[1..].fill(WordType::None);
+        if let Some(words) = self.words.get_mut(1..) {
+            words.fill(WordType::None);
+        }
         self.add_string(data);
     }
 
@@ -424,7 +453,9 @@ impl<const WORDS: usize> Message<WORDS> {
     /// * `data` - Words to add
     ///
     pub fn set_bytes(&mut self, data: &[u8]) {
-        self.words[1..].fill(WordType::None);
+        if let Some(words) = self.words.get_mut(1..) {
+            words.fill(WordType::None);
+        }
         self.add_bytes(data);
     }
 
@@ -472,7 +503,7 @@ impl<const WORDS: usize> Message<WORDS> {
     ///
     /// * If and error was generated during construction
     /// * If there are multiple header words (command or status)
-    /// * If any word has a bad parity
+    /// * If any word has a bad parity (empty slots are ignored)
     /// * If the first word is a data word
     ///
     pub fn validate(&self) -> Result<()> {
@@ -482,12 +513,12 @@ impl<const WORDS: usize> Message<WORDS> {
         }
 
         // fail if there are multiple header words
-        if self.count() != (self.length() - 1) {
+        if self.count() + 1 != self.length() {
             return Err(Error::InvalidWord);
         }
 
         // fail if any word has a bad parity bit
-        if self.words.iter().any(|w| !w.check_parity()) {
+        if self.words().any(|w| !w.check_parity()) {
             return Err(Error::InvalidWord);
         }
 
@@ -506,6 +537,25 @@ impl<const WORDS: usize> Message<WORDS> {
         Ok(())
     }
 
+    /// Get an iterator over the violations of the standard in the message
+    ///
+    /// Checks the rules of MIL-STD-1553B that apply to a single
+    /// message, in order:
+    ///
+    /// * The first word has command or status sync
+    /// * Every later word has data sync
+    /// * There are no gaps between words
+    /// * Transmit commands aren't sent to the broadcast address
+    /// * Mode codes are defined for the transmit/receive bit
+    /// * The number of data words matches the command word
+    /// * The reserved bits of status words are cleared
+    ///
+    /// Parity and construction errors are reported by
+    /// [validate][Self::validate] instead.
+    pub fn violations(&self) -> impl Iterator<Item = Violation> + '_ {
+        Violation::find(&self.words)
+    }
+
     /// Clear all words from the message
     pub fn clear(&mut self) {
         self.words = [ARRAY_NONE; WORDS];
@@ -536,81 +586,135 @@ impl<const WORDS: usize> Message<WORDS> {
 
     /// Read bytes as a message
     ///
+    /// The bytes are read as packed 20-bit words. See
+    /// [read_from][Self::read_from] for other layouts.
+    ///
     /// # Arguments
     ///
     /// * `data` - A slice of bytes to read
     ///
     pub fn read<T: Word + Header>(data: &[u8]) -> Result<Self> {
-        let min = 3;
+        Self::read_from::<T, _>(&Packed::new(data))
+    }
 
-        // need at least three bytes to parse
-        if data.len() < min {
+    /// Read a message from a wire layout
+    ///
+    /// The first word of the layout is read as the header word,
+    /// and the word count of a command word determines how many
+    /// data words are read. For status words, every word in the
+    /// layout is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The layout to read
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use mil_std_1553b::*;
+    /// # fn main() -> Result<()> {
+    ///     let words = [0x0008_0821u32, 0x0002_1234];
+    ///     let message = Message::<2>::read_from::<CommandWord, _>(&Aligned::new(&words))?;
+    ///
+    ///     assert!(message.is_command());
+    ///     assert_eq!(message.at(0).map(|w| w.as_value()), Some(0x1234));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_from<T: Word + Header, L: Layout>(layout: &L) -> Result<Self> {
+        // get the number of whole words given
+        let capacity = layout.capacity();
+
+        // need at least one word to parse
+        if capacity == 0 {
             return Err(Error::InvalidMessage);
         }
 
-        // estimate word count from given data
-        let estimate = ((data.len() * 8) / 20).saturating_sub(1);
-
         // parse the specified header word
-        let word = Packet::read(data, 0)?.as_word::<T>()?;
+        let word = layout.read(0)?.as_word::<T>()?;
 
         // get the number of expected words or an
         // estimate if the header is a status word.
-        let count = word.count().unwrap_or(estimate);
-
-        // the expected number of bytes to parse
-        let expected = (((count + 1) * 20) + 7) / 8;
+        let count = word.count().unwrap_or(capacity - 1);
 
         // return error if data is too small
-        if data.len() < expected {
+        if capacity < count + 1 {
             return Err(Error::InvalidMessage);
         }
 
         // create a new message with the header word
         let mut message = Self::new().with_word(word);
 
-        let start = 1; // skip the service word
-        let end = count + 1; // adjust for service word
-
-        for index in start..end {
-            let b = index * 20; // offset in bits
-            let i = b / 8; // byte offset (whole)
-            let o = b % 8; // byte offset (fraction)
-            let bytes = &data[i..];
-
-            // use a packet to parse the bytes and convert to a word
-            message.add_data(Packet::read(bytes, o)?.try_into()?);
+        for index in 1..=count {
+            // convert each packet to a data word
+            message.add_data(layout.read(index)?.try_into()?);
         }
 
-        Ok(message)
+        // fail if the words didn't fit in the message
+        match message.error {
+            Some(e) => Err(e),
+            None => Ok(message),
+        }
     }
 
     /// Write the message to a byte array
     ///
+    /// The bytes are written as packed 20-bit words. See
+    /// [write_to][Self::write_to] for other layouts.
+    ///
     /// # Arguments
     ///
     /// * `data` - A slice of bytes to write
     ///
     pub fn write(&self, data: &mut [u8]) -> Result<()> {
-        let count = ((self.length() * 20) + 7) / 8;
+        self.write_to(&mut Packed::new(data))
+    }
 
-        if data.len() < count {
+    /// Write the message to a wire layout
+    ///
+    /// Fails without writing if the layout can't hold every
+    /// word of the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - The layout to write
+    ///
+    pub fn write_to<L: LayoutMut>(&self, layout: &mut L) -> Result<()> {
+        if layout.capacity() < self.length() {
             return Err(Error::OutOfBounds);
         }
 
         for (index, word) in self.words.iter().take_while(|w| w.is_some()).enumerate() {
-            let b = index * 20;
-            let i = b / 8;
-            let o = b % 8;
-
-            let packet = Packet::try_from(word)?;
-            packet.write(&mut data[i..], o)?;
+            layout.write(index, &Packet::try_from(word)?)?;
         }
 
         Ok(())
     }
 }
 
+impl<const WORDS: usize> core::fmt::Display for Message<WORDS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "Message with {} of {} words", self.length(), self.size())?;
+            match self.validate() {
+                Ok(_) => write!(f, " (valid)")?,
+                Err(e) => write!(f, " (invalid: {})", e)?,
+            }
+            for word in self.words() {
+                write!(f, "\n  {}", word)?;
+            }
+        } else {
+            for (i, word) in self.words().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", word)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Default for Message {
     fn default() -> Self {
         Self::new()
@@ -1088,6 +1192,29 @@ mod tests {
         assert!(!message.is_status());
     }
 
+    #[test]
+    fn test_message_with_command_not_full() {
+        let message = Message::<4>::new()
+            .with_command(0b0000000000000001)
+            .with_data(0b0000000000000001)
+            .build()
+            .unwrap();
+        assert!(message.is_valid());
+        assert!(message.is_full());
+        assert_eq!(message.length(), 2);
+        assert_eq!(message.size(), 4);
+    }
+
+    #[test]
+    fn test_message_with_command_fail_missing_data() {
+        // the command word expects two data words
+        let message = Message::<4>::new()
+            .with_command(0b0000000000000010)
+            .with_data(0b0000000000000001)
+            .build();
+        assert_eq!(message, Err(Error::InvalidMessage));
+    }
+
     #[test]
     fn test_message_with_command_fail_duplicate_header() {
         // adding two header words to the message
@@ -1142,6 +1269,18 @@ mod tests {
         assert!(!message.is_command());
     }
 
+    #[test]
+    fn test_message_with_status_not_full() {
+        let message = Message::<4>::new()
+            .with_status(0b0000000000000001)
+            .with_data(0b0000000000000001)
+            .build()
+            .unwrap();
+        assert!(message.is_valid());
+        assert!(!message.is_full());
+        assert_eq!(message.length(), 2);
+    }
+
     #[test]
     fn test_message_with_status_fail_duplicate_header() {
         // adding two header words to the message
@@ -1168,6 +1307,26 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_message_set_string_empty() {
+        let mut message = Message::<0>::new().with_string("TEST");
+        assert!(!message.is_valid());
+
+        message.set_bytes(&[1, 2]);
+        assert!(message.is_empty());
+    }
+
+    #[test]
+    fn test_message_at_index_overflow() {
+        let mut message = Message::<2>::new()
+            .with_status(0b0000000000000001)
+            .with_data(0b0000000000000001);
+
+        assert!(message.at(usize::MAX).is_none());
+        assert!(message.at_mut(usize::MAX).is_none());
+        assert!(message.get::<&str>(usize::MAX).is_none());
+    }
+
     #[test]
     fn test_message_add_status_fail_parity() {
         let word = StatusWord::new()
@@ -1304,6 +1463,16 @@ mod tests {
         assert_eq!(message.length(), 1);
     }
 
+    #[test]
+    fn test_message_read_status_fail_message_too_small() {
+        let input = [0b10000011, 0b00001100, 0b00100010, 0b11010000, 0b11010010];
+        let result = Message::<1>::read_status(&input);
+        assert_eq!(result, Err(Error::OutOfBounds));
+
+        let result = Message::<0>::read_command(&input);
+        assert_eq!(result, Err(Error::OutOfBounds));
+    }
+
     #[test]
     fn test_message_read_status_fail_buffer_too_small() {
         let input = [0b10000011, 0b00001100];
@@ -1390,6 +1559,35 @@ mod tests {
         assert_eq!(word4, None);
     }
 
+    #[test]
+    fn test_message_at_mut() {
+        let mut message = Message::<2>::new()
+            .with_command(0b0000000000000001)
+            .with_data(0b0000000000000001)
+            .build()
+            .unwrap();
+
+        if let Some(word) = message.at_mut(0) {
+            word.set_value(0b0000000000000011);
+        }
+
+        assert!(message.at_mut(1).is_none());
+        assert_eq!(message.at(0).map(Word::as_value), Some(0b11));
+        assert!(message.is_valid());
+    }
+
+    #[test]
+    fn test_message_words() {
+        let message = Message::<4>::new()
+            .with_command(0b0000000000000010)
+            .with_data(0b0000000000000001)
+            .with_data(0b0000000000000011);
+
+        assert_eq!(message.words().count(), 3);
+        assert!(message.words().next().unwrap().is_command());
+        assert!(message.words().skip(1).all(WordType::is_data));
+    }
+
     #[test]
     fn test_message_get() {
         let data1: u16 = 0b0000000000000101;
@@ -1431,4 +1629,34 @@ mod tests {
         assert!(message.is_empty());
         assert!(!message.is_full());
     }
+
+    #[test]
+    fn test_message_display() {
+        let message = Message::<3>::new()
+            .with_command(
+                CommandWord::new()
+                    .with_address(crate::Address::Value(12))
+                    .with_subaddress(crate::SubAddress::Value(5))
+                    .with_word_count(2)
+                    .with_calculated_parity(),
+            )
+            .with_data(0b0000000000000001)
+            .with_data(0b0000000000000011);
+
+        assert_eq!(std::format!("{}", message), "12-R-05-02, 0x0001, 0x0003");
+        assert_eq!(
+            std::format!("{:#}", message),
+            "Message with 3 of 3 words (valid)\n  12-R-05-02\n  0x0001\n  0x0003"
+        );
+    }
+
+    #[test]
+    fn test_message_display_invalid() {
+        let message = Message::<3>::new().with_data(0b0000000000000001);
+        assert_eq!(
+            std::format!("{:#}", message),
+            "Message with 1 of 3 words (invalid: invalid word)\n  0x0001"
+        );
+        assert_eq!(std::format!("{}", Message::<2>::new()), "");
+    }
 }
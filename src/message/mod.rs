@@ -1,7 +1,24 @@
 //! Messages constructed from words
 
+mod checks;
+mod cursors;
+mod faults;
+mod layouts;
 mod messages;
 mod packets;
+mod transfers;
+mod views;
+mod violations;
 
+/// The number of bits in a packed word
+const PACKET_BITS: usize = 20;
+
+pub use checks::InvalidMasks;
+pub use cursors::{PacketReader, PacketWriter};
+pub use faults::{Fault, Injection};
+pub use layouts::{Aligned, Layout, LayoutMut, Packed, SideChannel};
 pub use messages::Message;
 pub use packets::Packet;
+pub use transfers::Transfer;
+pub use views::MessageRef;
+pub use violations::Violation;
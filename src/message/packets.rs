@@ -128,9 +128,13 @@ impl Packet {
     ///
     #[allow(clippy::if_same_then_else)]
     pub fn write(&self, data: &mut [u8], offset: usize) -> Result<()> {
+        // if the offset won't fit in a u32
+        if offset > 12 {
+            return Err(Error::OutOfBounds);
+        }
         // if the offset requires 4 bytes and
         // they weren't given
-        if offset > 4 && data.len() < 4 {
+        else if offset > 4 && data.len() < 4 {
             return Err(Error::OutOfBounds);
         }
         // if the offset requires 3 bytes and
@@ -141,7 +145,7 @@ impl Packet {
 
         let mut v: u32 = 0;
         let mut m: u32 = 0;
-        let o = offset.clamp(0, 12);
+        let o = offset;
 
         v |= ((self.sync & 0b00000111) as u32) << 29;
         v |= (self.body[0] as u32) << 21;
@@ -263,6 +267,38 @@ impl TryFrom<Packet> for DataWord {
     }
 }
 
+impl core::fmt::Display for Packet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let body = u16::from_be_bytes(self.body);
+
+        if f.alternate() {
+            writeln!(f, "{:03b} {:#06X} {}", self.sync, body, self.parity)?;
+            writeln!(
+                f,
+                "  sync:   {:#05b} ({})",
+                self.sync,
+                match self.sync {
+                    Self::DATA_SYNC => "data",
+                    Self::SERV_SYNC => "service",
+                    _ => "invalid",
+                }
+            )?;
+            writeln!(f, "  body:   {:#018b}", body)?;
+            write!(
+                f,
+                "  parity: {} ({})",
+                self.parity,
+                match self.check_parity() {
+                    true => "valid",
+                    false => "invalid",
+                }
+            )
+        } else {
+            write!(f, "{:03b} {:#06X} {}", self.sync, body, self.parity)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,6 +374,16 @@ mod tests {
         test_packet_write_fail(0, &[0b11110000, 0b00000000, 0b00110000], &mut [0, 0], false);
     }
 
+    #[test]
+    fn test_packet_write_fail_2() {
+        test_packet_write_fail(
+            13,
+            &[0b11110000, 0b00000000, 0b00110000],
+            &mut [0, 0, 0, 0],
+            false,
+        );
+    }
+
     fn test_packet_read_success(offset: usize, input: &[u8], sync: u8, body: [u8; 2], parity: u8) {
         let packet = Packet::read(input, offset).unwrap();
         assert_eq!(packet.sync, sync);
@@ -745,7 +791,7 @@ mod tests {
     fn test_packet_clone() {
         let word = WordType::Command(CommandWord::new());
         let packet1 = Packet::try_from(word).unwrap();
-        let packet2 = packet1.clone();
+        let packet2 = Clone::clone(&packet1);
         assert_eq!(packet1, packet2);
     }
 
@@ -915,4 +961,15 @@ mod tests {
     }
 
     // ----------------------------------------------------------
+
+    #[test]
+    fn test_packet_display() {
+        let packet = Packet::new(Packet::SERV_SYNC, [0b01000000, 0b00100000], 1);
+        assert_eq!(std::format!("{}", packet), "100 0x4020 1");
+
+        let result = std::format!("{:#}", packet);
+        assert!(result.starts_with("100 0x4020 1\n"));
+        assert!(result.contains("  sync:   0b100 (service)\n"));
+        assert!(result.ends_with("  parity: 1 (valid)"));
+    }
 }
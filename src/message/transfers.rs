@@ -0,0 +1,278 @@
+use crate::errors::{Error, Result};
+use crate::flags::{Address, ModeCode, SubAddress, TransmitReceive};
+use crate::word::CommandWord;
+use crate::Word;
+
+/// A transfer format initiated by the bus controller
+///
+/// Every transfer on the bus begins with one command word, except
+/// for terminal-to-terminal transfers which begin with a receive
+/// command to the receiving terminal followed by a transmit command
+/// to the transmitting terminal. Broadcast transfers use the same
+/// formats with the broadcast address.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> core::result::Result<(), ParseError> {
+///     let transfer: Transfer = "01-R-02-04, 05-T-03-04".parse()?;
+///
+///     assert!(transfer.is_rt_to_rt());
+///     assert_eq!(transfer.word_count(), 4);
+///     assert_eq!(transfer.to_string(), "01-R-02-04, 05-T-03-04");
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transfer {
+    /// The bus controller sends data words to a terminal
+    BcToRt(CommandWord),
+
+    /// A terminal sends data words to the bus controller
+    RtToBc(CommandWord),
+
+    /// A terminal sends data words to another terminal
+    ///
+    /// Contains the receive command followed by the transmit command.
+    RtToRt(CommandWord, CommandWord),
+
+    /// The bus controller sends a mode code command
+    ModeCode(CommandWord),
+}
+
+impl Transfer {
+    /// Create a transfer from a single command word
+    ///
+    /// Mode code commands create [ModeCode](Self::ModeCode) transfers,
+    /// otherwise the transmit/receive flag decides the direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word that starts the transfer
+    ///
+    pub fn new(command: CommandWord) -> Self {
+        if command.is_mode_code() {
+            Self::ModeCode(command)
+        } else if command.is_transmit() {
+            Self::RtToBc(command)
+        } else {
+            Self::BcToRt(command)
+        }
+    }
+
+    /// Create a terminal-to-terminal transfer
+    ///
+    /// Fails if the first command isn't a receive command, the second
+    /// command isn't a transmit command, either command is a mode code,
+    /// the word counts differ, both commands address the same terminal,
+    /// or the transmitting terminal is the broadcast address.
+    ///
+    /// # Arguments
+    ///
+    /// * `receive` - The receive command for the receiving terminal
+    /// * `transmit` - The transmit command for the transmitting terminal
+    ///
+    pub fn rt_to_rt(receive: CommandWord, transmit: CommandWord) -> Result<Self> {
+        if !receive.is_receive()
+            || !transmit.is_transmit()
+            || receive.is_mode_code()
+            || transmit.is_mode_code()
+            || receive.word_count() != transmit.word_count()
+            || receive.address() == transmit.address()
+            || transmit.address().is_broadcast()
+        {
+            return Err(Error::InvalidMessage);
+        }
+        Ok(Self::RtToRt(receive, transmit))
+    }
+
+    /// Create a mode code transfer to a terminal
+    ///
+    /// The transmit/receive bit is set to the direction that
+    /// the standard defines for the mode code, and reserved
+    /// mode codes are sent as transmit commands.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal (or broadcast)
+    /// * `code` - The mode code to send
+    ///
+    pub fn mode_code(address: Address, code: ModeCode) -> Self {
+        Self::ModeCode(
+            CommandWord::new()
+                .with_address(address)
+                .with_subaddress(SubAddress::ModeCode(0))
+                .with_transmit_receive(code.direction().unwrap_or(TransmitReceive::Transmit))
+                .with_mode_code(code)
+                .with_calculated_parity(),
+        )
+    }
+
+    /// Get the first command word of the transfer
+    pub fn command(&self) -> &CommandWord {
+        match self {
+            Self::BcToRt(c) => c,
+            Self::RtToBc(c) => c,
+            Self::RtToRt(c, _) => c,
+            Self::ModeCode(c) => c,
+        }
+    }
+
+    /// Get the transmit command of a terminal-to-terminal transfer
+    pub fn transmit_command(&self) -> Option<&CommandWord> {
+        match self {
+            Self::RtToRt(_, c) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Get the number of data words in the transfer
+    ///
+    /// Mode code transfers have a single data word if the
+    /// mode code has an associated data word.
+    pub fn word_count(&self) -> usize {
+        match self {
+            Self::ModeCode(c) => c.mode_code().data_words(),
+            _ => self.command().count(),
+        }
+    }
+
+    /// Check if the transfer is sent to the broadcast address
+    #[must_use = "Returned value is not used"]
+    pub fn is_broadcast(&self) -> bool {
+        self.command().address().is_broadcast()
+    }
+
+    /// Check if the enum is the 'BcToRt' variant
+    #[must_use = "Returned value is not used"]
+    pub const fn is_bc_to_rt(&self) -> bool {
+        matches!(self, Self::BcToRt(_))
+    }
+
+    /// Check if the enum is the 'RtToBc' variant
+    #[must_use = "Returned value is not used"]
+    pub const fn is_rt_to_bc(&self) -> bool {
+        matches!(self, Self::RtToBc(_))
+    }
+
+    /// Check if the enum is the 'RtToRt' variant
+    #[must_use = "Returned value is not used"]
+    pub const fn is_rt_to_rt(&self) -> bool {
+        matches!(self, Self::RtToRt(_, _))
+    }
+
+    /// Check if the enum is the 'ModeCode' variant
+    #[must_use = "Returned value is not used"]
+    pub const fn is_mode_code(&self) -> bool {
+        matches!(self, Self::ModeCode(_))
+    }
+}
+
+impl From<CommandWord> for Transfer {
+    fn from(value: CommandWord) -> Self {
+        Self::new(value)
+    }
+}
+
+impl core::fmt::Display for Transfer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::RtToRt(r, t) => write!(f, "{}, {}", r, t),
+            _ => write!(f, "{}", self.command()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::command;
+
+    #[test]
+    fn test_transfer_clone() {
+        let item1 = Transfer::new(command("01-R-03-02"));
+        let item2 = Clone::clone(&item1);
+        assert_eq!(item1, item2);
+    }
+
+    #[test]
+    fn test_transfer_mode_code() {
+        let transfer = Transfer::mode_code(Address::Value(4), ModeCode::SynchronizeWithDataWord);
+        let command = transfer.command();
+        assert!(transfer.is_mode_code());
+        assert!(command.check_parity());
+        assert!(command.is_receive());
+        assert_eq!(command.address(), Address::Value(4));
+        assert_eq!(command.mode_code(), ModeCode::SynchronizeWithDataWord);
+        assert_eq!(transfer.word_count(), 1);
+
+        let transfer = Transfer::mode_code(Address::Value(4), ModeCode::TransmitVectorWord);
+        assert!(transfer.command().is_transmit());
+    }
+
+    #[test]
+    fn test_transfer_new() {
+        let transfer = Transfer::new(command("01-R-03-02"));
+        assert!(transfer.is_bc_to_rt());
+        assert_eq!(transfer.word_count(), 2);
+
+        let transfer = Transfer::new(command("01-T-03-02"));
+        assert!(transfer.is_rt_to_bc());
+        assert_eq!(transfer.transmit_command(), None);
+
+        let transfer = Transfer::from(
+            CommandWord::new()
+                .with_subaddress(SubAddress::ModeCode(31))
+                .with_mode_code(ModeCode::TransmitBITWord),
+        );
+        assert!(transfer.is_mode_code());
+        assert_eq!(transfer.word_count(), 1);
+    }
+
+    #[test]
+    fn test_transfer_rt_to_rt() {
+        let receive = command("01-R-03-04");
+        let transmit = command("05-T-03-04");
+        let transfer = Transfer::rt_to_rt(receive, transmit).unwrap();
+
+        assert!(transfer.is_rt_to_rt());
+        assert_eq!(transfer.command(), &receive);
+        assert_eq!(transfer.transmit_command(), Some(&transmit));
+        assert_eq!(transfer.word_count(), 4);
+    }
+
+    #[test]
+    fn test_transfer_rt_to_rt_fail() {
+        let receive = command("01-R-03-04");
+        let transmit = command("05-T-03-04");
+
+        assert!(Transfer::rt_to_rt(transmit, receive).is_err());
+        assert!(Transfer::rt_to_rt(receive, transmit.with_word_count(3)).is_err());
+        assert!(Transfer::rt_to_rt(receive, command("01-T-03-04")).is_err());
+        assert!(Transfer::rt_to_rt(receive, command("31-T-03-04")).is_err());
+    }
+
+    #[test]
+    fn test_transfer_broadcast() {
+        let transfer = Transfer::new(command("31-R-03-02"));
+        assert!(transfer.is_broadcast());
+
+        let receive = command("31-R-03-04");
+        let transmit = command("05-T-03-04");
+        let transfer = Transfer::rt_to_rt(receive, transmit).unwrap();
+        assert!(transfer.is_broadcast());
+    }
+
+    #[test]
+    fn test_transfer_display() {
+        let transfer = Transfer::new(command("01-R-03-02"));
+        assert_eq!(std::format!("{}", transfer), "01-R-03-02");
+
+        let receive = command("01-R-03-04");
+        let transmit = command("05-T-03-04");
+        let transfer = Transfer::rt_to_rt(receive, transmit).unwrap();
+        assert_eq!(std::format!("{}", transfer), "01-R-03-04, 05-T-03-04");
+    }
+}
@@ -0,0 +1,406 @@
+use super::PACKET_BITS;
+use crate::word::WordType;
+use crate::word::{CommandWord, DataWord, StatusWord};
+use crate::{errors::*, Header, Layout, Message, Packed, Word};
+
+/// A message borrowed from a packed byte buffer
+///
+/// Unlike [Message], a MessageRef doesn't copy the words of the
+/// message out of the buffer. Only the header word is decoded
+/// when the view is created, and data words are decoded each
+/// time they are accessed. This makes it cheap to skim large
+/// numbers of messages, at the cost of decoding a word again
+/// every time it's read.
+///
+/// The buffer has the same layout as the one parsed by
+/// [Message::read_command]: a series of 20-bit words, with the
+/// header word aligned to the beginning of the slice. Data words
+/// are not checked until they are accessed or the view is
+/// [validated][Self::validate].
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let data = [0b10000011, 0b00001100, 0b00100010, 0b11010000, 0b11010010];
+///     let message = MessageRef::read_command(&data)?;
+///
+///     assert!(message.is_command());
+///     assert_eq!(message.count(), 1);
+///     assert_eq!(message.at(0).as_ref(), Message::<2>::read_command(&data)?.at(0));
+///     assert!(message.is_valid());
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct MessageRef<'a> {
+    /// The bytes of the message
+    data: &'a [u8],
+
+    /// The decoded header word
+    header: WordType,
+
+    /// The number of data words in the message
+    count: usize,
+}
+
+impl<'a> MessageRef<'a> {
+    /// Borrow a slice of bytes as a command message
+    ///
+    /// The word count of the command word determines how many
+    /// data words are in the message, and any bytes after the
+    /// last data word are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of bytes to borrow
+    ///
+    pub fn read_command(data: &'a [u8]) -> Result<Self> {
+        Self::read::<CommandWord>(data)
+    }
+
+    /// Borrow a slice of bytes as a status message
+    ///
+    /// Because status words do not have a word count field,
+    /// every whole word after the status word is treated as a
+    /// data word. Slice the input data to avoid including any
+    /// unwanted words.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of bytes to borrow
+    ///
+    pub fn read_status(data: &'a [u8]) -> Result<Self> {
+        Self::read::<StatusWord>(data)
+    }
+
+    /// Borrow a slice of bytes as a message
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of bytes to borrow
+    ///
+    pub fn read<T: Word + Header>(data: &'a [u8]) -> Result<Self> {
+        let layout = Packed::new(data);
+
+        // need at least one whole word to parse
+        if layout.capacity() == 0 {
+            return Err(Error::InvalidMessage);
+        }
+
+        // estimate word count from given data
+        let estimate = layout.capacity() - 1;
+
+        // parse the specified header word
+        let word = layout.read(0)?.as_word::<T>()?;
+        let count = word.count().unwrap_or(estimate);
+
+        // return error if data is too small
+        if count >= layout.capacity() {
+            return Err(Error::InvalidMessage);
+        }
+
+        // the expected number of bytes to borrow
+        let expected = ((count + 1) * PACKET_BITS).div_ceil(8);
+
+        Ok(Self {
+            data: &data[..expected],
+            header: word.into(),
+            count,
+        })
+    }
+
+    /// Get the command word from the message
+    ///
+    /// Returns `None` if this message doesn't
+    /// have a command word.
+    pub fn command(&self) -> Option<&CommandWord> {
+        if let WordType::Command(w) = &self.header {
+            Some(w)
+        } else {
+            None
+        }
+    }
+
+    /// Get the status word from the message
+    ///
+    /// Returns `None` if this message doesn't
+    /// have a status word.
+    pub fn status(&self) -> Option<&StatusWord> {
+        if let WordType::Status(w) = &self.header {
+            Some(w)
+        } else {
+            None
+        }
+    }
+
+    /// Decode a data word from the message by index
+    ///
+    /// An index of 0 will return the first *data word*, not
+    /// the leading command or status word. Returns `None` if
+    /// the index is out of range or the word doesn't decode
+    /// as a data word with good parity.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - An index
+    ///
+    pub fn at(&self, index: usize) -> Option<DataWord> {
+        self.decode(index).ok()
+    }
+
+    /// Decode a custom data word from the message by index
+    ///
+    /// An index of 0 will return the first *data word*, not
+    /// the leading command or status word.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - An index
+    ///
+    pub fn get<T>(&self, index: usize) -> Option<T>
+    where
+        T: for<'b> TryFrom<&'b DataWord>,
+    {
+        self.at(index).and_then(|w| T::try_from(&w).ok())
+    }
+
+    /// Get an iterator that decodes the data words of the message
+    ///
+    /// Each item is the result of decoding a data word, so that
+    /// bad words can be found without stopping the iteration.
+    pub fn data(&self) -> impl Iterator<Item = Result<DataWord>> + '_ {
+        (0..self.count).map(|i| self.decode(i))
+    }
+
+    /// Check if message starts with a command word
+    #[must_use = "Returned value is not used"]
+    pub fn is_command(&self) -> bool {
+        self.command().is_some()
+    }
+
+    /// Check if message starts with a status word
+    #[must_use = "Returned value is not used"]
+    pub fn is_status(&self) -> bool {
+        self.status().is_some()
+    }
+
+    /// Check if the message is valid
+    #[must_use = "Returned value is not used"]
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Method to validate message
+    ///
+    /// Decodes every word of the message, returning an error
+    /// if the header word has bad parity, or any data word has
+    /// bad parity or the wrong sync.
+    pub fn validate(&self) -> Result<()> {
+        if !self.header.check_parity() {
+            return Err(Error::InvalidWord);
+        }
+        self.data().try_for_each(|w| w.map(|_| ()))
+    }
+
+    /// Get the number of data words
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the number of words, including the header word
+    pub fn length(&self) -> usize {
+        self.count + 1
+    }
+
+    /// Get the borrowed bytes of the message
+    ///
+    /// Bytes after the last data word are not included.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Copy the message into an owned [Message]
+    ///
+    /// Fails if the message doesn't fit, or any data word
+    /// fails to decode.
+    pub fn to_message<const WORDS: usize>(&self) -> Result<Message<WORDS>> {
+        let mut message = Message::new();
+        match self.header {
+            WordType::Command(w) => message.add_command(w),
+            WordType::Status(w) => message.add_status(w),
+            _ => return Err(Error::InvalidMessage),
+        }
+        for word in self.data() {
+            message.add_data(word?);
+        }
+        message.validate().map(|_| message)
+    }
+
+    /// Decode the data word at an index
+    fn decode(&self, index: usize) -> Result<DataWord> {
+        if index >= self.count {
+            return Err(Error::OutOfBounds);
+        }
+        // skip the header word
+        Packed::new(self.data).read(index + 1)?.try_into()
+    }
+}
+
+impl core::fmt::Display for MessageRef<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.header)?;
+        for word in self.data() {
+            match word {
+                Ok(w) => write!(f, ", {}", w)?,
+                Err(e) => write!(f, ", ({})", e)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, const WORDS: usize> TryFrom<MessageRef<'a>> for Message<WORDS> {
+    type Error = Error;
+
+    fn try_from(value: MessageRef<'a>) -> Result<Self> {
+        value.to_message()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::{Address, SubAddress};
+
+    fn command_message(count: u8) -> Message<4> {
+        let mut message = Message::new().with_command(
+            CommandWord::new()
+                .with_address(Address::Value(2))
+                .with_subaddress(SubAddress::Value(3))
+                .with_word_count(count)
+                .with_calculated_parity(),
+        );
+        for i in 0..count {
+            message.add_data(DataWord::from(u16::from(i) * 0x0101));
+        }
+        message
+    }
+
+    fn encode<const WORDS: usize>(message: &Message<WORDS>) -> [u8; 10] {
+        let mut data = [0; 10];
+        message.write(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_message_ref_read_command() {
+        let message = command_message(3);
+        let data = encode(&message);
+        let view = MessageRef::read_command(&data).unwrap();
+
+        assert!(view.is_command());
+        assert!(!view.is_status());
+        assert_eq!(view.command(), message.command());
+        assert_eq!(view.count(), 3);
+        assert_eq!(view.length(), 4);
+        assert_eq!(view.bytes().len(), 10);
+        assert_eq!(view.at(2).as_ref(), message.at(2));
+        assert_eq!(view.at(3), None);
+        assert!(view.is_valid());
+    }
+
+    #[test]
+    fn test_message_ref_read_status() {
+        let data = [0b10000011, 0b00001100, 0b00100010, 0b11010000, 0b11010010];
+        let view = MessageRef::read_status(&data).unwrap();
+        let message = Message::<2>::read_status(&data).unwrap();
+
+        assert!(view.is_status());
+        assert_eq!(view.status(), message.status());
+        assert_eq!(view.count(), 1);
+        assert_eq!(view.at(0).as_ref(), message.at(0));
+    }
+
+    #[test]
+    fn test_message_ref_ignores_trailing_bytes() {
+        let message = command_message(1);
+        let data = encode(&message);
+        let view = MessageRef::read_command(&data).unwrap();
+        assert_eq!(view.bytes().len(), 5);
+        assert_eq!(view.count(), 1);
+    }
+
+    #[test]
+    fn test_message_ref_read_short() {
+        let message = command_message(3);
+        let data = encode(&message);
+        assert_eq!(
+            MessageRef::read_command(&data[..2]),
+            Err(Error::InvalidMessage)
+        );
+        assert_eq!(
+            MessageRef::read_command(&data[..9]),
+            Err(Error::InvalidMessage)
+        );
+    }
+
+    #[test]
+    fn test_message_ref_get() {
+        let message = command_message(2);
+        let data = encode(&message);
+        let view = MessageRef::read_command(&data).unwrap();
+        assert_eq!(view.get::<u16>(1), Some(0x0101));
+        assert_eq!(view.get::<u16>(2), None);
+    }
+
+    #[test]
+    fn test_message_ref_lazy_validation() {
+        let message = command_message(2);
+        let mut data = encode(&message);
+
+        // flip a bit in the body of the second data word
+        data[7] ^= 0b0001_0000;
+
+        let view = MessageRef::read_command(&data).unwrap();
+        assert!(view.at(0).is_some());
+        assert_eq!(view.at(1), None);
+        assert_eq!(view.validate(), Err(Error::InvalidWord));
+        assert!(Message::<3>::read_command(&data).is_err());
+    }
+
+    #[test]
+    fn test_message_ref_bad_sync() {
+        let message = command_message(2);
+        let mut data = encode(&message);
+
+        // give the first data word service sync
+        data[2] ^= 0b0000_1110;
+
+        let view = MessageRef::read_command(&data).unwrap();
+        assert_eq!(view.at(0), None);
+        assert_eq!(view.validate(), Err(Error::InvalidPacket));
+        assert_eq!(view.data().filter(Result::is_ok).count(), 1);
+    }
+
+    #[test]
+    fn test_message_ref_to_message() {
+        let message = command_message(3);
+        let data = encode(&message);
+        let view = MessageRef::read_command(&data).unwrap();
+
+        assert_eq!(view.to_message::<4>(), Ok(message.clone()));
+        assert_eq!(Message::<4>::try_from(view), Ok(message));
+        assert_eq!(view.to_message::<3>(), Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_message_ref_display() {
+        let message = command_message(2);
+        let data = encode(&message);
+        let view = MessageRef::read_command(&data).unwrap();
+        assert_eq!(std::format!("{}", view), std::format!("{}", message));
+    }
+}
@@ -0,0 +1,398 @@
+use crate::flags::{ModeCode, Reserved};
+use crate::word::{CommandWord, WordType};
+
+/// A violation of the MIL-STD-1553B rules for a valid message
+///
+/// Each variant names the section of the standard (Notice 2) that
+/// the message breaks, which can be retrieved with [section](Self::section).
+/// Violations are found with [Message::violations](crate::Message::violations).
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let message = Message::<3>::new()
+///         .with_command(CommandWord::new()
+///             .with_address(Address::Value(31))
+///             .with_subaddress(SubAddress::Value(2))
+///             .with_transmit_receive(TransmitReceive::Transmit)
+///             .with_word_count(2)
+///             .build()?
+///         );
+///
+///     let mut violations = message.violations();
+///
+///     assert_eq!(violations.next(), Some(Violation::BroadcastTransmit));
+///     assert_eq!(violations.next(), Some(Violation::WordCount { expected: 2, actual: 0 }));
+///     assert_eq!(violations.next(), None);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Violation {
+    /// The first word has data sync instead of command or status sync
+    HeaderSync,
+
+    /// A word after the first has command or status sync instead of data sync
+    DataSync {
+        /// The index of the word in the message
+        index: usize,
+    },
+
+    /// An empty slot is followed by more words
+    Gap {
+        /// The index of the empty slot
+        index: usize,
+    },
+
+    /// The number of data words doesn't match the command word
+    WordCount {
+        /// The number of data words given by the command word
+        expected: usize,
+
+        /// The number of data words in the message
+        actual: usize,
+    },
+
+    /// The mode code is reserved or undefined for the transmit/receive bit
+    UnusedModeCode(ModeCode),
+
+    /// The reserved bits of the status word are set
+    ReservedBits(Reserved),
+
+    /// A transmit command is sent to the broadcast address
+    ///
+    /// Mode codes that may be broadcast are allowed.
+    BroadcastTransmit,
+}
+
+impl Violation {
+    /// Get the section of MIL-STD-1553B that is violated
+    pub fn section(&self) -> &'static str {
+        match self {
+            Self::HeaderSync => "4.3.3.5.1.1",
+            Self::DataSync { .. } => "4.3.3.5.2.1",
+            Self::Gap { .. } => "4.3.3.6",
+            Self::WordCount { .. } => "4.3.3.5.1.5",
+            Self::UnusedModeCode(_) => "4.3.3.5.1.7",
+            Self::ReservedBits(_) => "4.3.3.5.3.6",
+            Self::BroadcastTransmit => "4.3.3.6.7",
+        }
+    }
+
+    /// Find the violations in a sequence of words
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - The words of a message, including empty slots
+    ///
+    pub(crate) fn find(words: &[WordType]) -> impl Iterator<Item = Self> + '_ {
+        let length = words
+            .iter()
+            .rposition(WordType::is_some)
+            .map_or(0, |i| i + 1);
+
+        let positions = words[..length]
+            .iter()
+            .enumerate()
+            .filter_map(|(index, word)| match (index, word) {
+                (_, WordType::None) => Some(Self::Gap { index }),
+                (0, WordType::Data(_)) => Some(Self::HeaderSync),
+                (0, _) | (_, WordType::Data(_)) => None,
+                _ => Some(Self::DataSync { index }),
+            });
+
+        let fields = match words.first() {
+            Some(WordType::Command(c)) => Self::command(c, words),
+            Some(WordType::Status(s)) => [
+                s.reserved()
+                    .is_value()
+                    .then_some(Self::ReservedBits(s.reserved())),
+                None,
+                None,
+            ],
+            _ => [None, None, None],
+        };
+
+        positions.chain(fields.into_iter().flatten())
+    }
+
+    /// Find the violations of a command word and its data words
+    fn command(command: &CommandWord, words: &[WordType]) -> [Option<Self>; 3] {
+        let actual = words.iter().filter(|w| w.is_data()).count();
+        let broadcast = command.address().is_broadcast();
+
+        let (expected, unused, allowed) = if command.is_mode_code() {
+            let code = command.mode_code();
+            let unused = code.direction() != Some(command.transmit_receive());
+            let expected = code.data_words();
+            (
+                expected,
+                unused.then_some(Self::UnusedModeCode(code)),
+                code.is_broadcast(),
+            )
+        } else {
+            (command.count(), None, command.is_receive())
+        };
+
+        [
+            (broadcast && !allowed).then_some(Self::BroadcastTransmit),
+            unused,
+            (expected != actual).then_some(Self::WordCount { expected, actual }),
+        ]
+    }
+}
+
+impl core::fmt::Display for Violation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::HeaderSync => write!(f, "first word is not a command or status word"),
+            Self::DataSync { index } => write!(f, "word {} is not a data word", index),
+            Self::Gap { index } => write!(f, "gap at word {}", index),
+            Self::WordCount { expected, actual } => {
+                write!(f, "expected {} data words but found {}", expected, actual)
+            }
+            Self::UnusedModeCode(code) => write!(f, "unused mode code {}", code),
+            Self::ReservedBits(bits) => {
+                write!(f, "reserved status bits set to {:03b}", u8::from(*bits))
+            }
+            Self::BroadcastTransmit => write!(f, "transmit command sent to broadcast address"),
+        }?;
+        write!(f, " (MIL-STD-1553B {})", self.section())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::{Address, TransmitReceive};
+    use crate::parse::command;
+    use crate::word::{DataWord, StatusWord};
+    use crate::{Message, Word};
+
+    fn data() -> DataWord {
+        DataWord::from_value(0x1234).with_calculated_parity()
+    }
+
+    #[test]
+    fn test_violations_none_command() {
+        let message = Message::<3>::new()
+            .with_command(command("05-R-02-02"))
+            .with_data(data())
+            .with_data(data());
+        assert_eq!(message.violations().next(), None);
+    }
+
+    #[test]
+    fn test_violations_none_status() {
+        let message = Message::<3>::new()
+            .with_status(StatusWord::new().with_address(Address::Value(5)))
+            .with_data(data());
+        assert_eq!(message.violations().next(), None);
+    }
+
+    #[test]
+    fn test_violations_none_empty() {
+        let message = Message::<3>::new();
+        assert_eq!(message.violations().next(), None);
+    }
+
+    #[test]
+    fn test_violations_header_sync() {
+        let message = Message::<3>::new().with_data(data()).with_data(data());
+        let mut violations = message.violations();
+        assert_eq!(violations.next(), Some(Violation::HeaderSync));
+        assert_eq!(violations.next(), None);
+    }
+
+    #[test]
+    fn test_violations_data_sync() {
+        let message = Message::<3>::new()
+            .with_command(command("05-R-02-01"))
+            .with_status(StatusWord::new());
+        let mut violations = message.violations();
+        assert_eq!(violations.next(), Some(Violation::DataSync { index: 1 }));
+        assert_eq!(
+            violations.next(),
+            Some(Violation::WordCount {
+                expected: 1,
+                actual: 0
+            })
+        );
+        assert_eq!(violations.next(), None);
+    }
+
+    #[test]
+    fn test_violations_gap() {
+        let words = [
+            WordType::from(command("05-R-02-02")),
+            WordType::None,
+            WordType::from(data()),
+            WordType::from(data()),
+            WordType::None,
+        ];
+        let mut violations = Violation::find(&words);
+        assert_eq!(violations.next(), Some(Violation::Gap { index: 1 }));
+        assert_eq!(violations.next(), None);
+    }
+
+    #[test]
+    fn test_violations_gap_first() {
+        let words = [WordType::None, WordType::from(data())];
+        let mut violations = Violation::find(&words);
+        assert_eq!(violations.next(), Some(Violation::Gap { index: 0 }));
+        assert_eq!(violations.next(), None);
+    }
+
+    #[test]
+    fn test_violations_word_count_long() {
+        let message = Message::<4>::new()
+            .with_command(command("05-R-02-01"))
+            .with_data(data())
+            .with_data(data());
+        let mut violations = message.violations();
+        assert_eq!(
+            violations.next(),
+            Some(Violation::WordCount {
+                expected: 1,
+                actual: 2
+            })
+        );
+        assert_eq!(violations.next(), None);
+    }
+
+    #[test]
+    fn test_violations_word_count_32() {
+        let message = Message::<1>::new().with_command(command("05-R-02-32"));
+        assert_eq!(
+            message.violations().next(),
+            Some(Violation::WordCount {
+                expected: 32,
+                actual: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_violations_mode_code_with_data() {
+        let message = Message::<2>::new()
+            .with_command(command("05-T-00-TransmitBITWord"))
+            .with_data(data());
+        assert_eq!(message.violations().next(), None);
+    }
+
+    #[test]
+    fn test_violations_mode_code_without_data() {
+        let message = Message::<2>::new()
+            .with_command(command("05-T-31-Synchronize"))
+            .with_data(data());
+        let mut violations = message.violations();
+        assert_eq!(
+            violations.next(),
+            Some(Violation::WordCount {
+                expected: 0,
+                actual: 1
+            })
+        );
+        assert_eq!(violations.next(), None);
+    }
+
+    #[test]
+    fn test_violations_mode_code_reserved() {
+        let message = Message::<1>::new().with_command(
+            command("05-T-00-Synchronize")
+                .with_mode_code(ModeCode::UnknownModeCode(0b01001))
+                .with_calculated_parity(),
+        );
+        let mut violations = message.violations();
+        assert_eq!(
+            violations.next(),
+            Some(Violation::UnusedModeCode(ModeCode::UnknownModeCode(
+                0b01001
+            )))
+        );
+        assert_eq!(violations.next(), None);
+    }
+
+    #[test]
+    fn test_violations_mode_code_direction() {
+        let message = Message::<2>::new()
+            .with_command(
+                command("05-T-00-TransmitVectorWord")
+                    .with_transmit_receive(TransmitReceive::Receive)
+                    .with_calculated_parity(),
+            )
+            .with_data(data());
+        let mut violations = message.violations();
+        assert_eq!(
+            violations.next(),
+            Some(Violation::UnusedModeCode(ModeCode::TransmitVectorWord))
+        );
+        assert_eq!(violations.next(), None);
+    }
+
+    #[test]
+    fn test_violations_reserved_bits() {
+        let message = Message::<1>::new().with_status(
+            StatusWord::new()
+                .with_reserved(Reserved::from(0b101u8))
+                .with_calculated_parity(),
+        );
+        let mut violations = message.violations();
+        assert_eq!(
+            violations.next(),
+            Some(Violation::ReservedBits(Reserved::from(0b101u8)))
+        );
+        assert_eq!(violations.next(), None);
+    }
+
+    #[test]
+    fn test_violations_broadcast_transmit() {
+        let message = Message::<1>::new().with_command(command("31-T-02-01"));
+        let mut violations = message.violations();
+        assert_eq!(violations.next(), Some(Violation::BroadcastTransmit));
+    }
+
+    #[test]
+    fn test_violations_broadcast_receive() {
+        let message = Message::<2>::new()
+            .with_command(command("31-R-02-01"))
+            .with_data(data());
+        assert_eq!(message.violations().next(), None);
+    }
+
+    #[test]
+    fn test_violations_broadcast_mode_code() {
+        let allowed = Message::<1>::new().with_command(command("31-T-00-Synchronize"));
+        let denied = Message::<1>::new().with_command(command("31-T-00-TransmitStatusWord"));
+        assert_eq!(allowed.violations().next(), None);
+        assert_eq!(
+            denied.violations().next(),
+            Some(Violation::BroadcastTransmit)
+        );
+    }
+
+    #[test]
+    fn test_violation_display() {
+        let violation = Violation::WordCount {
+            expected: 2,
+            actual: 1,
+        };
+        assert_eq!(
+            std::format!("{}", violation),
+            "expected 2 data words but found 1 (MIL-STD-1553B 4.3.3.5.1.5)"
+        );
+    }
+
+    #[test]
+    fn test_violation_section() {
+        assert_eq!(Violation::HeaderSync.section(), "4.3.3.5.1.1");
+        assert_eq!(
+            Violation::ReservedBits(Reserved::None).section(),
+            "4.3.3.5.3.6"
+        );
+    }
+}
@@ -0,0 +1,670 @@
+//! Parsers for the text notation of words and transfers
+//!
+//! Command words can be written in any of three forms:
+//!
+//! * Display form: `05-T-03-02` or `16-T-00-TransmitterShutdown`
+//! * Compact form: `12R05/4` or `16T00/TransmitterShutdown`
+//! * Verbose form: `RT12 R SA5 WC4` or `RT3 T SA31 MC TransmitBITWord`
+//!
+//! Status words can be written as `05 [SR BUSY]` or `RT5 SR BUSY`,
+//! and terminal-to-terminal transfers join the receive and transmit
+//! commands with a comma. Keywords, flags and mode code names are
+//! case-insensitive, and mode codes may also be given as numbers.
+
+use core::str::FromStr;
+
+use crate::errors::{ParseError, ParseErrorKind};
+use crate::flags::*;
+use crate::{CommandWord, MessageError, StatusWord, SubsystemError, TerminalError, Transfer, Word};
+
+/// The maximum value of the address and subaddress fields
+const MAX_ADDRESS: u32 = 0b11111;
+
+/// The status flags and their abbreviations
+const STATUS_FLAGS: &[&str] = &["ME", "INSTR", "SR", "BCR", "BUSY", "SSF", "DBCA", "TF"];
+
+/// Tracks the position of a parser within some text
+#[derive(Clone, Copy)]
+struct Cursor<'a> {
+    /// The text being parsed
+    text: &'a str,
+
+    /// The byte offset of the next character
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Create a cursor at the start of the text
+    fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    /// Create an error at the current position
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.position, kind)
+    }
+
+    /// Create an error for the next character
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(_) => self.error(ParseErrorKind::UnexpectedCharacter),
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    /// Get the remaining text
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    /// Get the next character without consuming it
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Skip any whitespace at the current position
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consume a character if it matches (ignoring case)
+    fn eat(&mut self, c: char) -> bool {
+        match self.peek() {
+            Some(n) if n.eq_ignore_ascii_case(&c) => {
+                self.position += n.len_utf8();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Consume a character or fail with an error
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// Consume a keyword if it matches (ignoring case)
+    fn keyword(&mut self, word: &str) -> bool {
+        match self.rest().get(..word.len()) {
+            Some(s) if s.eq_ignore_ascii_case(word) => {
+                self.position += word.len();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Consume a run of letters and digits
+    fn name(&mut self) -> &'a str {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    /// Consume a decimal number, saturating if it's too large
+    fn number(&mut self) -> Result<u32, ParseError> {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+
+        if length == 0 {
+            return Err(match self.peek() {
+                Some(_) => self.error(ParseErrorKind::ExpectedNumber),
+                None => self.error(ParseErrorKind::UnexpectedEnd),
+            });
+        }
+
+        self.position += length;
+        Ok(rest[..length].bytes().fold(0u32, |v, d| {
+            v.saturating_mul(10).saturating_add((d - b'0').into())
+        }))
+    }
+
+    /// Consume a number and check that it's no larger than a maximum
+    fn bounded(&mut self, min: u32, max: u32, kind: ParseErrorKind) -> Result<u8, ParseError> {
+        self.skip_whitespace();
+        let start = *self;
+        match self.number()? {
+            v if v >= min && v <= max => Ok(v as u8),
+            _ => Err(start.error(kind)),
+        }
+    }
+
+    /// Fail if there is any text left other than whitespace
+    fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(_) => Err(self.error(ParseErrorKind::UnexpectedCharacter)),
+            None => Ok(()),
+        }
+    }
+
+    /// Parse a terminal address
+    fn address(&mut self) -> Result<Address, ParseError> {
+        self.bounded(0, MAX_ADDRESS, ParseErrorKind::InvalidAddress)
+            .map(Address::from)
+    }
+
+    /// Parse a subaddress
+    fn subaddress(&mut self) -> Result<SubAddress, ParseError> {
+        self.bounded(0, MAX_ADDRESS, ParseErrorKind::InvalidSubaddress)
+            .map(SubAddress::from)
+    }
+
+    /// Parse a word count
+    fn word_count(&mut self) -> Result<u8, ParseError> {
+        self.bounded(1, 32, ParseErrorKind::InvalidWordCount)
+    }
+
+    /// Parse a 'T' or 'R' direction flag
+    fn direction(&mut self) -> Result<TransmitReceive, ParseError> {
+        self.skip_whitespace();
+        if self.eat('T') {
+            Ok(TransmitReceive::Transmit)
+        } else if self.eat('R') {
+            Ok(TransmitReceive::Receive)
+        } else if self.peek().is_none() {
+            Err(self.error(ParseErrorKind::UnexpectedEnd))
+        } else {
+            Err(self.error(ParseErrorKind::ExpectedDirection))
+        }
+    }
+
+    /// Parse a known mode code given by name or value
+    ///
+    /// Fails if the mode code isn't defined for the direction.
+    fn mode_code(&mut self, direction: TransmitReceive) -> Result<ModeCode, ParseError> {
+        self.skip_whitespace();
+        let start = *self;
+        let name = self.name();
+
+        if name.is_empty() {
+            return Err(start.unexpected());
+        }
+
+        let found = match name.parse::<u8>() {
+            Ok(v) if u32::from(v) <= MAX_ADDRESS => Some(ModeCode::from(v)),
+            Ok(_) => None,
+            Err(_) => (0..=MAX_ADDRESS as u8)
+                .map(ModeCode::from)
+                .find(|m| m.name().is_some_and(|n| n.eq_ignore_ascii_case(name))),
+        };
+
+        match found {
+            Some(m) if m.is_unknown() => Err(start.error(ParseErrorKind::UnknownModeCode)),
+            Some(m) if m.direction() != Some(direction) => {
+                Err(start.error(ParseErrorKind::WrongDirection))
+            }
+            Some(m) => Ok(m),
+            None => Err(start.error(ParseErrorKind::UnknownModeCode)),
+        }
+    }
+
+    /// Parse a command word in any of the supported forms
+    fn command(&mut self) -> Result<CommandWord, ParseError> {
+        self.skip_whitespace();
+
+        if self.keyword("RT") {
+            return self.command_verbose();
+        }
+
+        let address = self.address()?;
+        self.skip_whitespace();
+
+        let word = if self.eat('-') {
+            let direction = self.direction()?;
+            self.expect('-')?;
+            let subaddress = self.subaddress()?;
+            self.expect('-')?;
+            self.command_tail(address, direction, subaddress)?
+        } else {
+            let direction = self.direction()?;
+            let subaddress = self.subaddress()?;
+            self.expect('/')?;
+            self.command_tail(address, direction, subaddress)?
+        };
+
+        Ok(word)
+    }
+
+    /// Parse the verbose form of a command word after the 'RT' keyword
+    fn command_verbose(&mut self) -> Result<CommandWord, ParseError> {
+        let address = self.address()?;
+        let direction = self.direction()?;
+
+        self.skip_whitespace();
+        if !self.keyword("SA") {
+            return Err(self.unexpected());
+        }
+
+        let subaddress = self.subaddress()?;
+
+        self.skip_whitespace();
+        let keyword = match subaddress.is_mode_code() {
+            true => "MC",
+            false => "WC",
+        };
+        if !self.keyword(keyword) {
+            return Err(self.unexpected());
+        }
+
+        self.command_tail(address, direction, subaddress)
+    }
+
+    /// Parse the word count or mode code and build a command word
+    fn command_tail(
+        &mut self,
+        address: Address,
+        direction: TransmitReceive,
+        subaddress: SubAddress,
+    ) -> Result<CommandWord, ParseError> {
+        let word = CommandWord::new()
+            .with_address(address)
+            .with_transmit_receive(direction)
+            .with_subaddress(subaddress);
+
+        let word = if subaddress.is_mode_code() {
+            word.with_mode_code(self.mode_code(direction)?)
+        } else {
+            word.with_word_count(self.word_count()?)
+        };
+
+        Ok(word.with_calculated_parity())
+    }
+
+    /// Parse a status word in any of the supported forms
+    fn status(&mut self) -> Result<StatusWord, ParseError> {
+        self.skip_whitespace();
+
+        let verbose = self.keyword("RT");
+        let mut word = StatusWord::new().with_address(self.address()?);
+        let mut seen = 0u16;
+
+        if !verbose {
+            self.expect('[')?;
+        }
+
+        loop {
+            self.skip_whitespace();
+
+            if !verbose && self.eat(']') {
+                break;
+            }
+            if verbose && self.peek().is_none() {
+                break;
+            }
+
+            let start = *self;
+            let name = self.name();
+
+            if name.is_empty() {
+                return Err(start.unexpected());
+            }
+
+            let flag = STATUS_FLAGS
+                .iter()
+                .position(|f| f.eq_ignore_ascii_case(name));
+
+            // reserved bits are tracked after the named flags
+            let bit = 1 << flag.unwrap_or(STATUS_FLAGS.len());
+            if seen & bit != 0 {
+                return Err(start.error(ParseErrorKind::DuplicateFlag));
+            }
+            seen |= bit;
+
+            word = match flag {
+                Some(0) => word.with_message_error(MessageError::Error),
+                Some(1) => word.with_instrumentation(Instrumentation::Command),
+                Some(2) => word.with_service_request(ServiceRequest::Service),
+                Some(3) => word.with_broadcast_received(BroadcastReceived::Received),
+                Some(4) => word.with_terminal_busy(TerminalBusy::Busy),
+                Some(5) => word.with_subsystem_error(SubsystemError::Error),
+                Some(6) => word.with_dynamic_bus_acceptance(DynamicBusAcceptance::Accepted),
+                Some(_) => word.with_terminal_error(TerminalError::Error),
+                None => word.with_reserved(start.reserved(name)?),
+            };
+        }
+
+        Ok(word.with_calculated_parity())
+    }
+
+    /// Parse reserved bits given as 'RSV' followed by a value
+    fn reserved(&self, name: &str) -> Result<Reserved, ParseError> {
+        let value = match name.get(..3) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RSV") => &name[3..],
+            _ => return Err(self.error(ParseErrorKind::UnknownFlag)),
+        };
+
+        match value.parse::<u8>() {
+            Ok(v @ 1..=7) => Ok(Reserved::from(v)),
+            _ => Err(self.error(ParseErrorKind::InvalidReserved)),
+        }
+    }
+
+    /// Parse a single command or a pair of commands as a transfer
+    fn transfer(&mut self) -> Result<Transfer, ParseError> {
+        let first = self.command()?;
+
+        self.skip_whitespace();
+        if !self.eat(',') {
+            return Ok(Transfer::new(first));
+        }
+
+        self.skip_whitespace();
+        let start = *self;
+        let second = self.command()?;
+
+        Transfer::rt_to_rt(first, second).map_err(|_| start.error(ParseErrorKind::InvalidTransfer))
+    }
+}
+
+/// Parse a value with a cursor and fail on trailing text
+fn parse<'a, T>(
+    text: &'a str,
+    f: impl FnOnce(&mut Cursor<'a>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let mut cursor = Cursor::new(text);
+    let value = f(&mut cursor)?;
+    cursor.end()?;
+    Ok(value)
+}
+
+impl FromStr for CommandWord {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s, Cursor::command)
+    }
+}
+
+impl FromStr for StatusWord {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s, Cursor::status)
+    }
+}
+
+impl FromStr for Transfer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s, Cursor::transfer)
+    }
+}
+
+/// Parse a command word for a test, panicking if the text is invalid
+#[cfg(test)]
+pub(crate) fn command(text: &str) -> CommandWord {
+    text.parse().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(position: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(position, kind)
+    }
+
+    #[test]
+    fn test_parse_command_display_form() {
+        let expected = CommandWord::new()
+            .with_address(Address::Value(5))
+            .with_transmit_receive(TransmitReceive::Transmit)
+            .with_subaddress(SubAddress::Value(3))
+            .with_word_count(2)
+            .with_calculated_parity();
+        assert_eq!("05-T-03-02".parse::<CommandWord>(), Ok(expected));
+        assert_eq!(" 5 - t - 3 - 2 ".parse::<CommandWord>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_command_compact_form() {
+        let expected = command("12-R-05-04");
+        assert_eq!("12R05/4".parse::<CommandWord>(), Ok(expected));
+
+        let expected = command("12-R-05-32");
+        assert_eq!("12R05/32".parse::<CommandWord>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_command_verbose_form() {
+        let expected = command("03-T-31-TransmitBITWord");
+        assert_eq!(
+            "RT3 T SA31 MC TransmitBITWord".parse::<CommandWord>(),
+            Ok(expected)
+        );
+        assert_eq!("rt3 t sa31 mc 19".parse::<CommandWord>(), Ok(expected));
+
+        let expected = command("12-R-05-04");
+        assert_eq!("RT12 R SA5 WC4".parse::<CommandWord>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_command_mode_code() {
+        let expected = CommandWord::new()
+            .with_address(Address::Value(16))
+            .with_transmit_receive(TransmitReceive::Transmit)
+            .with_subaddress(SubAddress::ModeCode(0))
+            .with_mode_code(ModeCode::TransmitterShutdown)
+            .with_calculated_parity();
+        assert_eq!(
+            "16-T-00-TransmitterShutdown".parse::<CommandWord>(),
+            Ok(expected)
+        );
+        assert_eq!("16T00/4".parse::<CommandWord>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_command_display_round_trip() {
+        let word = CommandWord::new()
+            .with_address(Address::Value(31))
+            .with_transmit_receive(TransmitReceive::Receive)
+            .with_subaddress(SubAddress::ModeCode(31))
+            .with_mode_code(ModeCode::SynchronizeWithDataWord)
+            .with_calculated_parity();
+        let text = std::format!("{}", word);
+        assert_eq!(text.parse::<CommandWord>(), Ok(word));
+    }
+
+    #[test]
+    fn test_parse_command_fail_ranges() {
+        assert_eq!(
+            "32-T-03-02".parse::<CommandWord>(),
+            Err(error(0, ParseErrorKind::InvalidAddress))
+        );
+        assert_eq!(
+            "05-T-40-02".parse::<CommandWord>(),
+            Err(error(5, ParseErrorKind::InvalidSubaddress))
+        );
+        assert_eq!(
+            "05-T-03-33".parse::<CommandWord>(),
+            Err(error(8, ParseErrorKind::InvalidWordCount))
+        );
+        assert_eq!(
+            "12R05/0".parse::<CommandWord>(),
+            Err(error(6, ParseErrorKind::InvalidWordCount))
+        );
+        assert_eq!(
+            "RT3 T SA31 MC 10".parse::<CommandWord>(),
+            Err(error(14, ParseErrorKind::UnknownModeCode))
+        );
+        assert_eq!(
+            "RT3 T SA31 MC TransmitEverything".parse::<CommandWord>(),
+            Err(error(14, ParseErrorKind::UnknownModeCode))
+        );
+    }
+
+    #[test]
+    fn test_parse_command_fail_direction() {
+        assert_eq!(
+            "RT3 R SA31 MC TransmitBITWord".parse::<CommandWord>(),
+            Err(error(14, ParseErrorKind::WrongDirection))
+        );
+        assert_eq!(
+            "05-T-31-17".parse::<CommandWord>(),
+            Err(error(8, ParseErrorKind::WrongDirection))
+        );
+        assert_eq!(
+            "05R00/DynamicBusControl".parse::<CommandWord>(),
+            Err(error(6, ParseErrorKind::WrongDirection))
+        );
+    }
+
+    #[test]
+    fn test_parse_command_fail_syntax() {
+        assert_eq!(
+            "".parse::<CommandWord>(),
+            Err(error(0, ParseErrorKind::UnexpectedEnd))
+        );
+        assert_eq!(
+            "05-X-03-02".parse::<CommandWord>(),
+            Err(error(3, ParseErrorKind::ExpectedDirection))
+        );
+        assert_eq!(
+            "12R05-4".parse::<CommandWord>(),
+            Err(error(5, ParseErrorKind::UnexpectedCharacter))
+        );
+        assert_eq!(
+            "05-T-03-02 extra".parse::<CommandWord>(),
+            Err(error(11, ParseErrorKind::UnexpectedCharacter))
+        );
+        assert_eq!(
+            "RT3 T SA31 WC2".parse::<CommandWord>(),
+            Err(error(11, ParseErrorKind::UnexpectedCharacter))
+        );
+        assert_eq!(
+            "05-T-03-".parse::<CommandWord>(),
+            Err(error(8, ParseErrorKind::UnexpectedEnd))
+        );
+    }
+
+    #[test]
+    fn test_parse_status() {
+        let expected = StatusWord::new()
+            .with_address(Address::Value(5))
+            .with_service_request(ServiceRequest::Service)
+            .with_terminal_busy(TerminalBusy::Busy)
+            .with_calculated_parity();
+        assert_eq!("05 [SR BUSY]".parse::<StatusWord>(), Ok(expected));
+        assert_eq!("RT5 busy sr".parse::<StatusWord>(), Ok(expected));
+
+        let expected = StatusWord::new()
+            .with_address(Address::Value(1))
+            .with_calculated_parity();
+        assert_eq!("01 []".parse::<StatusWord>(), Ok(expected));
+        assert_eq!("RT1".parse::<StatusWord>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_status_all_flags() {
+        let word: StatusWord = "RT7 ME INSTR SR RSV5 BCR BUSY SSF DBCA TF".parse().unwrap();
+        assert!(word.message_error().is_error());
+        assert!(word.instrumentation().is_command());
+        assert!(word.service_request().is_service());
+        assert_eq!(word.reserved(), Reserved::Value(5));
+        assert!(word.broadcast_received().is_received());
+        assert!(word.terminal_busy().is_busy());
+        assert!(word.subsystem_error().is_error());
+        assert!(word.dynamic_bus_acceptance().is_accepted());
+        assert!(word.terminal_error().is_error());
+        assert!(word.check_parity());
+    }
+
+    #[test]
+    fn test_parse_status_display_round_trip() {
+        let word = StatusWord::new()
+            .with_address(Address::Value(30))
+            .with_message_error(MessageError::Error)
+            .with_reserved(Reserved::Value(3))
+            .with_terminal_error(TerminalError::Error)
+            .with_calculated_parity();
+        let text = std::format!("{}", word);
+        assert_eq!(text.parse::<StatusWord>(), Ok(word));
+    }
+
+    #[test]
+    fn test_parse_status_fail() {
+        assert_eq!(
+            "05 [SR LATE]".parse::<StatusWord>(),
+            Err(error(7, ParseErrorKind::UnknownFlag))
+        );
+        assert_eq!(
+            "05 [RSV8]".parse::<StatusWord>(),
+            Err(error(4, ParseErrorKind::InvalidReserved))
+        );
+        assert_eq!(
+            "05 [SR".parse::<StatusWord>(),
+            Err(error(6, ParseErrorKind::UnexpectedEnd))
+        );
+        assert_eq!(
+            "05 SR".parse::<StatusWord>(),
+            Err(error(3, ParseErrorKind::UnexpectedCharacter))
+        );
+        assert_eq!(
+            "40 []".parse::<StatusWord>(),
+            Err(error(0, ParseErrorKind::InvalidAddress))
+        );
+        assert_eq!(
+            "05 [SR SR]".parse::<StatusWord>(),
+            Err(error(7, ParseErrorKind::DuplicateFlag))
+        );
+        assert_eq!(
+            "RT5 BUSY ME busy".parse::<StatusWord>(),
+            Err(error(12, ParseErrorKind::DuplicateFlag))
+        );
+        assert_eq!(
+            "05 [RSV1 RSV2]".parse::<StatusWord>(),
+            Err(error(9, ParseErrorKind::DuplicateFlag))
+        );
+    }
+
+    #[test]
+    fn test_parse_transfer() {
+        let transfer: Transfer = "05-T-03-02".parse().unwrap();
+        assert!(transfer.is_rt_to_bc());
+
+        let transfer: Transfer = "RT5 R SA3 WC2".parse().unwrap();
+        assert!(transfer.is_bc_to_rt());
+
+        let transfer: Transfer = "31T00/Synchronize".parse().unwrap();
+        assert!(transfer.is_mode_code());
+        assert!(transfer.is_broadcast());
+
+        let transfer: Transfer = "01R02/4, RT5 T SA3 WC4".parse().unwrap();
+        assert!(transfer.is_rt_to_rt());
+        assert_eq!(transfer.word_count(), 4);
+    }
+
+    #[test]
+    fn test_parse_transfer_fail() {
+        assert_eq!(
+            "01-R-02-04, 05-T-03-05".parse::<Transfer>(),
+            Err(error(12, ParseErrorKind::InvalidTransfer))
+        );
+        assert_eq!(
+            "01-R-02-04, 05-T-03-04,".parse::<Transfer>(),
+            Err(error(22, ParseErrorKind::UnexpectedCharacter))
+        );
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let result = "32-T-03-02".parse::<CommandWord>().unwrap_err();
+        assert_eq!(
+            std::format!("{}", result),
+            "address must be between 0 and 31 at position 0"
+        );
+    }
+}
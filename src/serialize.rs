@@ -0,0 +1,1067 @@
+//! Serde implementations for words, messages and flags
+//!
+//! Human-readable formats (JSON, YAML etc.) get a decomposed form
+//! where each field of a word is written by name, while compact
+//! formats (MessagePack, bincode etc.) get the raw 20-bit word with
+//! sync, body and parity packed into a u32. Deserialization checks
+//! the parity of every word, and the sync of every raw word.
+//!
+//! The [raw] module can be used with `#[serde(with = ..)]` to
+//! get the raw form of a word in human-readable formats as well.
+
+use core::fmt;
+use core::result::Result;
+
+use serde::de::{self, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess};
+use serde::de::{Unexpected, VariantAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{MessageError, SubsystemError, TerminalError};
+use crate::flags::*;
+use crate::{CommandWord, DataWord, Message, Packet, StatusWord, Word, WordType};
+
+/// Field names of the decomposed command word
+const COMMAND_FIELDS: &[&str] = &[
+    "address",
+    "subaddress",
+    "transmit_receive",
+    "word_count",
+    "mode_code",
+    "parity",
+];
+
+/// Field names of the decomposed status word
+const STATUS_FIELDS: &[&str] = &[
+    "address",
+    "message_error",
+    "instrumentation",
+    "service_request",
+    "reserved",
+    "broadcast_received",
+    "terminal_busy",
+    "subsystem_error",
+    "dynamic_bus_acceptance",
+    "terminal_error",
+    "parity",
+];
+
+/// Field names of the decomposed data word
+const DATA_FIELDS: &[&str] = &["value", "parity"];
+
+/// Field names of the decomposed packet
+const PACKET_FIELDS: &[&str] = &["sync", "body", "parity"];
+
+/// Variant names of the word container
+const WORD_VARIANTS: &[&str] = &["None", "Command", "Status", "Data"];
+
+/// Implement serde traits for a flag enum
+///
+/// Human-readable formats use the variant name for unit variants
+/// and the raw value for everything else, compact formats always
+/// use the raw value. Raw values larger than `$max` are rejected.
+macro_rules! impl_flag {
+    ( $name:ident, $max:expr, [ $( $variant:ident ),* ] ) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    $( Self::$variant if serializer.is_human_readable() => {
+                        serializer.serialize_str(stringify!($variant))
+                    } )*
+                    _ => serializer.serialize_u8(u8::from(*self)),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(Clone, Copy)]
+                struct FlagVisitor;
+
+                impl Visitor<'_> for FlagVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(f, "a {} name or a value no greater than {}", stringify!($name), $max)
+                    }
+
+                    fn visit_u64<E: de::Error>(self, value: u64) -> Result<$name, E> {
+                        u8::try_from(value)
+                            .ok()
+                            .filter(|v| *v <= $max)
+                            .map($name::from)
+                            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+                    }
+
+                    fn visit_str<E: de::Error>(self, value: &str) -> Result<$name, E> {
+                        match value {
+                            $( stringify!($variant) => Ok($name::$variant), )*
+                            _ => Err(E::unknown_variant(value, &[ $( stringify!($variant) ),* ])),
+                        }
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(FlagVisitor)
+                } else {
+                    deserializer.deserialize_u8(FlagVisitor)
+                }
+            }
+        }
+    };
+}
+
+impl_flag!(Address, 0b11111, []);
+impl_flag!(SubAddress, 0b11111, []);
+impl_flag!(TransmitReceive, 1, [Receive, Transmit]);
+impl_flag!(
+    ModeCode,
+    0b11111,
+    [
+        DynamicBusControl,
+        Synchronize,
+        TransmitStatusWord,
+        InitiateSelfTest,
+        TransmitterShutdown,
+        OverrideTransmitterShutdown,
+        InhibitTerminalFlagBit,
+        OverrideInhibitTerminalFlagBit,
+        ResetRemoteTerminal,
+        TransmitVectorWord,
+        SynchronizeWithDataWord,
+        TransmitLastCommandWord,
+        TransmitBITWord,
+        SelectedTransmitterShutdown,
+        OverrideSelectedTransmitterShutdown
+    ]
+);
+impl_flag!(Instrumentation, 1, [Status, Command]);
+impl_flag!(ServiceRequest, 1, [NoService, Service]);
+impl_flag!(Reserved, 0b111, [None]);
+impl_flag!(BroadcastReceived, 1, [NotReceived, Received]);
+impl_flag!(TerminalBusy, 1, [NotBusy, Busy]);
+impl_flag!(DynamicBusAcceptance, 1, [NotAccepted, Accepted]);
+impl_flag!(MessageError, 1, [None, Error]);
+impl_flag!(SubsystemError, 1, [None, Error]);
+impl_flag!(TerminalError, 1, [None, Error]);
+
+/// Deserializes a field or variant name as an index into a list of names
+#[derive(Clone, Copy)]
+struct Names(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for Names {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl Visitor<'_> for Names {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "one of {:?}", self.0)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<usize, E> {
+        usize::try_from(value)
+            .ok()
+            .filter(|i| *i < self.0.len())
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<usize, E> {
+        self.0
+            .iter()
+            .position(|n| *n == value)
+            .ok_or_else(|| E::unknown_field(value, self.0))
+    }
+}
+
+/// Pack a packet into the low 20 bits of a u32
+fn pack(packet: Packet) -> u32 {
+    (u32::from(packet.sync & 0b111) << 17)
+        | (u32::from(u16::from_be_bytes(packet.body)) << 1)
+        | u32::from(packet.parity & 0b1)
+}
+
+/// Unpack a packet from the low 20 bits of a u32
+fn unpack(value: u32) -> Option<Packet> {
+    if value >> 20 != 0 {
+        return None;
+    }
+
+    let sync = (value >> 17) as u8;
+    let body = ((value >> 1) as u16).to_be_bytes();
+    let parity = (value & 0b1) as u8;
+
+    Some(Packet::new(sync, body, parity))
+}
+
+/// Set the parity of a decoded word, failing if it's incorrect
+fn with_parity<T: Word, E: de::Error>(word: T, parity: u8) -> Result<T, E> {
+    word.with_parity(parity).build().map_err(|_| {
+        E::invalid_value(
+            Unexpected::Unsigned(parity.into()),
+            &"a parity bit matching the word",
+        )
+    })
+}
+
+/// Read the value of a field, failing if it was already found
+fn next_field<'de, T: Deserialize<'de>, A: MapAccess<'de>>(
+    map: &mut A,
+    value: &mut Option<T>,
+    name: &'static str,
+) -> Result<(), A::Error> {
+    if value.is_some() {
+        return Err(de::Error::duplicate_field(name));
+    }
+    *value = Some(map.next_value()?);
+    Ok(())
+}
+
+/// Get a required field, failing if it wasn't found
+fn required<T, E: de::Error>(value: Option<T>, name: &'static str) -> Result<T, E> {
+    value.ok_or_else(|| E::missing_field(name))
+}
+
+/// Serialize a word as a packed 20-bit value
+fn serialize_raw<T: Word, S: Serializer>(
+    word: &T,
+    sync: u8,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u32(pack(Packet::new(sync, word.as_bytes(), word.parity())))
+}
+
+/// Deserialize a word from a packed 20-bit value, checking sync and parity
+fn deserialize_raw<'de, T: Word, D: Deserializer<'de>>(
+    deserializer: D,
+    sync: u8,
+) -> Result<T, D::Error> {
+    let packet = deserializer.deserialize_u32(RawVisitor(sync))?;
+    packet.as_word::<T>().map_err(|_| {
+        de::Error::invalid_value(
+            Unexpected::Unsigned(pack(packet).into()),
+            &"a word with a valid parity bit",
+        )
+    })
+}
+
+/// Get the sync of a word from its type
+fn sync_of<T: Word>(word: T) -> u8 {
+    if word.into().is_data() {
+        Packet::DATA_SYNC
+    } else {
+        Packet::SERV_SYNC
+    }
+}
+
+/// Serde functions for the raw form of a word
+///
+/// Words are serialized as the packed 20-bit value regardless of
+/// the format, so the raw form can be used in human-readable formats
+/// where the decomposed form would otherwise be used.
+///
+/// Use `#[serde(with = "mil_std_1553b::raw")]` on a field of type
+/// [CommandWord], [StatusWord] or [DataWord].
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let word = DataWord::new().with_value(0x1234).with_calculated_parity();
+///
+///     let value = raw::serialize(&word, serde_json::value::Serializer).unwrap();
+///     assert_eq!(value, 0x0002_2468);
+///
+///     let result: DataWord = raw::deserialize(value).unwrap();
+///     assert_eq!(result, word);
+/// # Ok(())
+/// # }
+/// ```
+pub mod raw {
+    use super::*;
+
+    /// Serialize a word as a packed 20-bit value
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The word to serialize
+    /// * `serializer` - The serializer to use
+    ///
+    pub fn serialize<T: Word + Copy, S: Serializer>(
+        word: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_raw(word, sync_of(*word), serializer)
+    }
+
+    /// Deserialize a word from a packed 20-bit value
+    ///
+    /// Fails if the sync doesn't match the type of word or
+    /// the parity bit is incorrect.
+    ///
+    /// # Arguments
+    ///
+    /// * `deserializer` - The deserializer to use
+    ///
+    pub fn deserialize<'de, T: Word, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserialize_raw(deserializer, sync_of(T::new()))
+    }
+}
+
+/// Visits a packed 20-bit value with an expected sync
+#[derive(Clone, Copy)]
+struct RawVisitor(u8);
+
+impl Visitor<'_> for RawVisitor {
+    type Value = Packet;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a 20-bit word with sync {:#05b}", self.0)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Packet, E> {
+        u32::try_from(value)
+            .ok()
+            .and_then(unpack)
+            .filter(|p| p.sync == self.0)
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+}
+
+impl Serialize for CommandWord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serialize_raw(self, Packet::SERV_SYNC, serializer);
+        }
+
+        let mut state = serializer.serialize_struct("CommandWord", 5)?;
+        state.serialize_field("address", &self.address())?;
+        state.serialize_field("subaddress", &self.subaddress())?;
+        state.serialize_field("transmit_receive", &self.transmit_receive())?;
+        if self.is_mode_code() {
+            state.serialize_field("mode_code", &self.mode_code())?;
+        } else {
+            state.serialize_field("word_count", &self.word_count())?;
+        }
+        state.serialize_field("parity", &self.parity())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CommandWord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Clone, Copy)]
+        struct CommandVisitor;
+
+        impl<'de> Visitor<'de> for CommandVisitor {
+            type Value = CommandWord;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a command word")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<CommandWord, A::Error> {
+                let mut address = None;
+                let mut subaddress = None;
+                let mut transmit_receive = None;
+                let mut word_count = None;
+                let mut mode_code = None;
+                let mut parity = None;
+
+                while let Some(index) = map.next_key_seed(Names(COMMAND_FIELDS))? {
+                    match index {
+                        0 => next_field(&mut map, &mut address, "address")?,
+                        1 => next_field(&mut map, &mut subaddress, "subaddress")?,
+                        2 => next_field(&mut map, &mut transmit_receive, "transmit_receive")?,
+                        3 => next_field(&mut map, &mut word_count, "word_count")?,
+                        4 => next_field(&mut map, &mut mode_code, "mode_code")?,
+                        _ => next_field(&mut map, &mut parity, "parity")?,
+                    }
+                }
+
+                let word = CommandWord::new()
+                    .with_address(required(address, "address")?)
+                    .with_subaddress(required(subaddress, "subaddress")?)
+                    .with_transmit_receive(required(transmit_receive, "transmit_receive")?);
+
+                let word = match (word.is_mode_code(), word_count, mode_code) {
+                    (true, None, Some(m)) => word.with_mode_code(m),
+                    (false, Some(c @ 1..=32), None) => word.with_word_count(c),
+                    (false, Some(c), None) => {
+                        return Err(de::Error::invalid_value(
+                            Unexpected::Unsigned(c.into()),
+                            &"a word count between 1 and 32",
+                        ))
+                    }
+                    (true, None, None) => return Err(de::Error::missing_field("mode_code")),
+                    (false, None, None) => return Err(de::Error::missing_field("word_count")),
+                    (true, Some(_), _) => {
+                        return Err(de::Error::custom("unexpected word_count with mode code"))
+                    }
+                    (false, _, Some(_)) => {
+                        return Err(de::Error::custom("unexpected mode_code without mode code"))
+                    }
+                };
+
+                with_parity(word, required(parity, "parity")?)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("CommandWord", COMMAND_FIELDS, CommandVisitor)
+        } else {
+            deserialize_raw(deserializer, Packet::SERV_SYNC)
+        }
+    }
+}
+
+impl Serialize for StatusWord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serialize_raw(self, Packet::SERV_SYNC, serializer);
+        }
+
+        let mut state = serializer.serialize_struct("StatusWord", STATUS_FIELDS.len())?;
+        state.serialize_field("address", &self.address())?;
+        state.serialize_field("message_error", &self.message_error())?;
+        state.serialize_field("instrumentation", &self.instrumentation())?;
+        state.serialize_field("service_request", &self.service_request())?;
+        state.serialize_field("reserved", &self.reserved())?;
+        state.serialize_field("broadcast_received", &self.broadcast_received())?;
+        state.serialize_field("terminal_busy", &self.terminal_busy())?;
+        state.serialize_field("subsystem_error", &self.subsystem_error())?;
+        state.serialize_field("dynamic_bus_acceptance", &self.dynamic_bus_acceptance())?;
+        state.serialize_field("terminal_error", &self.terminal_error())?;
+        state.serialize_field("parity", &self.parity())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusWord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Clone, Copy)]
+        struct StatusVisitor;
+
+        impl<'de> Visitor<'de> for StatusVisitor {
+            type Value = StatusWord;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a status word")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<StatusWord, A::Error> {
+                let mut address = None;
+                let mut message_error = None;
+                let mut instrumentation = None;
+                let mut service_request = None;
+                let mut reserved = None;
+                let mut broadcast_received = None;
+                let mut terminal_busy = None;
+                let mut subsystem_error = None;
+                let mut dynamic_bus_acceptance = None;
+                let mut terminal_error = None;
+                let mut parity = None;
+
+                while let Some(index) = map.next_key_seed(Names(STATUS_FIELDS))? {
+                    match index {
+                        0 => next_field(&mut map, &mut address, "address")?,
+                        1 => next_field(&mut map, &mut message_error, "message_error")?,
+                        2 => next_field(&mut map, &mut instrumentation, "instrumentation")?,
+                        3 => next_field(&mut map, &mut service_request, "service_request")?,
+                        4 => next_field(&mut map, &mut reserved, "reserved")?,
+                        5 => next_field(&mut map, &mut broadcast_received, "broadcast_received")?,
+                        6 => next_field(&mut map, &mut terminal_busy, "terminal_busy")?,
+                        7 => next_field(&mut map, &mut subsystem_error, "subsystem_error")?,
+                        8 => next_field(
+                            &mut map,
+                            &mut dynamic_bus_acceptance,
+                            "dynamic_bus_acceptance",
+                        )?,
+                        9 => next_field(&mut map, &mut terminal_error, "terminal_error")?,
+                        _ => next_field(&mut map, &mut parity, "parity")?,
+                    }
+                }
+
+                let word = StatusWord::new()
+                    .with_address(required(address, "address")?)
+                    .with_message_error(required(message_error, "message_error")?)
+                    .with_instrumentation(required(instrumentation, "instrumentation")?)
+                    .with_service_request(required(service_request, "service_request")?)
+                    .with_reserved(required(reserved, "reserved")?)
+                    .with_broadcast_received(required(broadcast_received, "broadcast_received")?)
+                    .with_terminal_busy(required(terminal_busy, "terminal_busy")?)
+                    .with_subsystem_error(required(subsystem_error, "subsystem_error")?)
+                    .with_dynamic_bus_acceptance(required(
+                        dynamic_bus_acceptance,
+                        "dynamic_bus_acceptance",
+                    )?)
+                    .with_terminal_error(required(terminal_error, "terminal_error")?);
+
+                with_parity(word, required(parity, "parity")?)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("StatusWord", STATUS_FIELDS, StatusVisitor)
+        } else {
+            deserialize_raw(deserializer, Packet::SERV_SYNC)
+        }
+    }
+}
+
+impl Serialize for DataWord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serialize_raw(self, Packet::DATA_SYNC, serializer);
+        }
+
+        let mut state = serializer.serialize_struct("DataWord", DATA_FIELDS.len())?;
+        state.serialize_field("value", &self.as_value())?;
+        state.serialize_field("parity", &self.parity())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for DataWord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Clone, Copy)]
+        struct DataVisitor;
+
+        impl<'de> Visitor<'de> for DataVisitor {
+            type Value = DataWord;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a data word")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<DataWord, A::Error> {
+                let mut value = None;
+                let mut parity = None;
+
+                while let Some(index) = map.next_key_seed(Names(DATA_FIELDS))? {
+                    match index {
+                        0 => next_field(&mut map, &mut value, "value")?,
+                        _ => next_field(&mut map, &mut parity, "parity")?,
+                    }
+                }
+
+                let word = DataWord::new().with_value(required(value, "value")?);
+                with_parity(word, required(parity, "parity")?)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("DataWord", DATA_FIELDS, DataVisitor)
+        } else {
+            deserialize_raw(deserializer, Packet::DATA_SYNC)
+        }
+    }
+}
+
+impl Serialize for WordType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::None => serializer.serialize_unit_variant("WordType", 0, "None"),
+            Self::Command(w) => serializer.serialize_newtype_variant("WordType", 1, "Command", w),
+            Self::Status(w) => serializer.serialize_newtype_variant("WordType", 2, "Status", w),
+            Self::Data(w) => serializer.serialize_newtype_variant("WordType", 3, "Data", w),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WordType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Clone, Copy)]
+        struct WordTypeVisitor;
+
+        impl<'de> Visitor<'de> for WordTypeVisitor {
+            type Value = WordType;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a command, status or data word")
+            }
+
+            fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<WordType, A::Error> {
+                let (index, variant) = data.variant_seed(Names(WORD_VARIANTS))?;
+                match index {
+                    0 => variant.unit_variant().map(|_| WordType::None),
+                    1 => variant.newtype_variant().map(WordType::Command),
+                    2 => variant.newtype_variant().map(WordType::Status),
+                    _ => variant.newtype_variant().map(WordType::Data),
+                }
+            }
+        }
+
+        deserializer.deserialize_enum("WordType", WORD_VARIANTS, WordTypeVisitor)
+    }
+}
+
+impl Serialize for Packet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_u32(pack(*self));
+        }
+
+        let mut state = serializer.serialize_struct("Packet", PACKET_FIELDS.len())?;
+        state.serialize_field("sync", &self.sync)?;
+        state.serialize_field("body", &self.body)?;
+        state.serialize_field("parity", &self.parity)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Packet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Clone, Copy)]
+        struct PacketVisitor;
+
+        impl<'de> Visitor<'de> for PacketVisitor {
+            type Value = Packet;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a packet with a valid sync and parity")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Packet, E> {
+                u32::try_from(value)
+                    .ok()
+                    .and_then(unpack)
+                    .filter(Packet::is_valid)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Packet, A::Error> {
+                let mut sync = None;
+                let mut body = None;
+                let mut parity = None;
+
+                while let Some(index) = map.next_key_seed(Names(PACKET_FIELDS))? {
+                    match index {
+                        0 => next_field(&mut map, &mut sync, "sync")?,
+                        1 => next_field(&mut map, &mut body, "body")?,
+                        _ => next_field(&mut map, &mut parity, "parity")?,
+                    }
+                }
+
+                let packet = Packet::new(
+                    required(sync, "sync")?,
+                    required(body, "body")?,
+                    required(parity, "parity")?,
+                );
+
+                if packet.is_valid() {
+                    Ok(packet)
+                } else {
+                    Err(de::Error::invalid_value(Unexpected::Other("packet"), &self))
+                }
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("Packet", PACKET_FIELDS, PacketVisitor)
+        } else {
+            deserializer.deserialize_u32(PacketVisitor)
+        }
+    }
+}
+
+impl<const WORDS: usize> Serialize for Message<WORDS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_seq(Some(self.length()))?;
+        for word in self.words() {
+            state.serialize_element(word)?;
+        }
+        state.end()
+    }
+}
+
+impl<'de, const WORDS: usize> Deserialize<'de> for Message<WORDS> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Clone, Copy)]
+        struct MessageVisitor<const N: usize>;
+
+        impl<'de, const N: usize> Visitor<'de> for MessageVisitor<N> {
+            type Value = Message<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {} words", N)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Message<N>, A::Error> {
+                let mut message = Message::new();
+                let mut length = 0;
+
+                while let Some(word) = seq.next_element::<WordType>()? {
+                    if length == N {
+                        return Err(de::Error::invalid_length(length + 1, &self));
+                    }
+
+                    match word {
+                        WordType::Command(w) => message.add_command(w),
+                        WordType::Status(w) => message.add_status(w),
+                        WordType::Data(w) => message.add_data(w),
+                        WordType::None => {
+                            return Err(de::Error::invalid_value(
+                                Unexpected::UnitVariant,
+                                &"a command, status or data word",
+                            ))
+                        }
+                    }
+
+                    length += 1;
+                }
+
+                message.validate().map_err(de::Error::custom)?;
+                Ok(message)
+            }
+        }
+
+        deserializer.deserialize_seq(MessageVisitor::<WORDS>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::vec::Vec;
+
+    fn json<T: Serialize>(value: &T) -> serde_json::Value {
+        serde_json::to_value(value).unwrap()
+    }
+
+    fn from_json<T: for<'de> Deserialize<'de>>(value: serde_json::Value) -> serde_json::Result<T> {
+        serde_json::from_value(value)
+    }
+
+    fn compact<T: Serialize>(value: &T) -> Vec<u8> {
+        rmp_serde::to_vec(value).unwrap()
+    }
+
+    fn from_compact<T: for<'de> Deserialize<'de>>(data: &[u8]) -> Option<T> {
+        rmp_serde::from_slice(data).ok()
+    }
+
+    #[test]
+    fn test_serde_flags_human_readable() {
+        assert_eq!(json(&TransmitReceive::Transmit), json!("Transmit"));
+        assert_eq!(json(&ModeCode::TransmitBITWord), json!("TransmitBITWord"));
+        assert_eq!(json(&ModeCode::UnknownModeCode(0b01010)), json!(10));
+        assert_eq!(json(&Address::Broadcast(31)), json!(31));
+        assert_eq!(json(&Reserved::Value(5)), json!(5));
+        assert_eq!(json(&TerminalError::Error), json!("Error"));
+
+        let flag: ServiceRequest = from_json(json!("Service")).unwrap();
+        assert_eq!(flag, ServiceRequest::Service);
+
+        let flag: ServiceRequest = from_json(json!(1)).unwrap();
+        assert_eq!(flag, ServiceRequest::Service);
+
+        let flag: Address = from_json(json!(31)).unwrap();
+        assert_eq!(flag, Address::Broadcast(31));
+    }
+
+    #[test]
+    fn test_serde_flags_fail_invalid() {
+        assert!(from_json::<ServiceRequest>(json!("Unknown")).is_err());
+        assert!(from_json::<ServiceRequest>(json!(2)).is_err());
+        assert!(from_json::<Address>(json!(32)).is_err());
+        assert!(from_json::<Reserved>(json!(8)).is_err());
+    }
+
+    #[test]
+    fn test_serde_flags_compact() {
+        let data = compact(&BroadcastReceived::Received);
+        assert_eq!(data, [1]);
+        assert_eq!(from_compact(&data), Some(BroadcastReceived::Received));
+    }
+
+    #[test]
+    fn test_serde_command_word() {
+        let word = CommandWord::new()
+            .with_address(Address::Value(5))
+            .with_subaddress(SubAddress::Value(3))
+            .with_transmit_receive(TransmitReceive::Transmit)
+            .with_word_count(32)
+            .with_calculated_parity();
+
+        let value = json(&word);
+        assert_eq!(
+            value,
+            json!({
+                "address": 5,
+                "subaddress": 3,
+                "transmit_receive": "Transmit",
+                "word_count": 32,
+                "parity": word.parity()
+            })
+        );
+        assert_eq!(from_json::<CommandWord>(value).unwrap(), word);
+    }
+
+    #[test]
+    fn test_serde_command_word_mode_code() {
+        let word = CommandWord::new()
+            .with_address(Address::Value(16))
+            .with_subaddress(SubAddress::ModeCode(31))
+            .with_mode_code(ModeCode::TransmitterShutdown)
+            .with_calculated_parity();
+
+        let value = json(&word);
+        assert_eq!(value["mode_code"], json!("TransmitterShutdown"));
+        assert!(value.get("word_count").is_none());
+        assert_eq!(from_json::<CommandWord>(value).unwrap(), word);
+    }
+
+    #[test]
+    fn test_serde_command_word_fail_fields() {
+        let value = json!({
+            "address": 5,
+            "subaddress": 3,
+            "transmit_receive": "Receive",
+            "mode_code": "Synchronize",
+            "parity": 1
+        });
+        assert!(from_json::<CommandWord>(value).is_err());
+
+        let value = json!({
+            "address": 5,
+            "subaddress": 3,
+            "transmit_receive": "Receive",
+            "word_count": 33,
+            "parity": 1
+        });
+        assert!(from_json::<CommandWord>(value).is_err());
+
+        let value = json!({ "address": 5 });
+        assert!(from_json::<CommandWord>(value).is_err());
+    }
+
+    #[test]
+    fn test_serde_status_word() {
+        let word = StatusWord::new()
+            .with_address(Address::Value(12))
+            .with_service_request(ServiceRequest::Service)
+            .with_terminal_busy(TerminalBusy::Busy)
+            .with_calculated_parity();
+
+        let value = json(&word);
+        assert_eq!(value["address"], json!(12));
+        assert_eq!(value["service_request"], json!("Service"));
+        assert_eq!(value["terminal_busy"], json!("Busy"));
+        assert_eq!(value["reserved"], json!("None"));
+        assert_eq!(from_json::<StatusWord>(value).unwrap(), word);
+    }
+
+    #[test]
+    fn test_serde_data_word() {
+        let word = DataWord::from(0b0110100001101001);
+        let value = json(&word);
+        assert_eq!(value, json!({ "value": 0b0110100001101001, "parity": 0 }));
+        assert_eq!(from_json::<DataWord>(value).unwrap(), word);
+    }
+
+    #[test]
+    fn test_serde_word_fail_parity() {
+        let value = json!({ "value": 0b0110100001101001, "parity": 1 });
+        assert!(from_json::<DataWord>(value).is_err());
+    }
+
+    #[test]
+    fn test_serde_word_compact() {
+        let word = DataWord::from(0b0110100001101001);
+        let data = compact(&word);
+        assert_eq!(from_compact(&data), Some(word));
+
+        let word = CommandWord::from(0b0001100001100010);
+        let data = compact(&word);
+        assert_eq!(from_compact(&data), Some(word));
+    }
+
+    #[test]
+    fn test_serde_word_compact_fail_sync() {
+        let data = compact(&DataWord::from(0b0110100001101001));
+        assert_eq!(from_compact::<CommandWord>(&data), None);
+        assert_eq!(from_compact::<StatusWord>(&data), None);
+
+        let data = compact(&StatusWord::from(0b0001100001100010));
+        assert_eq!(from_compact::<DataWord>(&data), None);
+    }
+
+    #[test]
+    fn test_serde_word_compact_fail_parity() {
+        let word = DataWord::from(0b0110100001101001).with_parity(1);
+        let data = compact(&word);
+        assert_eq!(from_compact::<DataWord>(&data), None);
+    }
+
+    #[test]
+    fn test_serde_word_type() {
+        let word = WordType::Data(DataWord::from(0b0000000000000011));
+        let value = json(&word);
+        assert_eq!(value, json!({ "Data": { "value": 3, "parity": 1 } }));
+        assert_eq!(from_json::<WordType>(value).unwrap(), word);
+        assert_eq!(
+            from_json::<WordType>(json!("None")).unwrap(),
+            WordType::None
+        );
+
+        let data = compact(&word);
+        assert_eq!(from_compact(&data), Some(word));
+    }
+
+    #[test]
+    fn test_serde_packet() {
+        let packet = Packet::new(Packet::SERV_SYNC, [0b01000000, 0b00100000], 1);
+
+        let value = json(&packet);
+        assert_eq!(value, json!({ "sync": 4, "body": [64, 32], "parity": 1 }));
+        assert_eq!(from_json::<Packet>(value).unwrap(), packet);
+
+        let data = compact(&packet);
+        assert_eq!(from_compact(&data), Some(packet));
+    }
+
+    #[test]
+    fn test_serde_packet_fail_invalid() {
+        let value = json!({ "sync": 5, "body": [64, 32], "parity": 1 });
+        assert!(from_json::<Packet>(value).is_err());
+
+        let value = json!({ "sync": 4, "body": [64, 32], "parity": 0 });
+        assert!(from_json::<Packet>(value).is_err());
+
+        let data = compact(&Packet::new(Packet::DATA_SYNC, [0, 0], 0));
+        assert_eq!(from_compact::<Packet>(&data), None);
+    }
+
+    #[test]
+    fn test_serde_message() {
+        let message = Message::<3>::new()
+            .with_command(
+                CommandWord::new()
+                    .with_address(Address::Value(12))
+                    .with_subaddress(SubAddress::Value(5))
+                    .with_word_count(2)
+                    .with_calculated_parity(),
+            )
+            .with_data(0b0000000000000001)
+            .with_data(0b0000000000000011);
+
+        let value = json(&message);
+        assert_eq!(value.as_array().map(Vec::len), Some(3));
+        assert_eq!(value[2], json!({ "Data": { "value": 3, "parity": 1 } }));
+        assert_eq!(from_json::<Message<3>>(value).unwrap(), message);
+
+        let data = compact(&message);
+        assert_eq!(from_compact(&data), Some(message));
+    }
+
+    #[test]
+    fn test_serde_message_partial() {
+        let message = Message::<4>::new().with_status(0b0000100000000000);
+        let value = json(&message);
+        assert_eq!(from_json::<Message<4>>(value).unwrap(), message);
+    }
+
+    #[test]
+    fn test_serde_message_fail_too_long() {
+        let message = Message::<3>::new()
+            .with_status(0b0000100000000000)
+            .with_data(0b0000000000000001)
+            .with_data(0b0000000000000011);
+
+        assert!(from_json::<Message<2>>(json(&message)).is_err());
+        assert_eq!(from_compact::<Message<2>>(&compact(&message)), None);
+    }
+
+    #[test]
+    fn test_serde_message_fail_none() {
+        let value = json!([{ "Data": { "value": 3, "parity": 1 } }, "None"]);
+        assert!(from_json::<Message<3>>(value).is_err());
+    }
+
+    #[test]
+    fn test_serde_message_fail_data_first() {
+        let command = CommandWord::new()
+            .with_address(Address::Value(12))
+            .with_subaddress(SubAddress::Value(5))
+            .with_word_count(1)
+            .with_calculated_parity();
+
+        let data = DataWord::new().with_value(3).with_calculated_parity();
+        let value = json!([
+            json(&WordType::Data(data)),
+            json(&WordType::Command(command))
+        ]);
+        assert!(from_json::<Message<3>>(value).is_err());
+    }
+
+    #[test]
+    fn test_serde_message_fail_two_headers() {
+        let command = CommandWord::new()
+            .with_address(Address::Value(12))
+            .with_subaddress(SubAddress::Value(5))
+            .with_word_count(1)
+            .with_calculated_parity();
+
+        let value = json!([
+            json(&WordType::Command(command)),
+            json(&WordType::Command(command))
+        ]);
+        assert!(from_json::<Message<3>>(value).is_err());
+    }
+
+    #[test]
+    fn test_serde_message_fail_word_count() {
+        let command = CommandWord::new()
+            .with_address(Address::Value(12))
+            .with_subaddress(SubAddress::Value(5))
+            .with_word_count(2)
+            .with_calculated_parity();
+
+        let message = Message::<3>::new().with_command(command).with_data(1);
+        assert!(from_json::<Message<3>>(json(&message)).is_err());
+        assert_eq!(from_compact::<Message<3>>(&compact(&message)), None);
+    }
+
+    #[test]
+    fn test_serde_fail_duplicate_field() {
+        let value = r#"{ "value": 3, "value": 2, "parity": 1 }"#;
+        let error = serde_json::from_str::<DataWord>(value).unwrap_err();
+        assert!(std::format!("{}", error).contains("duplicate field `value`"));
+
+        let value = r#"{ "sync": 1, "body": [0, 3], "parity": 1, "parity": 1 }"#;
+        assert!(serde_json::from_str::<Packet>(value).is_err());
+    }
+
+    #[test]
+    fn test_serde_raw() {
+        let command = CommandWord::new()
+            .with_address(Address::Value(12))
+            .with_subaddress(SubAddress::Value(5))
+            .with_word_count(2)
+            .with_calculated_parity();
+
+        let value = raw::serialize(&command, serde_json::value::Serializer).unwrap();
+        assert!(value.is_u64());
+        assert_eq!(raw::deserialize::<CommandWord, _>(value).unwrap(), command);
+
+        let data = DataWord::new().with_value(3).with_calculated_parity();
+        let value = raw::serialize(&data, serde_json::value::Serializer).unwrap();
+        assert_eq!(value, json!(0x0002_0007));
+        assert_eq!(raw::deserialize::<DataWord, _>(value).unwrap(), data);
+    }
+
+    #[test]
+    fn test_serde_raw_fail_sync() {
+        let data = DataWord::new().with_value(3).with_calculated_parity();
+        let value = raw::serialize(&data, serde_json::value::Serializer).unwrap();
+        assert!(raw::deserialize::<CommandWord, _>(value).is_err());
+    }
+}
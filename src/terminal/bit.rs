@@ -0,0 +1,403 @@
+use crate::fields::Field;
+use crate::flags::{Address, ModeCode};
+use crate::message::Transfer;
+use crate::word::{CommandWord, DataWord};
+use crate::Word;
+
+/// A named fault bit (or group of bits) in a Built-In-Test word
+///
+/// The layout of a BIT word is specific to each terminal, so
+/// faults are declared as constants alongside the ICD.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct BitFault {
+    /// The name of the fault
+    name: &'static str,
+
+    /// The bits of the BIT word that report the fault
+    field: Field,
+}
+
+impl BitFault {
+    /// Create a new fault given a name and mask
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the fault
+    /// * `mask` - The bits of the BIT word that report the fault
+    ///
+    pub const fn new(name: &'static str, mask: u16) -> Self {
+        Self {
+            name,
+            field: Field::from(mask),
+        }
+    }
+
+    /// Get the name of the fault
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the bits of the BIT word that report the fault
+    pub const fn field(&self) -> Field {
+        self.field
+    }
+
+    /// Check if the fault is reported by a data word
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - A BIT word sent by a terminal
+    ///
+    #[must_use = "Returned value is not used"]
+    pub fn is_set(&self, word: &DataWord) -> bool {
+        self.field.read(word) != 0
+    }
+}
+
+/// A Built-In-Test (BIT) word with user-defined fault bits
+///
+/// Terminals send the BIT word in response to the
+/// [TransmitBITWord](ModeCode::TransmitBITWord) mode code, usually
+/// after being told to test themselves with the
+/// [InitiateSelfTest](ModeCode::InitiateSelfTest) mode code.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     const FAULTS: &[BitFault] = &[
+///         BitFault::new("ram", 0b0001),
+///         BitFault::new("rom", 0b0010),
+///         BitFault::new("transceiver", 0b1100),
+///     ];
+///
+///     let [initiate, request] = BitWord::transfers(Address::Value(5));
+///     assert_eq!(initiate.command().mode_code(), ModeCode::InitiateSelfTest);
+///     assert_eq!(request.command().mode_code(), ModeCode::TransmitBITWord);
+///
+///     let word = BitWord::from_word(FAULTS, DataWord::from(0b0100u16));
+///     let mut faults = word.faults();
+///
+///     assert_eq!(faults.next().map(BitFault::name), Some("transceiver"));
+///     assert_eq!(faults.next(), None);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct BitWord {
+    /// The fault bits of the word
+    faults: &'static [BitFault],
+
+    /// The value of the word
+    word: DataWord,
+}
+
+impl BitWord {
+    /// Create a new BIT word with no faults set
+    ///
+    /// # Arguments
+    ///
+    /// * `faults` - The fault bits of the word
+    ///
+    pub fn new(faults: &'static [BitFault]) -> Self {
+        Self::from_word(faults, DataWord::new())
+    }
+
+    /// Create a BIT word from a data word sent by a terminal
+    ///
+    /// # Arguments
+    ///
+    /// * `faults` - The fault bits of the word
+    /// * `word` - The data word
+    ///
+    pub fn from_word(faults: &'static [BitFault], word: DataWord) -> Self {
+        Self { faults, word }
+    }
+
+    /// Build the transfers that run a self test and request the BIT word
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal
+    ///
+    pub fn transfers(address: Address) -> [Transfer; 2] {
+        [
+            Transfer::mode_code(address, ModeCode::InitiateSelfTest),
+            Transfer::mode_code(address, ModeCode::TransmitBITWord),
+        ]
+    }
+
+    /// Constructor method to set a fault
+    ///
+    /// # Arguments
+    ///
+    /// * `fault` - The fault to set
+    ///
+    pub fn with_fault(mut self, fault: &BitFault) -> Self {
+        self.set_fault(fault, true);
+        self
+    }
+
+    /// Set or clear a fault
+    ///
+    /// Every bit of the fault is set or cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `fault` - The fault to set or clear
+    /// * `value` - True if the fault should be set
+    ///
+    pub fn set_fault(&mut self, fault: &BitFault, value: bool) {
+        let bits = match value {
+            true => u16::MAX,
+            false => 0,
+        };
+        fault.field.write(&mut self.word, bits);
+    }
+
+    /// Find a fault of the word by name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the fault
+    ///
+    pub fn fault(&self, name: &str) -> Option<&'static BitFault> {
+        self.faults.iter().find(|f| f.name == name)
+    }
+
+    /// Check if a fault is set
+    ///
+    /// # Arguments
+    ///
+    /// * `fault` - The fault to check
+    ///
+    #[must_use = "Returned value is not used"]
+    pub fn has_fault(&self, fault: &BitFault) -> bool {
+        fault.is_set(&self.word)
+    }
+
+    /// Get an iterator over the faults that are set
+    pub fn faults(&self) -> impl Iterator<Item = &'static BitFault> + '_ {
+        self.faults.iter().filter(|f| f.is_set(&self.word))
+    }
+
+    /// Check if no faults are set
+    #[must_use = "Returned value is not used"]
+    pub fn is_healthy(&self) -> bool {
+        self.faults().next().is_none()
+    }
+
+    /// Get the data word to send, with parity calculated
+    pub fn word(&self) -> DataWord {
+        self.word.with_calculated_parity()
+    }
+}
+
+impl From<BitWord> for DataWord {
+    fn from(value: BitWord) -> Self {
+        value.word()
+    }
+}
+
+/// Self test logic for a remote terminal
+///
+/// Runs a self test callback when the terminal receives the
+/// [InitiateSelfTest](ModeCode::InitiateSelfTest) mode code, and
+/// latches the result until the next test. The latched BIT word
+/// is sent in response to the [TransmitBITWord](ModeCode::TransmitBITWord)
+/// mode code.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     const RAM: BitFault = BitFault::new("ram", 0b0001);
+///
+///     let mut test = SelfTest::new(&[RAM]);
+///     let [initiate, request] = BitWord::transfers(Address::Value(5));
+///
+///     assert_eq!(test.handle(initiate.command(), |w| w.set_fault(&RAM, true)), None);
+///
+///     let word = test.handle(request.command(), |_| ()).unwrap();
+///     assert_eq!(word.as_value(), 0b0001);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct SelfTest {
+    /// The result of the last self test
+    result: BitWord,
+}
+
+impl SelfTest {
+    /// Create self test logic with a healthy result latched
+    ///
+    /// # Arguments
+    ///
+    /// * `faults` - The fault bits of the BIT word
+    ///
+    pub fn new(faults: &'static [BitFault]) -> Self {
+        Self {
+            result: BitWord::new(faults),
+        }
+    }
+
+    /// Run a self test and latch the result
+    ///
+    /// The callback is given a BIT word with no faults set,
+    /// and sets the faults that it finds.
+    ///
+    /// # Arguments
+    ///
+    /// * `test` - The self test callback
+    ///
+    pub fn initiate<F: FnOnce(&mut BitWord)>(&mut self, test: F) {
+        let mut result = BitWord::new(self.result.faults);
+        test(&mut result);
+        self.result = result;
+    }
+
+    /// Get the latched result of the last self test
+    pub fn result(&self) -> &BitWord {
+        &self.result
+    }
+
+    /// Handle a command received by the terminal
+    ///
+    /// Runs the self test for InitiateSelfTest commands, and
+    /// returns the latched BIT word for TransmitBITWord commands.
+    /// Other commands are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word received
+    /// * `test` - The self test callback
+    ///
+    pub fn handle<F: FnOnce(&mut BitWord)>(
+        &mut self,
+        command: &CommandWord,
+        test: F,
+    ) -> Option<DataWord> {
+        if !command.is_mode_code() {
+            return None;
+        }
+        match command.mode_code() {
+            ModeCode::InitiateSelfTest => {
+                self.initiate(test);
+                None
+            }
+            ModeCode::TransmitBITWord => Some(self.result.word()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::SubAddress;
+
+    const RAM: BitFault = BitFault::new("ram", 0b0001);
+    const ROM: BitFault = BitFault::new("rom", 0b0010);
+    const BUS: BitFault = BitFault::new("bus", 0b1100);
+    const FAULTS: &[BitFault] = &[RAM, ROM, BUS];
+
+    #[test]
+    fn test_bit_fault() {
+        assert_eq!(BUS.name(), "bus");
+        assert_eq!(BUS.field().mask(), 0b1100);
+        assert!(BUS.is_set(&DataWord::from(0b1000u16)));
+        assert!(!BUS.is_set(&DataWord::from(0b0011u16)));
+    }
+
+    #[test]
+    fn test_bit_word_new() {
+        let word = BitWord::new(FAULTS);
+        assert!(word.is_healthy());
+        assert_eq!(word.word().as_value(), 0);
+        assert!(word.word().check_parity());
+    }
+
+    #[test]
+    fn test_bit_word_set_fault() {
+        let mut word = BitWord::new(FAULTS).with_fault(&BUS).with_fault(&RAM);
+        assert_eq!(word.word().as_value(), 0b1101);
+        assert!(word.has_fault(&BUS));
+        assert!(!word.has_fault(&ROM));
+
+        word.set_fault(&BUS, false);
+        assert_eq!(DataWord::from(word).as_value(), 0b0001);
+    }
+
+    #[test]
+    fn test_bit_word_faults() {
+        let word = BitWord::from_word(FAULTS, DataWord::from(0b0110u16));
+        let names: std::vec::Vec<&str> = word.faults().map(BitFault::name).collect();
+        assert_eq!(names, ["rom", "bus"]);
+        assert!(!word.is_healthy());
+    }
+
+    #[test]
+    fn test_bit_word_fault_by_name() {
+        let word = BitWord::new(FAULTS);
+        assert_eq!(word.fault("rom"), Some(&ROM));
+        assert_eq!(word.fault("cpu"), None);
+    }
+
+    #[test]
+    fn test_bit_word_transfers() {
+        let [initiate, request] = BitWord::transfers(Address::Value(5));
+        assert_eq!(initiate.command().mode_code(), ModeCode::InitiateSelfTest);
+        assert!(initiate.command().is_transmit());
+        assert_eq!(initiate.word_count(), 0);
+        assert_eq!(request.command().mode_code(), ModeCode::TransmitBITWord);
+        assert!(request.command().is_transmit());
+        assert_eq!(request.word_count(), 1);
+    }
+
+    #[test]
+    fn test_self_test_latch() {
+        let mut test = SelfTest::new(FAULTS);
+        assert!(test.result().is_healthy());
+
+        test.initiate(|w| w.set_fault(&ROM, true));
+        assert!(test.result().has_fault(&ROM));
+
+        // each test starts from a healthy word
+        test.initiate(|w| w.set_fault(&RAM, true));
+        assert!(!test.result().has_fault(&ROM));
+        assert!(test.result().has_fault(&RAM));
+    }
+
+    #[test]
+    fn test_self_test_handle() {
+        let mut test = SelfTest::new(FAULTS);
+        let [initiate, request] = BitWord::transfers(Address::Value(5));
+
+        assert_eq!(
+            test.handle(initiate.command(), |w| w.set_fault(&BUS, true)),
+            None
+        );
+
+        let mut ran = false;
+        let word = test.handle(request.command(), |_| ran = true).unwrap();
+        assert!(!ran);
+        assert!(word.check_parity());
+        assert_eq!(word.as_value(), 0b1100);
+    }
+
+    #[test]
+    fn test_self_test_handle_other() {
+        let mut test = SelfTest::new(FAULTS);
+        let command = CommandWord::new()
+            .with_subaddress(SubAddress::Value(3))
+            .with_word_count(19)
+            .with_calculated_parity();
+        assert_eq!(test.handle(&command, |w| w.set_fault(&BUS, true)), None);
+        assert!(test.result().is_healthy());
+    }
+}
@@ -0,0 +1,702 @@
+use crate::errors::{Error, Result};
+use crate::flags::{SubAddress, TransmitReceive, SUBADDRESSES};
+use crate::word::{CommandWord, DataWord};
+use crate::Word;
+
+/// Storage for the data words of messages sent to or from a subaddress
+///
+/// Words of a message are written one at a time and only become
+/// readable once the message is [completed](Buffer::complete), so
+/// the reader never sees a partially written message (except with
+/// [SingleBuffer], which shares one array between both sides).
+/// If a completed message is replaced before it is read, the
+/// buffer is marked as overrun.
+pub trait Buffer {
+    /// Write the next data word of the message being written
+    ///
+    /// Fails if the message doesn't fit in the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The data word to write
+    ///
+    fn write(&mut self, word: DataWord) -> Result<()>;
+
+    /// Finish the message being written and make it readable
+    fn complete(&mut self);
+
+    /// Throw away the message being written
+    fn discard(&mut self);
+
+    /// Read data words into a slice, returning the number of words read
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The slice to copy data words into
+    ///
+    fn read(&mut self, data: &mut [DataWord]) -> usize;
+
+    /// Check if a completed message is waiting to be read
+    #[must_use = "Returned value is not used"]
+    fn is_complete(&self) -> bool;
+
+    /// Check if a completed message was replaced before being read
+    #[must_use = "Returned value is not used"]
+    fn is_overrun(&self) -> bool;
+
+    /// Clear the overrun flag
+    fn clear_overrun(&mut self);
+}
+
+/// A single buffer shared by the writer and the reader
+///
+/// Words are written directly into the readable array, so a
+/// message that is read while being written may be mixed
+/// with the previous message. Reading returns the last
+/// completed message every time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SingleBuffer<const WORDS: usize = 32> {
+    data: [DataWord; WORDS],
+    length: usize,
+    written: usize,
+    complete: bool,
+    overrun: bool,
+}
+
+/// A pair of buffers that swap when a message is completed
+///
+/// Words are written into the inactive buffer, which becomes the
+/// active buffer once the message is complete. Reading returns
+/// the last completed message every time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoubleBuffer<const WORDS: usize = 32> {
+    data: [[DataWord; WORDS]; 2],
+    length: [usize; 2],
+    active: usize,
+    written: usize,
+    complete: bool,
+    overrun: bool,
+}
+
+/// A ring of data words that messages are appended to
+///
+/// Completed messages are queued until they are read, and reading
+/// removes words from the queue. If a new message doesn't fit, the
+/// oldest completed messages are dropped whole and the buffer is
+/// marked as overrun, so the reader never sees part of a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircularBuffer<const WORDS: usize = 64> {
+    data: [DataWord; WORDS],
+    lengths: [usize; WORDS],
+    start: usize,
+    available: usize,
+    first: usize,
+    messages: usize,
+    written: usize,
+    overrun: bool,
+}
+
+impl<const WORDS: usize> SingleBuffer<WORDS> {
+    /// Create a new, empty buffer
+    pub fn new() -> Self {
+        Self {
+            data: [DataWord::new(); WORDS],
+            length: 0,
+            written: 0,
+            complete: false,
+            overrun: false,
+        }
+    }
+
+    /// Get the data words of the last completed message
+    pub fn words(&self) -> &[DataWord] {
+        &self.data[..self.length]
+    }
+}
+
+impl<const WORDS: usize> DoubleBuffer<WORDS> {
+    /// Create a new, empty buffer
+    pub fn new() -> Self {
+        Self {
+            data: [[DataWord::new(); WORDS]; 2],
+            length: [0; 2],
+            active: 0,
+            written: 0,
+            complete: false,
+            overrun: false,
+        }
+    }
+
+    /// Get the data words of the last completed message
+    pub fn words(&self) -> &[DataWord] {
+        &self.data[self.active][..self.length[self.active]]
+    }
+
+    /// Get the index of the buffer being written
+    fn inactive(&self) -> usize {
+        1 - self.active
+    }
+}
+
+impl<const WORDS: usize> CircularBuffer<WORDS> {
+    /// Create a new, empty buffer
+    pub fn new() -> Self {
+        Self {
+            data: [DataWord::new(); WORDS],
+            lengths: [0; WORDS],
+            start: 0,
+            available: 0,
+            first: 0,
+            messages: 0,
+            written: 0,
+            overrun: false,
+        }
+    }
+
+    /// Get the number of completed words waiting to be read
+    pub fn available(&self) -> usize {
+        self.available
+    }
+
+    /// Drop the words of the oldest completed message
+    fn drop_oldest(&mut self) {
+        let length = self.lengths[self.first];
+        self.start = (self.start + length) % WORDS;
+        self.available -= length;
+        self.first = (self.first + 1) % WORDS;
+        self.messages -= 1;
+        self.overrun = true;
+    }
+
+    /// Remove words that have been read from the queued messages
+    fn consume(&mut self, mut count: usize) {
+        while count > 0 {
+            let length = &mut self.lengths[self.first];
+            let taken = count.min(*length);
+            *length -= taken;
+            count -= taken;
+
+            if *length == 0 {
+                self.first = (self.first + 1) % WORDS;
+                self.messages -= 1;
+            }
+        }
+    }
+}
+
+impl<const WORDS: usize> Buffer for SingleBuffer<WORDS> {
+    fn write(&mut self, word: DataWord) -> Result<()> {
+        let slot = self.data.get_mut(self.written).ok_or(Error::OutOfBounds)?;
+        *slot = word;
+        self.written += 1;
+        Ok(())
+    }
+
+    fn complete(&mut self) {
+        self.overrun |= self.complete;
+        self.complete = true;
+        self.length = self.written;
+        self.written = 0;
+    }
+
+    fn discard(&mut self) {
+        self.written = 0;
+    }
+
+    fn read(&mut self, data: &mut [DataWord]) -> usize {
+        self.complete = false;
+        copy(self.words(), data)
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn is_overrun(&self) -> bool {
+        self.overrun
+    }
+
+    fn clear_overrun(&mut self) {
+        self.overrun = false;
+    }
+}
+
+impl<const WORDS: usize> Buffer for DoubleBuffer<WORDS> {
+    fn write(&mut self, word: DataWord) -> Result<()> {
+        let index = self.inactive();
+        let slot = self.data[index]
+            .get_mut(self.written)
+            .ok_or(Error::OutOfBounds)?;
+        *slot = word;
+        self.written += 1;
+        Ok(())
+    }
+
+    fn complete(&mut self) {
+        let index = self.inactive();
+        self.overrun |= self.complete;
+        self.complete = true;
+        self.length[index] = self.written;
+        self.active = index;
+        self.written = 0;
+    }
+
+    fn discard(&mut self) {
+        self.written = 0;
+    }
+
+    fn read(&mut self, data: &mut [DataWord]) -> usize {
+        self.complete = false;
+        copy(self.words(), data)
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn is_overrun(&self) -> bool {
+        self.overrun
+    }
+
+    fn clear_overrun(&mut self) {
+        self.overrun = false;
+    }
+}
+
+impl<const WORDS: usize> Buffer for CircularBuffer<WORDS> {
+    fn write(&mut self, word: DataWord) -> Result<()> {
+        if self.written >= WORDS {
+            return Err(Error::OutOfBounds);
+        }
+
+        // drop the oldest completed messages to make room
+        while self.available + self.written >= WORDS {
+            self.drop_oldest();
+        }
+
+        let index = (self.start + self.available + self.written) % WORDS;
+        self.data[index] = word;
+        self.written += 1;
+        Ok(())
+    }
+
+    fn complete(&mut self) {
+        if self.written > 0 {
+            self.lengths[(self.first + self.messages) % WORDS] = self.written;
+            self.messages += 1;
+            self.available += self.written;
+        }
+        self.written = 0;
+    }
+
+    fn discard(&mut self) {
+        self.written = 0;
+    }
+
+    fn read(&mut self, data: &mut [DataWord]) -> usize {
+        let count = data.len().min(self.available);
+        for (i, word) in data.iter_mut().take(count).enumerate() {
+            *word = self.data[(self.start + i) % WORDS];
+        }
+        self.start = (self.start + count) % WORDS.max(1);
+        self.available -= count;
+        self.consume(count);
+        count
+    }
+
+    fn is_complete(&self) -> bool {
+        self.available > 0
+    }
+
+    fn is_overrun(&self) -> bool {
+        self.overrun
+    }
+
+    fn clear_overrun(&mut self) {
+        self.overrun = false;
+    }
+}
+
+impl<const WORDS: usize> Default for SingleBuffer<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize> Default for DoubleBuffer<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize> Default for CircularBuffer<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A buffer for each transmit and receive subaddress of a terminal
+///
+/// Receive buffers are written by the terminal as messages arrive
+/// and read by the host, while transmit buffers are written by the
+/// host and read by the terminal when it is commanded to transmit.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let mut buffers: Buffers<DoubleBuffer<32>> = Buffers::new();
+///
+///     let command = CommandWord::new()
+///         .with_subaddress(SubAddress::Value(3))
+///         .with_transmit_receive(TransmitReceive::Receive)
+///         .with_word_count(2)
+///         .build()?;
+///
+///     buffers.store(&command, &[DataWord::from(1u16), DataWord::from(2u16)])?;
+///
+///     let mut data = [DataWord::new(); 32];
+///     assert_eq!(buffers.load(&command, &mut data), 2);
+///     assert_eq!(data[1], DataWord::from(2u16));
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Buffers<B> {
+    buffers: [[B; SUBADDRESSES]; 2],
+}
+
+impl<B: Buffer + Default> Buffers<B> {
+    /// Create a new set of empty buffers
+    pub fn new() -> Self {
+        Self {
+            buffers: core::array::from_fn(|_| core::array::from_fn(|_| B::default())),
+        }
+    }
+}
+
+impl<B: Buffer> Buffers<B> {
+    /// Get the buffer for a subaddress
+    ///
+    /// Returns `None` if the subaddress is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the buffer
+    /// * `subaddress` - The subaddress of the buffer
+    ///
+    pub fn buffer(&self, direction: TransmitReceive, subaddress: SubAddress) -> Option<&B> {
+        subaddress
+            .index()
+            .map(|i| &self.buffers[direction as usize][i])
+    }
+
+    /// Get the mutable buffer for a subaddress
+    ///
+    /// Returns `None` if the subaddress is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the buffer
+    /// * `subaddress` - The subaddress of the buffer
+    ///
+    pub fn buffer_mut(
+        &mut self,
+        direction: TransmitReceive,
+        subaddress: SubAddress,
+    ) -> Option<&mut B> {
+        subaddress
+            .index()
+            .map(|i| &mut self.buffers[direction as usize][i])
+    }
+
+    /// Write and complete a message in the buffer for a command
+    ///
+    /// The message is discarded if it doesn't fit in the buffer,
+    /// and fails if the subaddress of the command is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word of the message
+    /// * `data` - The data words of the message
+    ///
+    pub fn store(&mut self, command: &CommandWord, data: &[DataWord]) -> Result<()> {
+        let buffer = self
+            .buffer_mut(command.transmit_receive(), command.subaddress())
+            .ok_or(Error::OutOfBounds)?;
+        match data.iter().try_for_each(|w| buffer.write(*w)) {
+            Ok(()) => {
+                buffer.complete();
+                Ok(())
+            }
+            Err(e) => {
+                buffer.discard();
+                Err(e)
+            }
+        }
+    }
+
+    /// Read from the buffer for a command, returning the number of words read
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word of the message
+    /// * `data` - The slice to copy data words into
+    ///
+    pub fn load(&mut self, command: &CommandWord, data: &mut [DataWord]) -> usize {
+        self.buffer_mut(command.transmit_receive(), command.subaddress())
+            .map(|b| b.read(data))
+            .unwrap_or(0)
+    }
+}
+
+impl<B: Buffer + Default> Default for Buffers<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Copy words into a slice, returning the number of words copied
+fn copy(words: &[DataWord], data: &mut [DataWord]) -> usize {
+    let count = words.len().min(data.len());
+    data[..count].copy_from_slice(&words[..count]);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(value: u16) -> DataWord {
+        DataWord::from_value(value).with_calculated_parity()
+    }
+
+    fn write<B: Buffer>(buffer: &mut B, values: &[u16]) {
+        for value in values {
+            buffer.write(word(*value)).unwrap();
+        }
+        buffer.complete();
+    }
+
+    #[test]
+    fn test_single_buffer_read() {
+        let mut buffer = SingleBuffer::<4>::new();
+        let mut data = [DataWord::new(); 4];
+        assert_eq!(buffer.read(&mut data), 0);
+
+        write(&mut buffer, &[1, 2, 3]);
+        assert!(buffer.is_complete());
+        assert_eq!(buffer.read(&mut data), 3);
+        assert_eq!(data[..3], [word(1), word(2), word(3)]);
+        assert!(!buffer.is_complete());
+
+        // the last message can be read again
+        assert_eq!(buffer.read(&mut data[..2]), 2);
+    }
+
+    #[test]
+    fn test_single_buffer_shared() {
+        let mut buffer = SingleBuffer::<4>::new();
+        write(&mut buffer, &[1, 2]);
+        buffer.write(word(9)).unwrap();
+        assert_eq!(buffer.words(), &[word(9), word(2)]);
+    }
+
+    #[test]
+    fn test_single_buffer_overrun() {
+        let mut buffer = SingleBuffer::<4>::new();
+        write(&mut buffer, &[1]);
+        assert!(!buffer.is_overrun());
+        write(&mut buffer, &[2]);
+        assert!(buffer.is_overrun());
+        buffer.clear_overrun();
+        assert!(!buffer.is_overrun());
+    }
+
+    #[test]
+    fn test_single_buffer_full() {
+        let mut buffer = SingleBuffer::<2>::new();
+        buffer.write(word(1)).unwrap();
+        buffer.write(word(2)).unwrap();
+        assert_eq!(buffer.write(word(3)), Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_double_buffer_swap() {
+        let mut buffer = DoubleBuffer::<4>::new();
+        write(&mut buffer, &[1, 2]);
+        assert_eq!(buffer.words(), &[word(1), word(2)]);
+
+        // writing doesn't change the readable message until complete
+        buffer.write(word(3)).unwrap();
+        assert_eq!(buffer.words(), &[word(1), word(2)]);
+
+        buffer.complete();
+        assert_eq!(buffer.words(), &[word(3)]);
+    }
+
+    #[test]
+    fn test_double_buffer_discard() {
+        let mut buffer = DoubleBuffer::<4>::new();
+        write(&mut buffer, &[1, 2]);
+        buffer.write(word(3)).unwrap();
+        buffer.discard();
+        write(&mut buffer, &[4]);
+        assert_eq!(buffer.words(), &[word(4)]);
+    }
+
+    #[test]
+    fn test_double_buffer_overrun() {
+        let mut buffer = DoubleBuffer::<4>::new();
+        let mut data = [DataWord::new(); 4];
+        write(&mut buffer, &[1]);
+        assert_eq!(buffer.read(&mut data), 1);
+        write(&mut buffer, &[2]);
+        assert!(!buffer.is_overrun());
+        write(&mut buffer, &[3]);
+        assert!(buffer.is_overrun());
+    }
+
+    #[test]
+    fn test_double_buffer_full() {
+        let mut buffer = DoubleBuffer::<1>::new();
+        buffer.write(word(1)).unwrap();
+        assert_eq!(buffer.write(word(2)), Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_circular_buffer_queue() {
+        let mut buffer = CircularBuffer::<8>::new();
+        let mut data = [DataWord::new(); 8];
+        write(&mut buffer, &[1, 2]);
+        write(&mut buffer, &[3]);
+        buffer.write(word(4)).unwrap();
+        assert_eq!(buffer.available(), 3);
+
+        assert_eq!(buffer.read(&mut data[..2]), 2);
+        assert_eq!(data[..2], [word(1), word(2)]);
+        assert_eq!(buffer.read(&mut data), 1);
+        assert_eq!(data[0], word(3));
+        assert!(!buffer.is_complete());
+    }
+
+    #[test]
+    fn test_circular_buffer_wrap() {
+        let mut buffer = CircularBuffer::<4>::new();
+        let mut data = [DataWord::new(); 4];
+        write(&mut buffer, &[1, 2, 3]);
+        assert_eq!(buffer.read(&mut data), 3);
+        write(&mut buffer, &[4, 5, 6]);
+        assert_eq!(buffer.read(&mut data), 3);
+        assert_eq!(data[..3], [word(4), word(5), word(6)]);
+        assert!(!buffer.is_overrun());
+    }
+
+    #[test]
+    fn test_circular_buffer_overrun() {
+        let mut buffer = CircularBuffer::<4>::new();
+        let mut data = [DataWord::new(); 4];
+        write(&mut buffer, &[1, 2, 3]);
+        write(&mut buffer, &[4, 5]);
+        assert!(buffer.is_overrun());
+        assert_eq!(buffer.read(&mut data), 2);
+        assert_eq!(data[..2], [word(4), word(5)]);
+    }
+
+    #[test]
+    fn test_circular_buffer_overrun_keeps_newer_messages() {
+        let mut buffer = CircularBuffer::<6>::new();
+        let mut data = [DataWord::new(); 6];
+        write(&mut buffer, &[1, 2]);
+        write(&mut buffer, &[3]);
+        write(&mut buffer, &[4, 5]);
+        assert!(!buffer.is_overrun());
+
+        write(&mut buffer, &[6, 7]);
+        assert!(buffer.is_overrun());
+        assert_eq!(buffer.read(&mut data), 5);
+        assert_eq!(data[..5], [word(3), word(4), word(5), word(6), word(7)]);
+    }
+
+    #[test]
+    fn test_circular_buffer_overrun_after_partial_read() {
+        let mut buffer = CircularBuffer::<4>::new();
+        let mut data = [DataWord::new(); 4];
+        write(&mut buffer, &[1, 2, 3]);
+        assert_eq!(buffer.read(&mut data[..1]), 1);
+
+        write(&mut buffer, &[4, 5, 6]);
+        assert!(buffer.is_overrun());
+        assert_eq!(buffer.read(&mut data), 3);
+        assert_eq!(data[..3], [word(4), word(5), word(6)]);
+        assert!(!buffer.is_complete());
+    }
+
+    #[test]
+    fn test_circular_buffer_full() {
+        let mut buffer = CircularBuffer::<2>::new();
+        buffer.write(word(1)).unwrap();
+        buffer.write(word(2)).unwrap();
+        assert_eq!(buffer.write(word(3)), Err(Error::OutOfBounds));
+    }
+
+    #[test]
+    fn test_buffers_store() {
+        let mut buffers: Buffers<SingleBuffer<4>> = Buffers::new();
+        let command = CommandWord::new()
+            .with_subaddress(SubAddress::Value(3))
+            .with_transmit_receive(TransmitReceive::Receive)
+            .with_calculated_parity();
+
+        buffers.store(&command, &[word(1), word(2)]).unwrap();
+        assert!(buffers
+            .buffer(TransmitReceive::Receive, SubAddress::Value(3))
+            .is_some_and(Buffer::is_complete));
+        assert!(!buffers
+            .buffer(TransmitReceive::Transmit, SubAddress::Value(3))
+            .is_some_and(Buffer::is_complete));
+        assert!(!buffers
+            .buffer(TransmitReceive::Receive, SubAddress::Value(4))
+            .is_some_and(Buffer::is_complete));
+    }
+
+    #[test]
+    fn test_buffers_store_fail() {
+        let mut buffers: Buffers<DoubleBuffer<1>> = Buffers::default();
+        let command = CommandWord::new()
+            .with_subaddress(SubAddress::Value(3))
+            .with_calculated_parity();
+
+        buffers.store(&command, &[word(1)]).unwrap();
+        assert_eq!(
+            buffers.store(&command, &[word(2), word(3)]),
+            Err(Error::OutOfBounds)
+        );
+
+        let mut data = [DataWord::new(); 2];
+        assert_eq!(buffers.load(&command, &mut data), 1);
+        assert_eq!(data[0], word(1));
+    }
+
+    #[test]
+    fn test_buffers_subaddress_out_of_range() {
+        let mut buffers: Buffers<SingleBuffer<4>> = Buffers::new();
+        assert!(buffers
+            .buffer(TransmitReceive::Receive, SubAddress::Value(35))
+            .is_none());
+
+        let command = CommandWord::new()
+            .with_subaddress(SubAddress::Value(3))
+            .with_calculated_parity();
+        buffers.store(&command, &[word(1)]).unwrap();
+
+        assert!(buffers
+            .buffer_mut(TransmitReceive::Receive, SubAddress::Value(35))
+            .is_none());
+        assert!(buffers
+            .buffer(TransmitReceive::Receive, SubAddress::Value(3))
+            .is_some_and(Buffer::is_complete));
+    }
+}
@@ -0,0 +1,272 @@
+use crate::flags::{Address, ModeCode};
+use crate::message::Transfer;
+use crate::word::{CommandWord, DataWord, StatusWord};
+use crate::Word;
+
+/// The last valid command word and status word of a remote terminal
+///
+/// Terminals remember the last command they received and the last
+/// status word they sent, so that the bus controller can request
+/// them with the [TransmitLastCommandWord](ModeCode::TransmitLastCommandWord)
+/// and [TransmitStatusWord](ModeCode::TransmitStatusWord) mode codes.
+/// These two mode codes don't update the history, so that they can
+/// be used to diagnose the message before them.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let mut history = History::new();
+///
+///     let command: CommandWord = "05-R-03-02".parse().unwrap();
+///     let status = StatusWord::new().with_address(Address::Value(5)).build()?;
+///     history.handle(&command, status);
+///
+///     let request = History::last_command_transfer(Address::Value(5));
+///     let (_, data) = history.handle(request.command(), status);
+///
+///     assert_eq!(History::decode_last_command(&data.unwrap()), command);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct History {
+    /// The last valid command word received
+    command: Option<CommandWord>,
+
+    /// The last status word sent
+    status: Option<StatusWord>,
+}
+
+impl History {
+    /// Create an empty history
+    pub const fn new() -> Self {
+        Self {
+            command: None,
+            status: None,
+        }
+    }
+
+    /// Get the last valid command word received
+    pub fn last_command(&self) -> Option<&CommandWord> {
+        self.command.as_ref()
+    }
+
+    /// Get the last status word sent
+    pub fn last_status(&self) -> Option<&StatusWord> {
+        self.status.as_ref()
+    }
+
+    /// Clear the history
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Record a command word and the status word sent in response
+    ///
+    /// Commands with a parity error and commands for the
+    /// TransmitStatusWord and TransmitLastCommandWord mode codes
+    /// are not recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word received
+    /// * `status` - The status word sent in response
+    ///
+    pub fn record(&mut self, command: CommandWord, status: StatusWord) {
+        if command.check_parity() && !Self::is_history_request(&command) {
+            self.command = Some(command);
+            self.status = Some(status);
+        }
+    }
+
+    /// Handle a command and get the response to send
+    ///
+    /// For TransmitStatusWord commands, the response is the last
+    /// status word. For TransmitLastCommandWord commands, the
+    /// response is the last status word followed by the last
+    /// command word as a data word. Otherwise the command and
+    /// status word are recorded and the status word is returned.
+    /// If there is no history yet, the given status word is used
+    /// and the command word is sent as a data word of 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word received
+    /// * `status` - The status word the terminal would send
+    ///
+    pub fn handle(
+        &mut self,
+        command: &CommandWord,
+        status: StatusWord,
+    ) -> (StatusWord, Option<DataWord>) {
+        if !Self::is_history_request(command) {
+            self.record(*command, status);
+            return (status, None);
+        }
+
+        let last = self.status.unwrap_or(status);
+        match command.mode_code() {
+            ModeCode::TransmitLastCommandWord => (
+                last,
+                Some(
+                    DataWord::from_value(self.command.map(u16::from).unwrap_or(0))
+                        .with_calculated_parity(),
+                ),
+            ),
+            _ => (last, None),
+        }
+    }
+
+    /// Build the transfer that requests the last status word of a terminal
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal
+    ///
+    pub fn status_transfer(address: Address) -> Transfer {
+        Transfer::mode_code(address, ModeCode::TransmitStatusWord)
+    }
+
+    /// Build the transfer that requests the last command word of a terminal
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal
+    ///
+    pub fn last_command_transfer(address: Address) -> Transfer {
+        Transfer::mode_code(address, ModeCode::TransmitLastCommandWord)
+    }
+
+    /// Decode the data word sent in response to TransmitLastCommandWord
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The data word sent by the terminal
+    ///
+    pub fn decode_last_command(word: &DataWord) -> CommandWord {
+        CommandWord::from_value(word.as_value()).with_calculated_parity()
+    }
+
+    /// Check if a command requests the history of the terminal
+    fn is_history_request(command: &CommandWord) -> bool {
+        command.is_mode_code()
+            && matches!(
+                command.mode_code(),
+                ModeCode::TransmitStatusWord | ModeCode::TransmitLastCommandWord
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::MessageError;
+    use crate::parse::command;
+
+    fn status() -> StatusWord {
+        StatusWord::new()
+            .with_address(Address::Value(5))
+            .with_calculated_parity()
+    }
+
+    #[test]
+    fn test_history_record_fail_parity() {
+        let mut history = History::new();
+        history.record(command("05-R-03-02"), status());
+        history.record(command("05-R-04-02").with_parity(0), status());
+        assert_eq!(history.last_command(), Some(&command("05-R-03-02")));
+    }
+
+    #[test]
+    fn test_history_new() {
+        let history = History::new();
+        assert_eq!(history.last_command(), None);
+        assert_eq!(history.last_status(), None);
+        assert_eq!(History::default(), history);
+    }
+
+    #[test]
+    fn test_history_record() {
+        let mut history = History::new();
+        history.record(command("05-R-03-02"), status());
+        assert_eq!(history.last_command(), Some(&command("05-R-03-02")));
+        assert_eq!(history.last_status(), Some(&status()));
+
+        history.clear();
+        assert_eq!(history.last_command(), None);
+    }
+
+    #[test]
+    fn test_history_record_exceptions() {
+        let mut history = History::new();
+        history.record(command("05-R-03-02"), status());
+
+        let busy = status().with_message_error(MessageError::Error);
+        history.record(*History::status_transfer(Address::Value(5)).command(), busy);
+        history.record(
+            *History::last_command_transfer(Address::Value(5)).command(),
+            busy,
+        );
+
+        assert_eq!(history.last_command(), Some(&command("05-R-03-02")));
+        assert_eq!(history.last_status(), Some(&status()));
+    }
+
+    #[test]
+    fn test_history_handle_status() {
+        let mut history = History::new();
+        let error = status().with_message_error(MessageError::Error);
+        let (response, data) = history.handle(&command("05-R-03-02"), error);
+        assert_eq!(response, error);
+        assert_eq!(data, None);
+
+        let request = History::status_transfer(Address::Value(5));
+        let (response, data) = history.handle(request.command(), status());
+        assert_eq!(response, error);
+        assert_eq!(data, None);
+    }
+
+    #[test]
+    fn test_history_handle_last_command() {
+        let mut history = History::new();
+        let _ = history.handle(&command("05-R-03-02"), status());
+
+        let request = History::last_command_transfer(Address::Value(5));
+        let (response, data) = history.handle(request.command(), status());
+        assert_eq!(response, status());
+
+        let data = data.unwrap();
+        assert!(data.check_parity());
+        assert_eq!(History::decode_last_command(&data), command("05-R-03-02"));
+
+        // asking twice returns the same command
+        let (_, data) = history.handle(request.command(), status());
+        assert_eq!(
+            History::decode_last_command(&data.unwrap()),
+            command("05-R-03-02")
+        );
+    }
+
+    #[test]
+    fn test_history_handle_empty() {
+        let mut history = History::new();
+        let request = History::last_command_transfer(Address::Value(5));
+        let (response, data) = history.handle(request.command(), status());
+        assert_eq!(response, status());
+        assert_eq!(data.map(|d| d.as_value()), Some(0));
+    }
+
+    #[test]
+    fn test_history_transfers() {
+        let transfer = History::status_transfer(Address::Value(5));
+        assert!(transfer.command().is_transmit());
+        assert_eq!(transfer.word_count(), 0);
+
+        let transfer = History::last_command_transfer(Address::Value(5));
+        assert!(transfer.command().is_transmit());
+        assert_eq!(transfer.word_count(), 1);
+    }
+}
@@ -0,0 +1,373 @@
+use crate::errors::MessageError;
+use crate::flags::{SubAddress, TransmitReceive, SUBADDRESSES};
+use crate::word::CommandWord;
+
+/// A table of illegal commands for a remote terminal
+///
+/// Remote terminals are configured with the combinations of
+/// transmit/receive bit, subaddress and word count that they
+/// support, and respond to any other command by setting the
+/// message error bit of their status word. For mode code
+/// subaddresses, the word count field holds the mode code.
+///
+/// The table stores one bit per combination, and every
+/// command is legal until it is made illegal. The builder
+/// methods are const so that a table can be declared as
+/// a constant.
+///
+/// A word count of 32 is the same as a word count of 0, as it
+/// is in the command word. Subaddresses above 31 and counts
+/// above 32 can't be sent in a command word, so they are
+/// ignored by the builder methods and are always legal.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     const TABLE: Illegalization = Illegalization::new()
+///         .with_illegal_subaddress(TransmitReceive::Transmit, SubAddress::Value(2))
+///         .with_illegal(TransmitReceive::Receive, SubAddress::Value(3), 32);
+///
+///     let command = CommandWord::new()
+///         .with_subaddress(SubAddress::Value(2))
+///         .with_transmit_receive(TransmitReceive::Transmit)
+///         .with_word_count(4)
+///         .build()?;
+///
+///     let status = StatusWord::new()
+///         .with_message_error(TABLE.message_error(&command))
+///         .build()?;
+///
+///     assert!(status.is_error());
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Illegalization {
+    /// Bit masks of illegal word counts, indexed by T/R bit and subaddress
+    table: [[u32; SUBADDRESSES]; 2],
+}
+
+impl Illegalization {
+    /// Create a new table in which every command is legal
+    pub const fn new() -> Self {
+        Self {
+            table: [[0; SUBADDRESSES]; 2],
+        }
+    }
+
+    /// Constructor method to make a command illegal
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the command
+    /// * `subaddress` - The subaddress of the command
+    /// * `count` - The word count or mode code of the command (0 or 32 for 32 words)
+    ///
+    pub const fn with_illegal(
+        mut self,
+        direction: TransmitReceive,
+        subaddress: SubAddress,
+        count: u8,
+    ) -> Self {
+        if let Some(index) = subaddress.index() {
+            self.table[direction as usize][index] |= Self::bit(count);
+        }
+        self
+    }
+
+    /// Constructor method to make a command legal
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the command
+    /// * `subaddress` - The subaddress of the command
+    /// * `count` - The word count or mode code of the command (0 or 32 for 32 words)
+    ///
+    pub const fn with_legal(
+        mut self,
+        direction: TransmitReceive,
+        subaddress: SubAddress,
+        count: u8,
+    ) -> Self {
+        if let Some(index) = subaddress.index() {
+            self.table[direction as usize][index] &= !Self::bit(count);
+        }
+        self
+    }
+
+    /// Constructor method to make every command for a subaddress illegal
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the commands
+    /// * `subaddress` - The subaddress of the commands
+    ///
+    pub const fn with_illegal_subaddress(
+        mut self,
+        direction: TransmitReceive,
+        subaddress: SubAddress,
+    ) -> Self {
+        if let Some(index) = subaddress.index() {
+            self.table[direction as usize][index] = u32::MAX;
+        }
+        self
+    }
+
+    /// Constructor method to make every command for a subaddress legal
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the commands
+    /// * `subaddress` - The subaddress of the commands
+    ///
+    pub const fn with_legal_subaddress(
+        mut self,
+        direction: TransmitReceive,
+        subaddress: SubAddress,
+    ) -> Self {
+        if let Some(index) = subaddress.index() {
+            self.table[direction as usize][index] = 0;
+        }
+        self
+    }
+
+    /// Make a command illegal
+    ///
+    /// See [with_illegal](Self::with_illegal) for more information.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the command
+    /// * `subaddress` - The subaddress of the command
+    /// * `count` - The word count or mode code of the command
+    ///
+    pub fn set_illegal(&mut self, direction: TransmitReceive, subaddress: SubAddress, count: u8) {
+        *self = self.with_illegal(direction, subaddress, count);
+    }
+
+    /// Make a command legal
+    ///
+    /// See [with_legal](Self::with_legal) for more information.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the command
+    /// * `subaddress` - The subaddress of the command
+    /// * `count` - The word count or mode code of the command
+    ///
+    pub fn set_legal(&mut self, direction: TransmitReceive, subaddress: SubAddress, count: u8) {
+        *self = self.with_legal(direction, subaddress, count);
+    }
+
+    /// Make every command for a subaddress illegal
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the commands
+    /// * `subaddress` - The subaddress of the commands
+    ///
+    pub fn set_illegal_subaddress(&mut self, direction: TransmitReceive, subaddress: SubAddress) {
+        *self = self.with_illegal_subaddress(direction, subaddress);
+    }
+
+    /// Make every command for a subaddress legal
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the commands
+    /// * `subaddress` - The subaddress of the commands
+    ///
+    pub fn set_legal_subaddress(&mut self, direction: TransmitReceive, subaddress: SubAddress) {
+        *self = self.with_legal_subaddress(direction, subaddress);
+    }
+
+    /// Check if a command is legal
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The transmit/receive bit of the command
+    /// * `subaddress` - The subaddress of the command
+    /// * `count` - The word count or mode code of the command
+    ///
+    #[must_use = "Returned value is not used"]
+    pub const fn is_legal(
+        &self,
+        direction: TransmitReceive,
+        subaddress: SubAddress,
+        count: u8,
+    ) -> bool {
+        match subaddress.index() {
+            Some(index) => self.table[direction as usize][index] & Self::bit(count) == 0,
+            None => true,
+        }
+    }
+
+    /// Check if a command word is legal
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word received by the terminal
+    ///
+    #[must_use = "Returned value is not used"]
+    pub fn is_legal_command(&self, command: &CommandWord) -> bool {
+        self.is_legal(
+            command.transmit_receive(),
+            command.subaddress(),
+            command.word_count(),
+        )
+    }
+
+    /// Get the message error flag to respond to a command word with
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word received by the terminal
+    ///
+    pub fn message_error(&self, command: &CommandWord) -> MessageError {
+        match self.is_legal_command(command) {
+            true => MessageError::None,
+            false => MessageError::Error,
+        }
+    }
+
+    /// Get the bit for a word count, where 0 and 32 are the same
+    ///
+    /// Counts above 32 are out of range and have no bit.
+    const fn bit(count: u8) -> u32 {
+        match count {
+            0..=31 => 1 << count,
+            32 => 1,
+            _ => 0,
+        }
+    }
+}
+
+impl Default for Illegalization {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::ModeCode;
+    use crate::Word;
+
+    const TRANSMIT: TransmitReceive = TransmitReceive::Transmit;
+    const RECEIVE: TransmitReceive = TransmitReceive::Receive;
+
+    #[test]
+    fn test_illegalization_new() {
+        let table = Illegalization::new();
+        for count in 0..=32 {
+            assert!(table.is_legal(TRANSMIT, SubAddress::Value(1), count));
+            assert!(table.is_legal(RECEIVE, SubAddress::ModeCode(0), count));
+        }
+    }
+
+    #[test]
+    fn test_illegalization_default() {
+        assert_eq!(Illegalization::default(), Illegalization::new());
+    }
+
+    #[test]
+    fn test_illegalization_with_illegal() {
+        let table = Illegalization::new().with_illegal(RECEIVE, SubAddress::Value(3), 4);
+        assert!(!table.is_legal(RECEIVE, SubAddress::Value(3), 4));
+        assert!(table.is_legal(RECEIVE, SubAddress::Value(3), 5));
+        assert!(table.is_legal(TRANSMIT, SubAddress::Value(3), 4));
+        assert!(table.is_legal(RECEIVE, SubAddress::Value(4), 4));
+    }
+
+    #[test]
+    fn test_illegalization_word_count_32() {
+        let table = Illegalization::new().with_illegal(RECEIVE, SubAddress::Value(3), 32);
+        assert!(!table.is_legal(RECEIVE, SubAddress::Value(3), 0));
+        assert!(!table.is_legal(RECEIVE, SubAddress::Value(3), 32));
+    }
+
+    #[test]
+    fn test_illegalization_out_of_range() {
+        let table = Illegalization::new()
+            .with_illegal(RECEIVE, SubAddress::Value(3), 33)
+            .with_illegal(RECEIVE, SubAddress::Value(3), 40)
+            .with_illegal(RECEIVE, SubAddress::Value(3), u8::MAX)
+            .with_illegal_subaddress(RECEIVE, SubAddress::Value(35));
+        assert_eq!(table, Illegalization::new());
+        assert!(table.is_legal(RECEIVE, SubAddress::Value(35), 1));
+
+        let table = Illegalization::new()
+            .with_illegal_subaddress(RECEIVE, SubAddress::Value(3))
+            .with_legal(RECEIVE, SubAddress::Value(3), 33)
+            .with_legal(RECEIVE, SubAddress::Value(3), 40);
+        assert!((0..=32).all(|c| !table.is_legal(RECEIVE, SubAddress::Value(3), c)));
+        assert!(table.is_legal(RECEIVE, SubAddress::Value(3), 33));
+    }
+
+    #[test]
+    fn test_illegalization_with_legal() {
+        let table = Illegalization::new()
+            .with_illegal_subaddress(TRANSMIT, SubAddress::Value(7))
+            .with_legal(TRANSMIT, SubAddress::Value(7), 2);
+        assert!(table.is_legal(TRANSMIT, SubAddress::Value(7), 2));
+        assert!(!table.is_legal(TRANSMIT, SubAddress::Value(7), 3));
+    }
+
+    #[test]
+    fn test_illegalization_subaddress() {
+        let mut table = Illegalization::new();
+        table.set_illegal_subaddress(TRANSMIT, SubAddress::Value(7));
+        assert!((0..=32).all(|c| !table.is_legal(TRANSMIT, SubAddress::Value(7), c)));
+
+        table.set_legal_subaddress(TRANSMIT, SubAddress::Value(7));
+        assert_eq!(table, Illegalization::new());
+    }
+
+    #[test]
+    fn test_illegalization_set() {
+        let mut table = Illegalization::new();
+        table.set_illegal(TRANSMIT, SubAddress::Value(1), 1);
+        assert!(!table.is_legal(TRANSMIT, SubAddress::Value(1), 1));
+
+        table.set_legal(TRANSMIT, SubAddress::Value(1), 1);
+        assert!(table.is_legal(TRANSMIT, SubAddress::Value(1), 1));
+    }
+
+    #[test]
+    fn test_illegalization_mode_code() {
+        let table = Illegalization::new().with_illegal(
+            TRANSMIT,
+            SubAddress::ModeCode(31),
+            ModeCode::TransmitBITWord.value(),
+        );
+
+        let command = CommandWord::new()
+            .with_subaddress(SubAddress::ModeCode(31))
+            .with_transmit_receive(TRANSMIT)
+            .with_mode_code(ModeCode::TransmitBITWord)
+            .with_calculated_parity();
+
+        assert!(!table.is_legal_command(&command));
+        assert!(table.is_legal_command(&command.with_subaddress(SubAddress::ModeCode(0))));
+    }
+
+    #[test]
+    fn test_illegalization_message_error() {
+        let table = Illegalization::new().with_illegal(RECEIVE, SubAddress::Value(3), 4);
+        let command = CommandWord::new()
+            .with_subaddress(SubAddress::Value(3))
+            .with_transmit_receive(RECEIVE)
+            .with_word_count(4)
+            .with_calculated_parity();
+
+        assert_eq!(table.message_error(&command), MessageError::Error);
+        assert_eq!(
+            table.message_error(&command.with_word_count(5)),
+            MessageError::None
+        );
+    }
+}
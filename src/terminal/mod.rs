@@ -0,0 +1,15 @@
+//! Logic for remote terminals
+
+mod bit;
+mod buffers;
+mod history;
+mod illegal;
+mod sync;
+mod transmitters;
+
+pub use bit::{BitFault, BitWord, SelfTest};
+pub use buffers::{Buffer, Buffers, CircularBuffer, DoubleBuffer, SingleBuffer};
+pub use history::History;
+pub use illegal::Illegalization;
+pub use sync::TimeTag;
+pub use transmitters::{TransmitterSelect, Transmitters};
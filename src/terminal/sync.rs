@@ -0,0 +1,286 @@
+use crate::flags::{Address, ModeCode};
+use crate::message::{Message, Transfer};
+use crate::word::{CommandWord, DataWord};
+use crate::Word;
+
+/// A time-tag counter that is set by synchronize mode codes
+///
+/// Terminals count time in units of the resolution (in
+/// microseconds) with a 16-bit counter that wraps around. The
+/// [Synchronize](ModeCode::Synchronize) mode code resets the
+/// counter, and the [SynchronizeWithDataWord](ModeCode::SynchronizeWithDataWord)
+/// mode code loads the value of its data word. The difference
+/// between the loaded value and the local count is kept as the
+/// drift of the local clock.
+///
+/// The same resolution is used by the bus controller to build
+/// synchronize messages from a time value.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let bc = TimeTag::new(64);
+///     let mut rt = TimeTag::new(64);
+///
+///     rt.tick(6_400);
+///     assert_eq!(rt.count(), 100);
+///
+///     let message = bc.sync_message(Address::Value(5), 6_464);
+///     rt.handle(message.command().unwrap(), message.at(0));
+///
+///     assert_eq!(rt.count(), 101);
+///     assert_eq!(rt.drift(), Some(1));
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeTag {
+    /// The number of microseconds per count
+    resolution: u32,
+
+    /// The current count
+    count: u16,
+
+    /// Microseconds that haven't made up a full count
+    remainder: u32,
+
+    /// The drift found by the last synchronize mode code
+    drift: Option<i16>,
+}
+
+impl TimeTag {
+    /// Create a new time-tag counter
+    ///
+    /// A resolution of zero is treated as one microsecond.
+    ///
+    /// # Arguments
+    ///
+    /// * `resolution` - The number of microseconds per count
+    ///
+    pub fn new(resolution: u32) -> Self {
+        Self {
+            resolution: resolution.max(1),
+            count: 0,
+            remainder: 0,
+            drift: None,
+        }
+    }
+
+    /// Get the number of microseconds per count
+    pub fn resolution(&self) -> u32 {
+        self.resolution
+    }
+
+    /// Get the current count
+    pub fn count(&self) -> u16 {
+        self.count
+    }
+
+    /// Get the drift found by the last synchronize mode code
+    ///
+    /// The drift is the synchronized value minus the local
+    /// count, in counts, so a positive drift means the local
+    /// clock was slow. Synchronize without a data word counts
+    /// as synchronizing to zero.
+    pub fn drift(&self) -> Option<i16> {
+        self.drift
+    }
+
+    /// Convert a time in microseconds to a count
+    ///
+    /// The count wraps around if the time is too large.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time in microseconds
+    ///
+    pub fn to_count(&self, time: u64) -> u16 {
+        (time / u64::from(self.resolution)) as u16
+    }
+
+    /// Advance the counter by some amount of time
+    ///
+    /// # Arguments
+    ///
+    /// * `elapsed` - The time since the last tick in microseconds
+    ///
+    pub fn tick(&mut self, elapsed: u32) {
+        let total = u64::from(self.remainder) + u64::from(elapsed);
+        let counts = total / u64::from(self.resolution);
+        self.remainder = (total % u64::from(self.resolution)) as u32;
+        self.count = self.count.wrapping_add(counts as u16);
+    }
+
+    /// Load a new value into the counter and record the drift
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The synchronized count
+    ///
+    pub fn synchronize(&mut self, value: u16) {
+        self.drift = Some(value.wrapping_sub(self.count) as i16);
+        self.count = value;
+        self.remainder = 0;
+    }
+
+    /// Handle a command received by the terminal
+    ///
+    /// Synchronize commands reset the counter, and synchronize
+    /// commands with a data word load the data word. Other
+    /// commands, and synchronize commands that are missing
+    /// their data word, are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word received
+    /// * `data` - The data word of the message, if any
+    ///
+    pub fn handle(&mut self, command: &CommandWord, data: Option<&DataWord>) {
+        if !command.is_mode_code() {
+            return;
+        }
+        match (command.mode_code(), data) {
+            (ModeCode::Synchronize, _) => self.synchronize(0),
+            (ModeCode::SynchronizeWithDataWord, Some(word)) => self.synchronize(word.as_value()),
+            _ => (),
+        }
+    }
+
+    /// Build a synchronize transfer that resets the counter of a terminal
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal (or broadcast)
+    ///
+    pub fn sync_transfer(address: Address) -> Transfer {
+        Transfer::mode_code(address, ModeCode::Synchronize)
+    }
+
+    /// Build a synchronize message that loads a time into the counter of a terminal
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal (or broadcast)
+    /// * `time` - The time to load in microseconds
+    ///
+    pub fn sync_message(&self, address: Address, time: u64) -> Message<2> {
+        let transfer = Transfer::mode_code(address, ModeCode::SynchronizeWithDataWord);
+        Message::new()
+            .with_command(*transfer.command())
+            .with_data(DataWord::from_value(self.to_count(time)).with_calculated_parity())
+    }
+}
+
+impl Default for TimeTag {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::SubAddress;
+
+    #[test]
+    fn test_time_tag_new() {
+        let tag = TimeTag::new(0);
+        assert_eq!(tag.resolution(), 1);
+        assert_eq!(tag.count(), 0);
+        assert_eq!(tag.drift(), None);
+        assert_eq!(TimeTag::default(), tag);
+    }
+
+    #[test]
+    fn test_time_tag_tick() {
+        let mut tag = TimeTag::new(4);
+        tag.tick(10);
+        assert_eq!(tag.count(), 2);
+        tag.tick(2);
+        assert_eq!(tag.count(), 3);
+    }
+
+    #[test]
+    fn test_time_tag_tick_wrap() {
+        let mut tag = TimeTag::new(1);
+        tag.synchronize(u16::MAX);
+        tag.tick(2);
+        assert_eq!(tag.count(), 1);
+    }
+
+    #[test]
+    fn test_time_tag_to_count() {
+        let tag = TimeTag::new(64);
+        assert_eq!(tag.to_count(640), 10);
+        assert_eq!(tag.to_count(63), 0);
+        assert_eq!(tag.to_count(64 * 65537), 1);
+    }
+
+    #[test]
+    fn test_time_tag_synchronize_drift() {
+        let mut tag = TimeTag::new(1);
+        tag.tick(100);
+        tag.synchronize(90);
+        assert_eq!(tag.drift(), Some(-10));
+        assert_eq!(tag.count(), 90);
+
+        tag.synchronize(u16::MAX);
+        tag.tick(3);
+        tag.synchronize(5);
+        assert_eq!(tag.drift(), Some(3));
+    }
+
+    #[test]
+    fn test_time_tag_handle_synchronize() {
+        let mut tag = TimeTag::new(1);
+        tag.tick(12);
+        tag.handle(TimeTag::sync_transfer(Address::Value(5)).command(), None);
+        assert_eq!(tag.count(), 0);
+        assert_eq!(tag.drift(), Some(-12));
+    }
+
+    #[test]
+    fn test_time_tag_handle_with_data() {
+        let mut tag = TimeTag::new(2);
+        let message = TimeTag::new(2).sync_message(Address::Value(5), 1000);
+        assert_eq!(message.violations().next(), None);
+
+        tag.handle(message.command().unwrap(), message.at(0));
+        assert_eq!(tag.count(), 500);
+        assert_eq!(tag.drift(), Some(500));
+    }
+
+    #[test]
+    fn test_time_tag_handle_missing_data() {
+        let mut tag = TimeTag::new(1);
+        tag.tick(7);
+        let message = TimeTag::new(1).sync_message(Address::Value(5), 1000);
+        tag.handle(message.command().unwrap(), None);
+        assert_eq!(tag.count(), 7);
+        assert_eq!(tag.drift(), None);
+    }
+
+    #[test]
+    fn test_time_tag_handle_other() {
+        let mut tag = TimeTag::new(1);
+        tag.tick(7);
+        let command = CommandWord::new()
+            .with_subaddress(SubAddress::Value(1))
+            .with_word_count(1)
+            .with_calculated_parity();
+        tag.handle(&command, Some(&DataWord::from(3u16)));
+        assert_eq!(tag.count(), 7);
+    }
+
+    #[test]
+    fn test_time_tag_sync_transfer() {
+        let transfer = TimeTag::sync_transfer(Address::from(31u8));
+        assert!(transfer.is_broadcast());
+        assert!(transfer.command().is_transmit());
+        assert_eq!(transfer.command().mode_code(), ModeCode::Synchronize);
+        assert_eq!(transfer.word_count(), 0);
+    }
+}
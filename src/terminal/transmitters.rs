@@ -0,0 +1,381 @@
+use crate::errors::{Error, Result};
+use crate::flags::{Address, ModeCode};
+use crate::message::{Message, Transfer};
+use crate::word::{CommandWord, DataWord};
+use crate::Word;
+
+/// The data word of a selected transmitter shutdown mode code
+///
+/// The [SelectedTransmitterShutdown](ModeCode::SelectedTransmitterShutdown)
+/// and [OverrideSelectedTransmitterShutdown](ModeCode::OverrideSelectedTransmitterShutdown)
+/// mode codes carry a data word that selects a bus, for terminals
+/// with more than two buses. The bus is selected by its index,
+/// starting from zero, in the value of the data word.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let message = TransmitterSelect::new(2).shutdown_message(Address::Value(5));
+///     let command = message.command().unwrap();
+///
+///     assert_eq!(command.mode_code(), ModeCode::SelectedTransmitterShutdown);
+///     assert_eq!(TransmitterSelect::try_from(message.at(0).unwrap())?.bus(), 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TransmitterSelect {
+    /// The index of the selected bus
+    bus: u8,
+}
+
+impl TransmitterSelect {
+    /// The largest number of buses that can be selected
+    pub const MAX_BUSES: usize = 32;
+
+    /// Create a new selection given a bus index
+    ///
+    /// The index is truncated to the range 0..32.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - The index of the bus to select
+    ///
+    pub const fn new(bus: u8) -> Self {
+        Self {
+            bus: bus % Self::MAX_BUSES as u8,
+        }
+    }
+
+    /// Get the index of the selected bus
+    pub const fn bus(&self) -> u8 {
+        self.bus
+    }
+
+    /// Get the data word to send, with parity calculated
+    pub fn word(&self) -> DataWord {
+        DataWord::from_value(u16::from(self.bus)).with_calculated_parity()
+    }
+
+    /// Build a message that shuts down the selected transmitter of a terminal
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal (or broadcast)
+    ///
+    pub fn shutdown_message(&self, address: Address) -> Message<2> {
+        self.message(address, ModeCode::SelectedTransmitterShutdown)
+    }
+
+    /// Build a message that re-enables the selected transmitter of a terminal
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address of the terminal (or broadcast)
+    ///
+    pub fn override_message(&self, address: Address) -> Message<2> {
+        self.message(address, ModeCode::OverrideSelectedTransmitterShutdown)
+    }
+
+    fn message(&self, address: Address, code: ModeCode) -> Message<2> {
+        let transfer = Transfer::mode_code(address, code);
+        Message::new()
+            .with_command(*transfer.command())
+            .with_data(self.word())
+    }
+}
+
+impl TryFrom<&DataWord> for TransmitterSelect {
+    type Error = Error;
+
+    fn try_from(value: &DataWord) -> Result<Self> {
+        match value.as_value() {
+            v if usize::from(v) < Self::MAX_BUSES => Ok(Self::new(v as u8)),
+            _ => Err(Error::InvalidWord),
+        }
+    }
+}
+
+impl From<TransmitterSelect> for DataWord {
+    fn from(value: TransmitterSelect) -> Self {
+        value.word()
+    }
+}
+
+/// The transmitter enable state of a terminal with several buses
+///
+/// Every transmitter starts enabled. The
+/// [TransmitterShutdown](ModeCode::TransmitterShutdown) mode code
+/// disables the transmitters of every bus other than the one the
+/// command was received on, and the
+/// [OverrideTransmitterShutdown](ModeCode::OverrideTransmitterShutdown)
+/// mode code enables them again. The selected versions of these
+/// mode codes disable or enable the single transmitter chosen by
+/// their [TransmitterSelect] data word, and
+/// [ResetRemoteTerminal](ModeCode::ResetRemoteTerminal) enables
+/// every transmitter.
+///
+/// ## Example
+///
+/// ```rust
+/// # use mil_std_1553b::*;
+/// # fn main() -> Result<()> {
+///     let mut transmitters = Transmitters::<4>::new();
+///
+///     let message = TransmitterSelect::new(3).shutdown_message(Address::Value(5));
+///     transmitters.handle(message.command().unwrap(), message.at(0), 0)?;
+///
+///     assert!(transmitters.is_enabled(0));
+///     assert!(!transmitters.is_enabled(3));
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transmitters<const BUSES: usize = 4> {
+    /// The enable flag of each bus
+    enabled: [bool; BUSES],
+}
+
+impl<const BUSES: usize> Transmitters<BUSES> {
+    /// Create a new state with every transmitter enabled
+    pub const fn new() -> Self {
+        Self {
+            enabled: [true; BUSES],
+        }
+    }
+
+    /// Get the number of buses of the terminal
+    pub const fn buses(&self) -> usize {
+        BUSES
+    }
+
+    /// Check if the transmitter of a bus is enabled
+    ///
+    /// Buses that don't exist are never enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - The index of the bus
+    ///
+    #[must_use = "Returned value is not used"]
+    pub fn is_enabled(&self, bus: usize) -> bool {
+        self.enabled.get(bus).copied().unwrap_or(false)
+    }
+
+    /// Enable or disable the transmitter of a bus
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - The index of the bus
+    /// * `enabled` - True if the transmitter should be enabled
+    ///
+    pub fn set_enabled(&mut self, bus: usize, enabled: bool) -> Result<()> {
+        let flag = self.enabled.get_mut(bus).ok_or(Error::OutOfBounds)?;
+        *flag = enabled;
+        Ok(())
+    }
+
+    /// Enable every transmitter
+    pub fn reset(&mut self) {
+        self.enabled = [true; BUSES];
+    }
+
+    /// Handle a command received by the terminal
+    ///
+    /// Commands other than the transmitter shutdown and reset mode
+    /// codes are ignored. Fails without changing any transmitter if
+    /// a selected mode code is missing its data word, or the data
+    /// word selects a bus that doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command word received
+    /// * `data` - The data word of the message, if any
+    /// * `bus` - The index of the bus the command was received on
+    ///
+    pub fn handle(
+        &mut self,
+        command: &CommandWord,
+        data: Option<&DataWord>,
+        bus: usize,
+    ) -> Result<()> {
+        if !command.is_mode_code() {
+            return Ok(());
+        }
+        match command.mode_code() {
+            ModeCode::TransmitterShutdown => self.set_others(bus, false),
+            ModeCode::OverrideTransmitterShutdown => self.set_others(bus, true),
+            ModeCode::ResetRemoteTerminal => self.reset(),
+            ModeCode::SelectedTransmitterShutdown => {
+                return self.set_selected(data, false);
+            }
+            ModeCode::OverrideSelectedTransmitterShutdown => {
+                return self.set_selected(data, true);
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn set_others(&mut self, bus: usize, enabled: bool) {
+        self.enabled
+            .iter_mut()
+            .enumerate()
+            .filter(|(i, _)| *i != bus)
+            .for_each(|(_, flag)| *flag = enabled);
+    }
+
+    fn set_selected(&mut self, data: Option<&DataWord>, enabled: bool) -> Result<()> {
+        let select = TransmitterSelect::try_from(data.ok_or(Error::InvalidMessage)?)?;
+        self.set_enabled(usize::from(select.bus()), enabled)
+    }
+}
+
+impl<const BUSES: usize> Default for Transmitters<BUSES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::SubAddress;
+    use crate::parse::command;
+
+    #[test]
+    fn test_transmitter_select_word() {
+        let select = TransmitterSelect::new(3);
+        let word = select.word();
+        assert_eq!(word.as_value(), 3);
+        assert!(word.check_parity());
+        assert_eq!(DataWord::from(select), word);
+        assert_eq!(TransmitterSelect::try_from(&word), Ok(select));
+    }
+
+    #[test]
+    fn test_transmitter_select_truncate() {
+        assert_eq!(TransmitterSelect::new(33).bus(), 1);
+    }
+
+    #[test]
+    fn test_transmitter_select_invalid_word() {
+        let word = DataWord::from(32u16);
+        assert_eq!(TransmitterSelect::try_from(&word), Err(Error::InvalidWord));
+    }
+
+    #[test]
+    fn test_transmitter_select_messages() {
+        let message = TransmitterSelect::new(1).shutdown_message(Address::Value(5));
+        let command = message.command().unwrap();
+        assert_eq!(command.mode_code(), ModeCode::SelectedTransmitterShutdown);
+        assert!(command.is_receive());
+        assert_eq!(message.at(0).map(|w| w.as_value()), Some(1));
+
+        let message = TransmitterSelect::new(1).override_message(Address::Value(5));
+        let command = message.command().unwrap();
+        assert_eq!(
+            command.mode_code(),
+            ModeCode::OverrideSelectedTransmitterShutdown
+        );
+        assert!(command.is_receive());
+    }
+
+    #[test]
+    fn test_transmitters_new() {
+        let transmitters = Transmitters::<4>::new();
+        assert_eq!(transmitters.buses(), 4);
+        assert!((0..4).all(|b| transmitters.is_enabled(b)));
+        assert!(!transmitters.is_enabled(4));
+        assert_eq!(Transmitters::default(), transmitters);
+    }
+
+    #[test]
+    fn test_transmitters_set_enabled() {
+        let mut transmitters = Transmitters::<2>::new();
+        assert_eq!(transmitters.set_enabled(1, false), Ok(()));
+        assert!(!transmitters.is_enabled(1));
+        assert_eq!(transmitters.set_enabled(2, false), Err(Error::OutOfBounds));
+
+        transmitters.reset();
+        assert!(transmitters.is_enabled(1));
+    }
+
+    #[test]
+    fn test_transmitters_shutdown() {
+        let mut transmitters = Transmitters::<4>::new();
+        let shutdown = command("05-T-00-TransmitterShutdown");
+        assert_eq!(transmitters.handle(&shutdown, None, 2), Ok(()));
+        assert!(!transmitters.is_enabled(0));
+        assert!(!transmitters.is_enabled(1));
+        assert!(transmitters.is_enabled(2));
+        assert!(!transmitters.is_enabled(3));
+
+        let restart = command("05-T-00-OverrideTransmitterShutdown");
+        assert_eq!(transmitters.handle(&restart, None, 2), Ok(()));
+        assert!((0..4).all(|b| transmitters.is_enabled(b)));
+    }
+
+    #[test]
+    fn test_transmitters_selected() {
+        let mut transmitters = Transmitters::<4>::new();
+        let select = TransmitterSelect::new(1);
+
+        let message = select.shutdown_message(Address::Value(5));
+        assert_eq!(
+            transmitters.handle(message.command().unwrap(), message.at(0), 0),
+            Ok(())
+        );
+        assert!(!transmitters.is_enabled(1));
+        assert!(transmitters.is_enabled(2));
+
+        let message = select.override_message(Address::Value(5));
+        assert_eq!(
+            transmitters.handle(message.command().unwrap(), message.at(0), 0),
+            Ok(())
+        );
+        assert!(transmitters.is_enabled(1));
+    }
+
+    #[test]
+    fn test_transmitters_selected_invalid() {
+        let mut transmitters = Transmitters::<2>::new();
+        let message = TransmitterSelect::new(3).shutdown_message(Address::Value(5));
+        assert_eq!(
+            transmitters.handle(message.command().unwrap(), message.at(0), 0),
+            Err(Error::OutOfBounds)
+        );
+        assert_eq!(
+            transmitters.handle(message.command().unwrap(), None, 0),
+            Err(Error::InvalidMessage)
+        );
+        assert!(transmitters.is_enabled(0) && transmitters.is_enabled(1));
+    }
+
+    #[test]
+    fn test_transmitters_reset() {
+        let mut transmitters = Transmitters::<4>::new();
+        let _ = transmitters.handle(&command("05-T-00-TransmitterShutdown"), None, 0);
+        let _ = transmitters.handle(&command("05-T-00-ResetRemoteTerminal"), None, 0);
+        assert!((0..4).all(|b| transmitters.is_enabled(b)));
+    }
+
+    #[test]
+    fn test_transmitters_other_command() {
+        let mut transmitters = Transmitters::<4>::new();
+        let command = CommandWord::new()
+            .with_subaddress(SubAddress::Value(4))
+            .with_word_count(1)
+            .with_calculated_parity();
+        assert_eq!(
+            transmitters.handle(&command, Some(&DataWord::from(1u16)), 0),
+            Ok(())
+        );
+        assert!((0..4).all(|b| transmitters.is_enabled(b)));
+    }
+}
@@ -101,6 +101,17 @@ impl From<StatusWord> for WordType {
     }
 }
 
+impl core::fmt::Display for WordType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Command(w) => core::fmt::Display::fmt(w, f),
+            Self::Status(w) => core::fmt::Display::fmt(w, f),
+            Self::Data(w) => core::fmt::Display::fmt(w, f),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,21 +119,21 @@ mod tests {
     #[test]
     fn test_wordtype_clone_equal_command() {
         let item1 = WordType::Command(0b1010101010101010.into());
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert!(item1 == item2);
     }
 
     #[test]
     fn test_wordtype_clone_equal_status() {
         let item1 = WordType::Status(0b1010101010101010.into());
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert!(item1 == item2);
     }
 
     #[test]
     fn test_wordtype_clone_equal_data() {
         let item1 = WordType::Data(0b1010101010101010.into());
-        let item2 = item1.clone();
+        let item2 = Clone::clone(&item1);
         assert!(item1 == item2);
     }
 
@@ -265,41 +276,48 @@ mod tests {
     #[test]
     fn test_wordtype_check_parity_command() {
         let item = WordType::from(CommandWord::new().with_parity(1));
-        assert_eq!(item.check_parity(), true);
+        assert!(item.check_parity());
     }
 
     #[test]
     fn test_wordtype_check_parity_command_fail() {
         let item = WordType::from(CommandWord::new().with_parity(0));
-        assert_eq!(item.check_parity(), false);
+        assert!(!item.check_parity());
     }
 
     #[test]
     fn test_wordtype_check_parity_status() {
         let item = WordType::from(StatusWord::new().with_parity(1));
-        assert_eq!(item.check_parity(), true);
+        assert!(item.check_parity());
     }
 
     #[test]
     fn test_wordtype_check_parity_status_fail() {
         let item = WordType::from(StatusWord::new().with_parity(0));
-        assert_eq!(item.check_parity(), false);
+        assert!(!item.check_parity());
     }
 
     #[test]
     fn test_wordtype_check_parity_data() {
         let item = WordType::from(DataWord::new().with_parity(1));
-        assert_eq!(item.check_parity(), true);
+        assert!(item.check_parity());
     }
 
     #[test]
     fn test_wordtype_check_parity_data_fail() {
         let item = WordType::from(DataWord::new().with_parity(0));
-        assert_eq!(item.check_parity(), false);
+        assert!(!item.check_parity());
     }
 
     #[test]
     fn test_wordtype_check_parity_none() {
-        assert_eq!(WordType::None.check_parity(), false);
+        assert!(!WordType::None.check_parity());
+    }
+
+    #[test]
+    fn test_word_type_display() {
+        let word = WordType::Data(DataWord::from(0b0000000000000011));
+        assert_eq!(std::format!("{}", word), "0x0003");
+        assert_eq!(std::format!("{}", WordType::None), "None");
     }
 }
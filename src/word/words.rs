@@ -1000,6 +1000,126 @@ impl Word for DataWord {
     }
 }
 
+/// Describe the parity bit of a word for alternate formatting
+fn parity_state<T: Word>(word: &T) -> &'static str {
+    if word.check_parity() {
+        "valid"
+    } else {
+        "invalid"
+    }
+}
+
+impl core::fmt::Display for CommandWord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}-",
+            self.address(),
+            self.transmit_receive(),
+            self.subaddress()
+        )?;
+
+        if self.is_mode_code() {
+            write!(f, "{}", self.mode_code())?;
+        } else {
+            write!(f, "{:02}", self.word_count())?;
+        }
+
+        if f.alternate() {
+            writeln!(f)?;
+            writeln!(f, "  address:          {:#}", self.address())?;
+            writeln!(f, "  transmit/receive: {:#}", self.transmit_receive())?;
+            writeln!(f, "  subaddress:       {:#}", self.subaddress())?;
+            if self.is_mode_code() {
+                writeln!(f, "  mode code:        {:#}", self.mode_code())?;
+            } else {
+                writeln!(f, "  word count:       {}", self.word_count())?;
+            }
+            write!(
+                f,
+                "  parity:           {} ({})",
+                self.parity(),
+                parity_state(self)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for StatusWord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let flags = [
+            (self.message_error().is_error(), "ME"),
+            (self.instrumentation().is_command(), "INSTR"),
+            (self.service_request().is_service(), "SR"),
+            (self.reserved().is_value(), "RSV"),
+            (self.broadcast_received().is_received(), "BCR"),
+            (self.terminal_busy().is_busy(), "BUSY"),
+            (self.subsystem_error().is_error(), "SSF"),
+            (self.dynamic_bus_acceptance().is_accepted(), "DBCA"),
+            (self.terminal_error().is_error(), "TF"),
+        ];
+
+        write!(f, "{} [", self.address())?;
+        for (i, (_, name)) in flags.iter().filter(|(set, _)| *set).enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", name)?;
+            if *name == "RSV" {
+                write!(f, "{}", u8::from(self.reserved()))?;
+            }
+        }
+        write!(f, "]")?;
+
+        if f.alternate() {
+            writeln!(f)?;
+            writeln!(f, "  address:                {:#}", self.address())?;
+            writeln!(f, "  message error:          {}", self.message_error())?;
+            writeln!(f, "  instrumentation:        {}", self.instrumentation())?;
+            writeln!(f, "  service request:        {}", self.service_request())?;
+            writeln!(f, "  reserved:               {}", self.reserved())?;
+            writeln!(f, "  broadcast received:     {}", self.broadcast_received())?;
+            writeln!(f, "  terminal busy:          {}", self.terminal_busy())?;
+            writeln!(f, "  subsystem flag:         {}", self.subsystem_error())?;
+            writeln!(
+                f,
+                "  dynamic bus acceptance: {}",
+                self.dynamic_bus_acceptance()
+            )?;
+            writeln!(f, "  terminal flag:          {}", self.terminal_error())?;
+            write!(
+                f,
+                "  parity:                 {} ({})",
+                self.parity(),
+                parity_state(self)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for DataWord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#06X}", self.as_value())?;
+
+        if f.alternate() {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "  value:  {} ({:#018b})",
+                self.as_value(),
+                self.as_value()
+            )?;
+            write!(f, "  parity: {} ({})", self.parity(), parity_state(self))?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Default for CommandWord {
     fn default() -> Self {
         Self::new()
@@ -1383,17 +1503,17 @@ mod tests {
         let mut word = CommandWord::from(0).with_parity(0);
         assert_eq!(word.parity, 0);
         assert_eq!(word.parity(), 0);
-        assert_eq!(word.check_parity(), false);
+        assert!(!word.check_parity());
 
         word.set_parity(1);
         assert_eq!(word.parity, 1);
         assert_eq!(word.parity(), 1);
-        assert_eq!(word.check_parity(), true);
+        assert!(word.check_parity());
 
         word = CommandWord::from(1).with_calculated_parity();
         assert_eq!(word.parity, 0);
         assert_eq!(word.parity(), 0);
-        assert_eq!(word.check_parity(), true);
+        assert!(word.check_parity());
     }
 
     #[test]
@@ -1513,11 +1633,11 @@ mod tests {
         let item2 = TerminalBusy::Busy;
 
         let mut word = StatusWord::new().with_terminal_busy(item1);
-        assert_eq!(word.is_busy(), false);
+        assert!(!word.is_busy());
         assert_eq!(word.terminal_busy(), item1);
 
         word.set_terminal_busy(item2);
-        assert_eq!(word.is_busy(), true);
+        assert!(word.is_busy());
         assert_eq!(word.terminal_busy(), item2);
     }
 
@@ -1663,17 +1783,17 @@ mod tests {
         let mut word = StatusWord::from(0).with_parity(0);
         assert_eq!(word.parity, 0);
         assert_eq!(word.parity(), 0);
-        assert_eq!(word.check_parity(), false);
+        assert!(!word.check_parity());
 
         word.set_parity(1);
         assert_eq!(word.parity, 1);
         assert_eq!(word.parity(), 1);
-        assert_eq!(word.check_parity(), true);
+        assert!(word.check_parity());
 
         word = StatusWord::from(1).with_calculated_parity();
         assert_eq!(word.parity, 0);
         assert_eq!(word.parity(), 0);
-        assert_eq!(word.check_parity(), true);
+        assert!(word.check_parity());
     }
 
     #[test]
@@ -1816,17 +1936,17 @@ mod tests {
         let mut word = DataWord::from(0).with_parity(0);
         assert_eq!(word.parity, 0);
         assert_eq!(word.parity(), 0);
-        assert_eq!(word.check_parity(), false);
+        assert!(!word.check_parity());
 
         word.set_parity(1);
         assert_eq!(word.parity, 1);
         assert_eq!(word.parity(), 1);
-        assert_eq!(word.check_parity(), true);
+        assert!(word.check_parity());
 
         word = DataWord::from(1).with_calculated_parity();
         assert_eq!(word.parity, 0);
         assert_eq!(word.parity(), 0);
-        assert_eq!(word.check_parity(), true);
+        assert!(word.check_parity());
     }
 
     #[test]
@@ -1922,4 +2042,85 @@ mod tests {
         let value = i64::from(word);
         assert_eq!(value, 170); // decimal
     }
+
+    #[test]
+    fn test_command_display() {
+        let word = CommandWord::new()
+            .with_address(Address::Value(5))
+            .with_transmit_receive(TransmitReceive::Transmit)
+            .with_subaddress(SubAddress::Value(3))
+            .with_word_count(2)
+            .with_calculated_parity();
+        assert_eq!(std::format!("{}", word), "05-T-03-02");
+
+        let word = word.with_word_count(32);
+        assert_eq!(std::format!("{}", word), "05-T-03-32");
+    }
+
+    #[test]
+    fn test_command_display_mode_code() {
+        let word = CommandWord::new()
+            .with_address(Address::Value(16))
+            .with_subaddress(SubAddress::ModeCode(0))
+            .with_mode_code(ModeCode::TransmitterShutdown)
+            .with_calculated_parity();
+        assert_eq!(std::format!("{}", word), "16-R-00-TransmitterShutdown");
+    }
+
+    #[test]
+    fn test_command_display_alternate() {
+        let word = CommandWord::new()
+            .with_address(Address::Value(5))
+            .with_transmit_receive(TransmitReceive::Transmit)
+            .with_subaddress(SubAddress::Value(3))
+            .with_word_count(2)
+            .with_parity(0);
+        assert_eq!(
+            std::format!("{:#}", word),
+            "05-T-03-02\n\
+             \x20 address:          5 (terminal)\n\
+             \x20 transmit/receive: Transmit\n\
+             \x20 subaddress:       3 (subaddress)\n\
+             \x20 word count:       2\n\
+             \x20 parity:           0 (invalid)"
+        );
+    }
+
+    #[test]
+    fn test_status_display() {
+        let word = StatusWord::new()
+            .with_address(Address::Value(12))
+            .with_service_request(ServiceRequest::Service)
+            .with_terminal_busy(TerminalBusy::Busy);
+        assert_eq!(std::format!("{}", word), "12 [SR BUSY]");
+
+        let word = StatusWord::new().with_address(Address::Value(1));
+        assert_eq!(std::format!("{}", word), "01 []");
+    }
+
+    #[test]
+    fn test_status_display_alternate() {
+        let word = StatusWord::new()
+            .with_address(Address::Value(12))
+            .with_message_error(MessageError::Error)
+            .with_calculated_parity();
+        let result = std::format!("{:#}", word);
+        assert!(result.starts_with("12 [ME]\n"));
+        assert!(result.contains("  message error:          Error\n"));
+        assert!(result.contains("  terminal busy:          NotBusy\n"));
+        assert!(result.ends_with("(valid)"));
+        assert_eq!(result.lines().count(), 12);
+    }
+
+    #[test]
+    fn test_data_display() {
+        let word = DataWord::from(0b0000000011111111);
+        assert_eq!(std::format!("{}", word), "0x00FF");
+        assert_eq!(
+            std::format!("{:#}", word),
+            "0x00FF\n\
+             \x20 value:  255 (0b0000000011111111)\n\
+             \x20 parity: 1 (valid)"
+        );
+    }
 }
@@ -0,0 +1,56 @@
+//! Checks that the bindings generated from the ICDs in `tests/icd` compile
+
+use mil_std_1553b::{Generator, Message, Result};
+
+mod icd {
+    include!("icd/generated.rs");
+}
+
+use icd::{AirData, NavData};
+
+#[test]
+fn test_generated_bindings_are_current() {
+    let output = Generator::new()
+        .with_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/icd/navigation.csv"
+        ))
+        .unwrap()
+        .with_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/icd/air_data.xml"
+        ))
+        .unwrap()
+        .generate();
+    assert_eq!(output, include_str!("icd/generated.rs"));
+}
+
+#[test]
+fn test_generated_nav_data() -> Result<()> {
+    let mut message = NavData::new();
+    message.set_altitude(-250.5)?;
+    message.set_valid_flag(true)?;
+
+    let mut buffer = [0u8; 8];
+    message.write(&mut buffer)?;
+
+    let message = NavData::read(&buffer)?;
+    assert_eq!(message.altitude(), Some(-250.5));
+    assert_eq!(message.valid_flag(), Some(true));
+    assert_eq!(NavData::DEFINITION.parameters().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_generated_air_data() -> Result<()> {
+    let mut message = AirData::default();
+    message.set_temperature(-20)?;
+    message.set_on_ground(true)?;
+
+    let message: Message<3> = message.into_message();
+    let message = AirData::try_from(message)?;
+    assert_eq!(message.temperature(), Some(-20));
+    assert_eq!(message.on_ground(), Some(true));
+    assert!(NavData::try_from(message.into_message()).is_err());
+    Ok(())
+}
@@ -0,0 +1,44 @@
+// This file was generated from an interface control document.
+// Do not edit it directly; regenerate it when the ICD changes.
+
+::mil_std_1553b::icd_message! {
+    /// `NavData`: RT 5, SA 3, transmit, 2 data words
+    pub struct NavData {
+        address: 5,
+        subaddress: 3,
+        transmit_receive: ::mil_std_1553b::TransmitReceive::Transmit,
+        word_count: 2,
+    }
+
+    /// Pressure altitude
+    /// Unit: ft
+    altitude, set_altitude: f32 = ::mil_std_1553b::Parameter::new(0, 0xFFFF)
+        .with_signed()
+        .with_scale(0.5)
+        .with_unit("ft")
+        .with_range(-1000.0, 50000.0);
+
+    valid_flag, set_valid_flag: bool = ::mil_std_1553b::Parameter::new(1, 0x8000);
+}
+
+::mil_std_1553b::icd_message! {
+    /// Output of the air data computer
+    ///
+    /// `air data`: RT 12, SA 5, receive, 2 data words
+    pub struct AirData {
+        address: 12,
+        subaddress: 5,
+        transmit_receive: ::mil_std_1553b::TransmitReceive::Receive,
+        word_count: 2,
+    }
+
+    /// Total air temperature
+    /// Unit: °C
+    temperature, set_temperature: i16 = ::mil_std_1553b::Parameter::new(0, 0x00FF)
+        .with_signed()
+        .with_offset(-40.0)
+        .with_unit("°C");
+
+    /// Set when airspeed > 50 kt & weight on wheels
+    on_ground, set_on_ground: bool = ::mil_std_1553b::Parameter::new(1, 0x0001);
+}